@@ -0,0 +1,61 @@
+//! Times the matrix operations behind every transformation.
+//!
+//! Compare the scalar and SSE versions with:
+//! ```text
+//! $ cargo bench
+//! $ cargo bench --features simd
+//! ```
+
+use rustracer::math::consts::PI;
+use rustracer::prelude::*;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 2_000_000;
+
+/// Runs `operation` repeatedly and prints the average time it took.
+fn bench<T>(name: &str, mut operation: impl FnMut() -> T) {
+    // Warm up the caches and the branch predictor first.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(operation());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(operation());
+    }
+    let nanos = start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!("{:<28} {:>8.2} ns", name, nanos);
+}
+
+fn main() {
+    let a: Matrix4 = Transform::identity()
+        .rotate_x(PI / 5.0)
+        .rotate_y(PI / 3.0)
+        .scale(2.0, 0.5, 1.5)
+        .translate(1.0, -2.0, 3.0)
+        .into();
+    let b = Matrix4::view_transform(
+        Point::new(1.0, 3.0, 2.0),
+        Point::new(4.0, -2.0, 8.0),
+        Vector::new(1.0, 1.0, 0.0),
+    );
+    let t = Tuple::new(1.0, -2.0, 3.5, 1.0);
+    let p = Point::new(1.0, -2.0, 3.5);
+    let v = Vector::new(-0.5, 4.0, 2.0);
+
+    let sse = cfg!(all(
+        feature = "simd",
+        target_arch = "x86_64",
+        not(feature = "f64")
+    ));
+    println!("{} path", if sse { "SSE" } else { "Scalar" });
+    bench("Matrix4 * Matrix4", || black_box(a) * black_box(b));
+    bench("Matrix4 * Tuple", || black_box(a) * black_box(t));
+    bench("Matrix4 * Point", || black_box(a) * black_box(p));
+    bench("Matrix4 * Vector", || black_box(a) * black_box(v));
+    bench("Matrix4::inverse", || black_box(a).inverse());
+    bench("Ray::transform", || {
+        Ray::new(black_box(p), black_box(v)).transform(&black_box(a))
+    });
+}
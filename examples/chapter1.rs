@@ -1,33 +1,33 @@
-use rustracer::tuple::Tuple;
+use rustracer::prelude::*;
 
 #[derive(Debug)]
 struct Projectile {
-    position: Tuple,
-    velocity: Tuple,
+    position: Point,
+    velocity: Vector,
 }
 
 #[derive(Debug)]
 struct Environment {
-    gravity: Tuple,
-    wind: Tuple,
+    gravity: Vector,
+    wind: Vector,
 }
 
 fn tick(env: &Environment, proj: &Projectile) -> Projectile {
     let position = proj.position + proj.velocity;
     let velocity = proj.velocity + env.gravity + env.wind;
 
-    Projectile{ position, velocity }
+    Projectile { position, velocity }
 }
 
 fn main() {
     let mut p = Projectile {
-        position: Tuple::from_point(0.0, 1.0, 0.0),
-        velocity: Tuple::from_vector(1.0, 1.0, 0.0).normalize(),
+        position: Point::new(0.0, 1.0, 0.0),
+        velocity: Vector::new(1.0, 1.0, 0.0).normalize(),
     };
 
     let e = Environment {
-        gravity: Tuple::from_point(0.0, -0.1, 0.0),
-        wind: Tuple::from_vector(-0.01, 0.0, 0.0).normalize(),
+        gravity: Vector::new(0.0, -0.1, 0.0),
+        wind: Vector::new(-0.01, 0.0, 0.0).normalize(),
     };
 
     let mut count = 0;
@@ -0,0 +1,71 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+use std::sync::Arc;
+
+fn main() -> Result<()> {
+    let floor = Plane::new().with_material(Material {
+        pattern: Some(Arc::new(CheckersPattern::new(
+            Color::new(0.35, 0.35, 0.35),
+            Color::new(0.65, 0.65, 0.65),
+        ))),
+        specular: 0.0,
+        reflective: 0.4,
+        ..Material::default()
+    });
+
+    let backdrop = Plane::new()
+        .with_transform(
+            Transform::identity()
+                .rotate_x(PI / 2.0)
+                .translate(0.0, 0.0, 5.0)
+                .into(),
+        )
+        .with_material(Material {
+            pattern: Some(Arc::new(StripePattern::new(
+                Color::new(0.6, 0.7, 1.0),
+                Color::new(0.3, 0.4, 0.8),
+            ))),
+            specular: 0.0,
+            ..Material::default()
+        });
+
+    let mut glass = Sphere::glass().with_transform(Matrix4::translation(-0.5, 1.0, 0.5));
+    let material = glass.material_mut();
+    material.color = Color::new(0.1, 0.1, 0.1);
+    material.diffuse = 0.1;
+    material.reflective = 0.9;
+    material.shininess = 300.0;
+
+    let mirror = Sphere::new()
+        .with_transform(
+            Transform::identity()
+                .scale(0.5, 0.5, 0.5)
+                .translate(1.5, 0.5, -0.5)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.2, 0.1, 0.1),
+            diffuse: 0.4,
+            reflective: 0.8,
+            ..Material::default()
+        });
+
+    let mut world = World::new();
+    world.add_object(floor);
+    world.add_object(backdrop);
+    world.add_object(glass);
+    world.add_object(mirror);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(0.0, 1.5, -5.0),
+        Point::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render_parallel(&world, 0).save_png("chapter11.png")
+}
@@ -0,0 +1,77 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+use std::sync::Arc;
+
+fn main() -> Result<()> {
+    let floor = Plane::new().with_material(Material {
+        pattern: Some(Arc::new(CheckersPattern::new(
+            Color::new(0.35, 0.35, 0.35),
+            Color::new(0.65, 0.65, 0.65),
+        ))),
+        specular: 0.0,
+        ..Material::default()
+    });
+
+    let cube = Cube::new()
+        .with_transform(
+            Transform::identity()
+                .scale(0.6, 0.6, 0.6)
+                .rotate_y(PI / 5.0)
+                .translate(-1.8, 0.6, 0.5)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.9, 0.3, 0.2),
+            diffuse: 0.7,
+            specular: 0.3,
+            ..Material::default()
+        });
+
+    let cylinder = Cylinder::truncated(0.0, 1.5, true)
+        .with_transform(
+            Transform::identity()
+                .scale(0.5, 1.0, 0.5)
+                .translate(0.0, 0.0, 0.8)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.2, 0.6, 0.9),
+            diffuse: 0.7,
+            specular: 0.3,
+            reflective: 0.2,
+            ..Material::default()
+        });
+
+    let cone = Cone::truncated(-1.0, 0.0, true)
+        .with_transform(
+            Transform::identity()
+                .scale(0.5, 1.0, 0.5)
+                .translate(1.6, 1.0, 0.2)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.3, 0.9, 0.3),
+            diffuse: 0.7,
+            specular: 0.3,
+            ..Material::default()
+        });
+
+    let mut world = World::new();
+    world.add_object(floor);
+    world.add_object(cube);
+    world.add_object(cylinder);
+    world.add_object(cone);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(0.0, 2.0, -5.0),
+        Point::new(0.0, 0.7, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render(&world).save_png("chapter13.png")
+}
@@ -0,0 +1,59 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+
+fn hexagon_corner() -> Sphere {
+    Sphere::new().with_transform(
+        Transform::identity()
+            .scale(0.25, 0.25, 0.25)
+            .translate(0.0, 0.0, -1.0)
+            .into(),
+    )
+}
+
+fn hexagon_edge() -> Cylinder {
+    Cylinder::truncated(0.0, 1.0, false).with_transform(
+        Transform::identity()
+            .scale(0.25, 1.0, 0.25)
+            .rotate_z(-PI / 2.0)
+            .rotate_y(-PI / 6.0)
+            .translate(0.0, 0.0, -1.0)
+            .into(),
+    )
+}
+
+fn hexagon_side(rotation: Float) -> Group {
+    Group::new()
+        .with_transform(Matrix4::rotation_y(rotation))
+        .with_child(hexagon_corner())
+        .with_child(hexagon_edge())
+}
+
+fn main() -> Result<()> {
+    let mut hexagon = Group::new();
+    for n in 0..6 {
+        hexagon.add_child(hexagon_side(n as Float * PI / 3.0));
+    }
+    hexagon.set_transform(
+        Transform::identity()
+            .rotate_x(-PI / 6.0)
+            .translate(0.0, 1.0, 0.0)
+            .into(),
+    );
+
+    let mut world = World::new();
+    world.add_object(Plane::new().with_transform(Matrix4::translation(0.0, -0.5, 0.0)));
+    world.add_object(hexagon);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(0.0, 2.0, -4.0),
+        Point::new(0.0, 0.8, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render(&world).save_png("chapter14.png")
+}
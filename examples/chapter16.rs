@@ -0,0 +1,69 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+
+// Hits report the material of the primitive they land on, so every part gets its own.
+fn ivory() -> Material {
+    Material {
+        color: Color::new(0.9, 0.9, 0.8),
+        ..Material::default()
+    }
+}
+
+fn pip(x: Float, y: Float, z: Float) -> Sphere {
+    Sphere::new()
+        .with_transform(
+            Transform::identity()
+                .scale(0.2, 0.2, 0.2)
+                .translate(x, y, z)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.1, 0.1, 0.1),
+            ..Material::default()
+        })
+}
+
+fn main() -> Result<()> {
+    // Rounded body: a cube with its corners trimmed by a sphere.
+    let body = Csg::new(
+        CsgOperation::Intersection,
+        Cube::new().with_material(ivory()),
+        Sphere::new()
+            .with_transform(Matrix4::scaling(1.5, 1.5, 1.5))
+            .with_material(ivory()),
+    );
+
+    // Pips are carved out of three of the faces.
+    let pips = Group::new()
+        .with_child(pip(0.0, 1.0, 0.0))
+        .with_child(pip(-0.4, 0.4, -1.0))
+        .with_child(pip(0.4, -0.4, -1.0))
+        .with_child(pip(-1.0, 0.5, -0.5))
+        .with_child(pip(-1.0, 0.0, 0.0))
+        .with_child(pip(-1.0, -0.5, 0.5));
+
+    let die = Csg::new(CsgOperation::Difference, body, pips).with_transform(
+        Transform::identity()
+            .scale(0.8, 0.8, 0.8)
+            .rotate_y(PI / 6.0)
+            .translate(0.0, 0.8, 0.0)
+            .into(),
+    );
+
+    let mut world = World::new();
+    world.add_object(Plane::new());
+    world.add_object(die);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(-1.5, 2.5, -4.0),
+        Point::new(0.0, 0.6, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render(&world).save_png("chapter16.png")
+}
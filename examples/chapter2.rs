@@ -0,0 +1,45 @@
+use rustracer::prelude::*;
+use std::fs;
+
+struct Projectile {
+    position: Point,
+    velocity: Vector,
+}
+
+struct Environment {
+    gravity: Vector,
+    wind: Vector,
+}
+
+fn tick(env: &Environment, proj: &Projectile) -> Projectile {
+    let position = proj.position + proj.velocity;
+    let velocity = proj.velocity + env.gravity + env.wind;
+
+    Projectile { position, velocity }
+}
+
+fn main() -> std::io::Result<()> {
+    let mut p = Projectile {
+        position: Point::new(0.0, 1.0, 0.0),
+        velocity: Vector::new(1.0, 1.8, 0.0).normalize() * 11.25,
+    };
+
+    let e = Environment {
+        gravity: Vector::new(0.0, -0.1, 0.0),
+        wind: Vector::new(-0.01, 0.0, 0.0),
+    };
+
+    let mut canvas = Canvas::new(900, 550);
+    let color = Color::new(1.0, 0.5, 0.25);
+
+    while p.position.y >= 0.0 {
+        let x = p.position.x.round();
+        let y = canvas.height() as Float - p.position.y.round();
+        if x >= 0.0 && y >= 0.0 && (x as usize) < canvas.width() && (y as usize) < canvas.height() {
+            canvas.write_pixel(x as usize, y as usize, color);
+        }
+        p = tick(&e, &p);
+    }
+
+    fs::write("chapter2.ppm", canvas.to_ppm())
+}
@@ -0,0 +1,19 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+
+fn main() -> Result<()> {
+    let size = 400;
+    let radius = size as Float * 3.0 / 8.0;
+    let mut canvas = Canvas::new(size, size);
+
+    // The clock lies in the xz plane, with twelve o'clock along +z.
+    let twelve = Point::new(0.0, 0.0, 1.0);
+    for hour in 0..12 {
+        let p = Matrix4::rotation_y(hour as Float * PI / 6.0) * twelve;
+        let x = (p.x * radius + size as Float / 2.0) as usize;
+        let y = (p.z * radius + size as Float / 2.0) as usize;
+        canvas.write_pixel(x, y, Color::white());
+    }
+
+    canvas.save_ppm("chapter4.ppm")
+}
@@ -0,0 +1,35 @@
+use rustracer::prelude::*;
+
+fn main() -> Result<()> {
+    let ray_origin = Point::new(0.0, 0.0, -5.0);
+    let wall_z = 10.0;
+    let wall_size = 7.0;
+    let canvas_pixels = 100;
+    let pixel_size = wall_size / canvas_pixels as Float;
+    let half = wall_size / 2.0;
+
+    let mut canvas = Canvas::new(canvas_pixels, canvas_pixels);
+    let color = Color::new(1.0, 0.0, 0.0);
+    let mut shape = Sphere::new();
+    shape.set_transform(
+        Transform::identity()
+            .scale(1.0, 0.5, 1.0)
+            .rotate_z(0.5)
+            .into(),
+    );
+
+    for y in 0..canvas_pixels {
+        let world_y = half - pixel_size * y as Float;
+        for x in 0..canvas_pixels {
+            let world_x = -half + pixel_size * x as Float;
+            let position = Point::new(world_x, world_y, wall_z);
+            let ray = Ray::new(ray_origin, (position - ray_origin).normalize());
+
+            if !shape.intersect(&ray).is_empty() {
+                canvas.write_pixel(x, y, color);
+            }
+        }
+    }
+
+    canvas.save_ppm("chapter5.ppm")
+}
@@ -0,0 +1,42 @@
+use rustracer::prelude::*;
+
+fn main() -> Result<()> {
+    let ray_origin = Point::new(0.0, 0.0, -5.0);
+    let wall_z = 10.0;
+    let wall_size = 7.0;
+    let canvas_pixels = 200;
+    let pixel_size = wall_size / canvas_pixels as Float;
+    let half = wall_size / 2.0;
+
+    let mut canvas = Canvas::new(canvas_pixels, canvas_pixels);
+    let mut shape = Sphere::new();
+    shape.material_mut().color = Color::new(1.0, 0.2, 1.0);
+    let light = PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::white());
+
+    for y in 0..canvas_pixels {
+        let world_y = half - pixel_size * y as Float;
+        for x in 0..canvas_pixels {
+            let world_x = -half + pixel_size * x as Float;
+            let position = Point::new(world_x, world_y, wall_z);
+            let ray = Ray::new(ray_origin, (position - ray_origin).normalize());
+
+            if let Some(hit) = shape.intersect(&ray).hit() {
+                let point = ray.position(hit.t);
+                let normal = hit.object.normal_at(point);
+                let eye = -ray.direction;
+                let color = lighting(
+                    hit.object.material(),
+                    hit.object,
+                    &light,
+                    point,
+                    eye,
+                    normal,
+                    false,
+                );
+                canvas.write_pixel(x, y, color);
+            }
+        }
+    }
+
+    canvas.save_png("chapter6.png")
+}
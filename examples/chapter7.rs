@@ -0,0 +1,83 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+
+fn main() -> Result<()> {
+    let mut wall_material = Material::new();
+    wall_material.color = Color::new(1.0, 0.9, 0.9);
+    wall_material.specular = 0.0;
+
+    let mut floor = Sphere::new();
+    floor.set_transform(Matrix4::scaling(10.0, 0.01, 10.0));
+    floor.set_material(wall_material.clone());
+
+    let mut left_wall = Sphere::new();
+    left_wall.set_transform(
+        Transform::identity()
+            .scale(10.0, 0.01, 10.0)
+            .rotate_x(PI / 2.0)
+            .rotate_y(-PI / 4.0)
+            .translate(0.0, 0.0, 5.0)
+            .into(),
+    );
+    left_wall.set_material(wall_material.clone());
+
+    let mut right_wall = Sphere::new();
+    right_wall.set_transform(
+        Transform::identity()
+            .scale(10.0, 0.01, 10.0)
+            .rotate_x(PI / 2.0)
+            .rotate_y(PI / 4.0)
+            .translate(0.0, 0.0, 5.0)
+            .into(),
+    );
+    right_wall.set_material(wall_material);
+
+    let mut middle = Sphere::new();
+    middle.set_transform(Matrix4::translation(-0.5, 1.0, 0.5));
+    middle.material_mut().color = Color::new(0.1, 1.0, 0.5);
+    middle.material_mut().diffuse = 0.7;
+    middle.material_mut().specular = 0.3;
+
+    let mut right = Sphere::new();
+    right.set_transform(
+        Transform::identity()
+            .scale(0.5, 0.5, 0.5)
+            .translate(1.5, 0.5, -0.5)
+            .into(),
+    );
+    right.material_mut().color = Color::new(0.5, 1.0, 0.1);
+    right.material_mut().diffuse = 0.7;
+    right.material_mut().specular = 0.3;
+
+    let mut left = Sphere::new();
+    left.set_transform(
+        Transform::identity()
+            .scale(0.33, 0.33, 0.33)
+            .translate(-1.5, 0.33, -0.75)
+            .into(),
+    );
+    left.material_mut().color = Color::new(1.0, 0.8, 0.1);
+    left.material_mut().diffuse = 0.7;
+    left.material_mut().specular = 0.3;
+
+    let mut world = World::new();
+    world.add_object(floor);
+    world.add_object(left_wall);
+    world.add_object(right_wall);
+    world.add_object(middle);
+    world.add_object(right);
+    world.add_object(left);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(0.0, 1.5, -5.0),
+        Point::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render(&world).save_png("chapter7.png")
+}
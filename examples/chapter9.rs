@@ -0,0 +1,80 @@
+use rustracer::prelude::*;
+use rustracer::math::consts::PI;
+
+fn main() -> Result<()> {
+    let floor = Plane::new().with_material(Material {
+        color: Color::new(1.0, 0.9, 0.9),
+        specular: 0.0,
+        ..Material::default()
+    });
+
+    let backdrop = Plane::new()
+        .with_transform(
+            Transform::identity()
+                .rotate_x(PI / 2.0)
+                .translate(0.0, 0.0, 5.0)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.6, 0.7, 1.0),
+            specular: 0.0,
+            ..Material::default()
+        });
+
+    let middle = Sphere::new()
+        .with_transform(Matrix4::translation(-0.5, 1.0, 0.5))
+        .with_material(Material {
+            color: Color::new(0.1, 1.0, 0.5),
+            diffuse: 0.7,
+            specular: 0.3,
+            ..Material::default()
+        });
+
+    let right = Sphere::new()
+        .with_transform(
+            Transform::identity()
+                .scale(0.5, 0.5, 0.5)
+                .translate(1.5, 0.5, -0.5)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(0.5, 1.0, 0.1),
+            diffuse: 0.7,
+            specular: 0.3,
+            ..Material::default()
+        });
+
+    let left = Sphere::new()
+        .with_transform(
+            Transform::identity()
+                .scale(0.33, 0.33, 0.33)
+                .translate(-1.5, 0.33, -0.75)
+                .into(),
+        )
+        .with_material(Material {
+            color: Color::new(1.0, 0.8, 0.1),
+            diffuse: 0.7,
+            specular: 0.3,
+            ..Material::default()
+        });
+
+    let mut world = World::new();
+    world.add_object(floor);
+    world.add_object(backdrop);
+    world.add_object(middle);
+    world.add_object(right);
+    world.add_object(left);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::white(),
+    ));
+
+    let mut camera = Camera::new(200, 100, PI / 3.0);
+    camera.set_transform(Matrix4::view_transform(
+        Point::new(0.0, 1.5, -5.0),
+        Point::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    camera.render(&world).save_png("chapter9.png")
+}
@@ -0,0 +1,56 @@
+use rustracer::math::consts::PI;
+use rustracer::prelude::*;
+use std::sync::Arc;
+
+fn main() -> Result<()> {
+    // One full turn of the camera, while the small sphere bounces twice.
+    let angle = Keyframes::new(0.0, 0.0).with_key(1.0, 2.0 * PI);
+    let height = Keyframes::new(0.0, 0.33)
+        .with_key(0.25, 1.5)
+        .with_key(0.5, 0.33)
+        .with_key(0.75, 1.5)
+        .with_key(1.0, 0.33)
+        .with_easing(Easing::EaseInOut);
+
+    let animation = Animation::new(36, 1.0, move |t| {
+        let mut floor = Plane::new();
+        floor.material_mut().pattern = Some(Arc::new(CheckersPattern::new(
+            Color::new(0.9, 0.9, 0.9),
+            Color::new(0.3, 0.3, 0.3),
+        )));
+
+        let mut middle = Sphere::new().with_transform(Matrix4::translation(0.0, 1.0, 0.0));
+        middle.material_mut().color = Color::new(0.1, 1.0, 0.5);
+        middle.material_mut().diffuse = 0.7;
+        middle.material_mut().specular = 0.3;
+
+        let mut bouncing = Sphere::new().with_transform(
+            Transform::identity()
+                .scale(0.33, 0.33, 0.33)
+                .translate(1.5, height.at(t), -0.75)
+                .into(),
+        );
+        bouncing.material_mut().color = Color::new(1.0, 0.8, 0.1);
+        bouncing.material_mut().diffuse = 0.7;
+        bouncing.material_mut().specular = 0.3;
+
+        let mut world = World::new();
+        world.add_object(floor);
+        world.add_object(middle);
+        world.add_object(bouncing);
+        world.add_light(PointLight::new(
+            Point::new(-10.0, 10.0, -10.0),
+            Color::white(),
+        ));
+
+        let mut camera = Camera::new(200, 100, PI / 3.0);
+        camera.set_transform(Matrix4::view_transform(
+            Matrix4::rotation_y(angle.at(t)) * Point::new(0.0, 1.5, -5.0),
+            Point::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+        Scene { world, camera }
+    });
+
+    animation.save_png_frames("turntable", 0)
+}
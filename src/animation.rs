@@ -0,0 +1,376 @@
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// A value that can be blended between two keyframes.
+pub trait Interpolate: Copy {
+    /// Value `t` of the way from `self` to `other`, with `t` in [0, 1].
+    fn lerp(self, other: Self, t: Float) -> Self;
+}
+
+impl Interpolate for Float {
+    fn lerp(self, other: Self, t: Float) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Point {
+    fn lerp(self, other: Self, t: Float) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Vector {
+    fn lerp(self, other: Self, t: Float) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn lerp(self, other: Self, t: Float) -> Self {
+        self * (1.0 - t) + other * t
+    }
+}
+
+/// How the time between two keyframes turns into the blend between their values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Changes at a constant rate.
+    #[default]
+    Linear,
+    /// Speeds up after a keyframe and slows down before the next one.
+    EaseInOut,
+    /// Holds each value until the next keyframe.
+    Step,
+}
+
+impl Easing {
+    /// Blend for the fraction `t` of the time between two keyframes.
+    pub fn apply(self, t: Float) -> Float {
+        match self {
+            Self::Linear => t,
+            Self::EaseInOut => t * t * (3.0 - 2.0 * t),
+            Self::Step => 0.0,
+        }
+    }
+}
+
+/// Values at given times, blended in between, e.g. the angle of a turntable or the
+/// position of a camera.
+///
+/// Before the first keyframe and after the last, their values are held.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+    keys: Vec<(Float, T)>,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Keyframes<T> {
+    pub fn new(time: Float, value: T) -> Self {
+        Self {
+            keys: alloc::vec![(time, value)],
+            easing: Easing::default(),
+        }
+    }
+
+    /// Adds a keyframe, replacing any other one at the same time.
+    pub fn with_key(mut self, time: Float, value: T) -> Self {
+        let index = self.keys.partition_point(|&(t, _)| t < time);
+        match self.keys.get_mut(index) {
+            Some(key) if key.0 == time => key.1 = value,
+            _ => self.keys.insert(index, (time, value)),
+        }
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn keys(&self) -> &[(Float, T)] {
+        &self.keys
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    pub fn at(&self, time: Float) -> T {
+        let next = self.keys.partition_point(|&(t, _)| t <= time);
+        if next == 0 {
+            return self.keys[0].1;
+        }
+        if next == self.keys.len() {
+            return self.keys[next - 1].1;
+        }
+
+        let (start, from) = self.keys[next - 1];
+        let (end, to) = self.keys[next];
+        from.lerp(to, self.easing.apply((time - start) / (end - start)))
+    }
+}
+
+/// Builds the scene shown at a time of the animation.
+pub type SceneAt = dyn Fn(Float) -> Scene + Send + Sync;
+
+/// A sequence of frames, each rendered from the scene at its time.
+///
+/// The frames are spread evenly from time 0 up to, but not including, the duration, so
+/// that an animation ending where it started loops without showing that frame twice.
+pub struct Animation {
+    frames: usize,
+    duration: Float,
+    scene_at: Box<SceneAt>,
+}
+
+impl Animation {
+    /// Animation of `frames` frames over `duration`, in whatever unit `scene_at` takes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no frames or `duration` is negative.
+    pub fn new<F>(frames: usize, duration: Float, scene_at: F) -> Self
+    where
+        F: Fn(Float) -> Scene + Send + Sync + 'static,
+    {
+        Self::try_new(frames, duration, scene_at).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Animation like [`Animation::new`], failing instead of panicking on a bad number of
+    /// `frames` or `duration`.
+    pub fn try_new<F>(frames: usize, duration: Float, scene_at: F) -> Result<Self>
+    where
+        F: Fn(Float) -> Scene + Send + Sync + 'static,
+    {
+        if frames == 0 {
+            return Err(RustracerError::InvalidArgument(
+                "an animation needs at least one frame",
+            ));
+        }
+        if duration < 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the duration cannot be negative",
+            ));
+        }
+        Ok(Self {
+            frames,
+            duration,
+            scene_at: Box::new(scene_at),
+        })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn duration(&self) -> Float {
+        self.duration
+    }
+
+    pub fn time_of(&self, frame: usize) -> Float {
+        self.duration * frame as Float / self.frames as Float
+    }
+
+    pub fn scene_of(&self, frame: usize) -> Scene {
+        (self.scene_at)(self.time_of(frame))
+    }
+
+    pub fn render_frame(&self, frame: usize) -> Canvas {
+        let Scene { world, camera } = self.scene_of(frame);
+        camera.render(&world)
+    }
+}
+
+impl fmt::Debug for Animation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Animation")
+            .field("frames", &self.frames)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Name of the image of a frame, like `frame_0042.png`, so that the files sort in order.
+pub fn frame_file_name(frame: usize, extension: &str) -> String {
+    format!("frame_{:04}.{}", frame, extension)
+}
+
+#[cfg(feature = "std")]
+impl Animation {
+    /// Renders like [`Animation::render_frame`], splitting the rows between `num_threads`
+    /// threads like [`Camera::render_parallel`] does.
+    pub fn render_frame_parallel(&self, frame: usize, num_threads: usize) -> Canvas {
+        let Scene { world, camera } = self.scene_of(frame);
+        camera.render_parallel(&world, num_threads)
+    }
+
+    /// Renders every frame into `dir`, named by [`frame_file_name`] and encoded with
+    /// `encoder`, calling `on_frame` with the index of every frame once it is written.
+    pub fn save_frames<P: AsRef<Path>, F: FnMut(usize)>(
+        &self,
+        dir: P,
+        encoder: &dyn ImageEncoder,
+        extension: &str,
+        num_threads: usize,
+        mut on_frame: F,
+    ) -> Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        for frame in 0..self.frames {
+            let image = self.render_frame_parallel(frame, num_threads);
+            image.save(dir.join(frame_file_name(frame, extension)), encoder)?;
+            on_frame(frame);
+        }
+        Ok(())
+    }
+
+    pub fn save_ppm_frames<P: AsRef<Path>>(&self, dir: P, num_threads: usize) -> Result<()> {
+        self.save_frames(dir, &PpmEncoder, "ppm", num_threads, |_| {})
+    }
+
+    #[cfg(feature = "png")]
+    pub fn save_png_frames<P: AsRef<Path>>(&self, dir: P, num_threads: usize) -> Result<()> {
+        self.save_frames(dir, &PngEncoder, "png", num_threads, |_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn keyframes_interpolate_between_keys() {
+        let k = Keyframes::new(0.0, 0.0)
+            .with_key(2.0, 10.0)
+            .with_key(1.0, 4.0);
+
+        assert_eq!(k.keys(), &[(0.0, 0.0), (1.0, 4.0), (2.0, 10.0)]);
+        assert_eq!(k.at(0.0), 0.0);
+        assert_eq!(k.at(0.5), 2.0);
+        assert_eq!(k.at(1.0), 4.0);
+        assert_eq!(k.at(1.25), 5.5);
+        assert_eq!(k.at(2.0), 10.0);
+    }
+
+    #[test]
+    fn keyframes_hold_values_outside_keys() {
+        let k =
+            Keyframes::new(1.0, Point::new(1.0, 0.0, 0.0)).with_key(3.0, Point::new(3.0, 2.0, 0.0));
+
+        assert_eq!(k.at(-5.0), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(k.at(2.0), Point::new(2.0, 1.0, 0.0));
+        assert_eq!(k.at(7.0), Point::new(3.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn keyframes_replace_keys_at_same_time() {
+        let k = Keyframes::new(0.0, Color::black()).with_key(0.0, Color::white());
+
+        assert_eq!(k.keys(), &[(0.0, Color::white())]);
+    }
+
+    #[test]
+    fn easing_changes_blend_between_keys() {
+        let k = Keyframes::new(0.0, Vector::new(0.0, 0.0, 0.0))
+            .with_key(1.0, Vector::new(4.0, 0.0, 0.0));
+
+        let eased = k.clone().with_easing(Easing::EaseInOut);
+        assert_eq!(eased.at(0.25), Vector::new(0.625, 0.0, 0.0));
+        assert_eq!(eased.at(0.5), Vector::new(2.0, 0.0, 0.0));
+        assert_eq!(eased.at(1.0), Vector::new(4.0, 0.0, 0.0));
+
+        let stepped = k.with_easing(Easing::Step);
+        assert_eq!(stepped.at(0.99), Vector::new(0.0, 0.0, 0.0));
+        assert_eq!(stepped.at(1.0), Vector::new(4.0, 0.0, 0.0));
+    }
+
+    fn turntable() -> Animation {
+        let angle = Keyframes::new(0.0, 0.0).with_key(1.0, 2.0 * consts::PI);
+        Animation::new(4, 1.0, move |t| {
+            let mut world = World::new();
+            world.add_light(PointLight::new(
+                Point::new(-10.0, 10.0, -10.0),
+                Color::white(),
+            ));
+            let mut sphere = Sphere::new().with_transform(Matrix4::translation(1.0, 0.0, 0.0));
+            sphere.material_mut().color = Color::new(1.0, 0.2, 0.2);
+            world.add_object(sphere);
+
+            let mut camera = Camera::new(11, 11, consts::FRAC_PI_2);
+            let eye = Matrix4::rotation_y(angle.at(t)) * Point::new(0.0, 0.0, -5.0);
+            camera.set_transform(Matrix4::view_transform(
+                eye,
+                Point::new(0.0, 0.0, 0.0),
+                Vector::new(0.0, 1.0, 0.0),
+            ));
+            Scene { world, camera }
+        })
+    }
+
+    #[test]
+    fn trying_animation_without_frames_fails() {
+        let scene_at = |_| turntable().scene_of(0);
+
+        assert!(Animation::try_new(0, 1.0, scene_at).is_err());
+        assert!(Animation::try_new(4, -1.0, scene_at).is_err());
+        assert_eq!(
+            Animation::try_new(4, 2.0, scene_at).unwrap().duration(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn frames_spread_over_duration() {
+        let a = turntable();
+
+        assert_eq!(a.frames(), 4);
+        assert_eq!(a.time_of(0), 0.0);
+        assert_eq!(a.time_of(1), 0.25);
+        assert_eq!(a.time_of(3), 0.75);
+        assert_eq!(
+            *a.scene_of(2).camera.transform() * Point::new(0.0, 0.0, 5.0),
+            Point::new(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn rendering_frames_from_scene_at_their_time() {
+        let a = turntable();
+
+        // The sphere to the right of the center only faces the camera from the front.
+        let front = a.render_frame(0);
+        let back = a.render_frame(2);
+        assert_ne!(front.pixel_at(7, 5), Color::black());
+        assert_eq!(front.pixel_at(3, 5), Color::black());
+        assert_eq!(back.pixel_at(7, 5), Color::black());
+        assert_ne!(back.pixel_at(3, 5), Color::black());
+    }
+
+    #[test]
+    fn naming_frame_files_in_order() {
+        assert_eq!(frame_file_name(7, "png"), "frame_0007.png");
+        assert_eq!(frame_file_name(12345, "ppm"), "frame_12345.ppm");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn saving_frames_to_directory() {
+        let dir = std::env::temp_dir().join("rustracer-animation-test");
+        let a = turntable();
+        let mut saved = Vec::new();
+
+        a.save_frames(&dir, &PpmEncoder, "ppm", 2, |frame| saved.push(frame))
+            .unwrap();
+
+        assert_eq!(saved, [0, 1, 2, 3]);
+        let last = Canvas::load_ppm(dir.join("frame_0003.ppm")).unwrap();
+        assert_eq!(last.to_ppm(), a.render_frame(3).to_ppm());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// What rays see when they hit nothing.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Background {
+    /// The same color in every direction.
+    Solid(Color),
+    /// A sky fading from `horizon` to `zenith` as rays point higher, with `ground` in the
+    /// same way below the horizon. Without a ground, the horizon color goes all the way
+    /// down.
+    Gradient {
+        horizon: Color,
+        zenith: Color,
+        ground: Option<Color>,
+    },
+}
+
+impl Background {
+    /// Color seen looking along `direction`, given in world space.
+    pub fn color_toward(&self, direction: Vector) -> Color {
+        match *self {
+            Self::Solid(color) => color,
+            Self::Gradient {
+                horizon,
+                zenith,
+                ground,
+            } => {
+                // Sine of the angle above the horizon.
+                let height = direction.y / direction.magnitude();
+                match ground {
+                    _ if height >= 0.0 => horizon * (1.0 - height) + zenith * height,
+                    Some(ground) => horizon * (1.0 + height) - ground * height,
+                    None => horizon,
+                }
+            }
+        }
+    }
+}
+
+/// Black, as in the book.
+impl Default for Background {
+    fn default() -> Self {
+        Self::Solid(Color::black())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::FRAC_1_SQRT_2;
+    use crate::prelude::*;
+
+    #[test]
+    fn background_is_black_by_default() {
+        assert_eq!(
+            Background::default().color_toward(Vector::new(0.0, 1.0, 0.0)),
+            Color::black()
+        );
+    }
+
+    #[test]
+    fn gradient_fades_from_horizon_to_zenith() {
+        let sky = Background::Gradient {
+            horizon: Color::white(),
+            zenith: Color::new(0.0, 0.0, 1.0),
+            ground: None,
+        };
+
+        assert_eq!(sky.color_toward(Vector::new(0.0, 0.0, 1.0)), Color::white());
+        assert_eq!(
+            sky.color_toward(Vector::new(0.0, 2.0, 0.0)),
+            Color::new(0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            sky.color_toward(Vector::new(1.0, 1.0, 0.0)),
+            Color::new(1.0 - FRAC_1_SQRT_2, 1.0 - FRAC_1_SQRT_2, 1.0)
+        );
+        assert_eq!(
+            sky.color_toward(Vector::new(0.0, -1.0, 0.0)),
+            Color::white()
+        );
+    }
+
+    #[test]
+    fn gradient_fades_to_ground_below_horizon() {
+        let sky = Background::Gradient {
+            horizon: Color::white(),
+            zenith: Color::new(0.0, 0.0, 1.0),
+            ground: Some(Color::new(0.2, 0.1, 0.0)),
+        };
+
+        assert_eq!(
+            sky.color_toward(Vector::new(0.0, -1.0, 0.0)),
+            Color::new(0.2, 0.1, 0.0)
+        );
+        assert_eq!(
+            sky.color_toward(Vector::new(0.0, -1.0, Float::sqrt(3.0))),
+            Color::new(0.6, 0.55, 0.5)
+        );
+    }
+}
@@ -0,0 +1,546 @@
+use crate::prelude::*;
+
+/// An axis-aligned box enclosing a shape, used to skip rays that cannot possibly hit it.
+///
+/// Unbounded shapes such as planes have infinite extents.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// A box that contains nothing, to be grown with [`BoundingBox::add_point`].
+    pub const fn empty() -> Self {
+        Self::new(
+            Point::new(Float::INFINITY, Float::INFINITY, Float::INFINITY),
+            Point::new(Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    /// Grows the box just enough to contain `point`.
+    pub fn add_point(&mut self, point: Point) {
+        self.min = Point::new(
+            self.min.x.min(point.x),
+            self.min.y.min(point.y),
+            self.min.z.min(point.z),
+        );
+        self.max = Point::new(
+            self.max.x.max(point.x),
+            self.max.y.max(point.y),
+            self.max.z.max(point.z),
+        );
+    }
+
+    /// Grows the box just enough to contain `other`.
+    pub fn add_box(&mut self, other: &BoundingBox) {
+        if !other.is_empty() {
+            self.add_point(other.min);
+            self.add_point(other.max);
+        }
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// The axis-aligned box enclosing this one once transformed by `m`.
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+
+        // Each output coordinate is a sum of one term per input axis, so picking the
+        // smallest and largest term of each gives the same box as transforming all eight
+        // corners. Skipping zero factors keeps infinite extents from turning into NaN.
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+        let mut new_min = [0.0; 3];
+        let mut new_max = [0.0; 3];
+        for row in 0..3 {
+            new_min[row] = m[(row, 3)];
+            new_max[row] = m[(row, 3)];
+            for col in 0..3 {
+                let factor = m[(row, col)];
+                if factor == 0.0 {
+                    continue;
+                }
+                let a = factor * min[col];
+                let b = factor * max[col];
+                new_min[row] += a.min(b);
+                new_max[row] += a.max(b);
+            }
+        }
+
+        Self::new(
+            Point::new(new_min[0], new_min[1], new_min[2]),
+            Point::new(new_max[0], new_max[1], new_max[2]),
+        )
+    }
+
+    /// Whether `ray` passes through the box, given in the same space as the ray.
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x, self.min.x, self.max.x);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y, self.min.y, self.max.y);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z, self.min.z, self.max.z);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        tmin <= tmax
+    }
+
+    /// Cuts the box in half across its longest axis.
+    pub fn split(&self) -> (Self, Self) {
+        let dx = self.max.x - self.min.x;
+        let dy = self.max.y - self.min.y;
+        let dz = self.max.z - self.min.z;
+        let greatest = dx.max(dy).max(dz);
+
+        let mut mid_min = self.min;
+        let mut mid_max = self.max;
+        if greatest == dx {
+            mid_min.x = self.min.x + dx / 2.0;
+            mid_max.x = mid_min.x;
+        } else if greatest == dy {
+            mid_min.y = self.min.y + dy / 2.0;
+            mid_max.y = mid_min.y;
+        } else {
+            mid_min.z = self.min.z + dz / 2.0;
+            mid_max.z = mid_min.z;
+        }
+
+        (Self::new(self.min, mid_max), Self::new(mid_min, self.max))
+    }
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+fn check_axis(origin: Float, direction: Float, min: Float, max: Float) -> (Float, Float) {
+    let tmin = (min - origin) / direction;
+    let tmax = (max - origin) / direction;
+
+    if tmin > tmax {
+        (tmax, tmin)
+    } else {
+        (tmin, tmax)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::math::consts::{FRAC_PI_4, SQRT_2};
+
+    // Scenario: Creating an empty bounding box
+    //  Given box ← bounding_box(empty)
+    //  Then box.min = point(infinity, infinity, infinity)
+    //    And box.max = point(-infinity, -infinity, -infinity)
+    #[test]
+    fn creating_empty_bounding_box() {
+        let b = BoundingBox::empty();
+
+        assert_eq!(
+            b.min,
+            Point::new(Float::INFINITY, Float::INFINITY, Float::INFINITY)
+        );
+        assert_eq!(
+            b.max,
+            Point::new(Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY)
+        );
+        assert!(b.is_empty());
+    }
+
+    // Scenario: Adding points to an empty bounding box
+    //  Given box ← bounding_box(empty)
+    //    And p1 ← point(-5, 2, 0)
+    //    And p2 ← point(7, 0, -3)
+    //  When p1 is added to box
+    //    And p2 is added to box
+    //  Then box.min = point(-5, 0, -3)
+    //    And box.max = point(7, 2, 0)
+    #[test]
+    fn adding_points_to_empty_bounding_box() {
+        let mut b = BoundingBox::empty();
+
+        b.add_point(Point::new(-5.0, 2.0, 0.0));
+        b.add_point(Point::new(7.0, 0.0, -3.0));
+
+        assert_eq!(b.min, Point::new(-5.0, 0.0, -3.0));
+        assert_eq!(b.max, Point::new(7.0, 2.0, 0.0));
+    }
+
+    // Scenario: Adding one bounding box to another
+    //  Given box1 ← bounding_box(min=point(-5, -2, 0) max=point(7, 4, 4))
+    //    And box2 ← bounding_box(min=point(8, -7, -2) max=point(14, 2, 8))
+    //  When box2 is added to box1
+    //  Then box1.min = point(-5, -7, -2)
+    //    And box1.max = point(14, 4, 8)
+    #[test]
+    fn adding_one_bounding_box_to_another() {
+        let mut box1 = BoundingBox::new(Point::new(-5.0, -2.0, 0.0), Point::new(7.0, 4.0, 4.0));
+        let box2 = BoundingBox::new(Point::new(8.0, -7.0, -2.0), Point::new(14.0, 2.0, 8.0));
+
+        box1.add_box(&box2);
+
+        assert_eq!(box1.min, Point::new(-5.0, -7.0, -2.0));
+        assert_eq!(box1.max, Point::new(14.0, 4.0, 8.0));
+    }
+
+    // Scenario Outline: Checking to see if a box contains a given point
+    //  Given box ← bounding_box(min=point(5, -2, 0) max=point(11, 4, 7))
+    //    And p ← <point>
+    //  Then box_contains_point(box, p) is <result>
+    //
+    //  Examples:
+    //    | point           | result |
+    //    | point(5, -2, 0) | true   |
+    //    | point(11, 4, 7) | true   |
+    //    | point(8, 1, 3)  | true   |
+    //    | point(3, 0, 3)  | false  |
+    //    | point(8, -4, 3) | false  |
+    //    | point(8, 1, -1) | false  |
+    //    | point(13, 1, 3) | false  |
+    //    | point(8, 5, 3)  | false  |
+    //    | point(8, 1, 8)  | false  |
+    #[test]
+    fn checking_if_box_contains_point() {
+        let b = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+        let examples = [
+            (Point::new(5.0, -2.0, 0.0), true),
+            (Point::new(11.0, 4.0, 7.0), true),
+            (Point::new(8.0, 1.0, 3.0), true),
+            (Point::new(3.0, 0.0, 3.0), false),
+            (Point::new(8.0, -4.0, 3.0), false),
+            (Point::new(8.0, 1.0, -1.0), false),
+            (Point::new(13.0, 1.0, 3.0), false),
+            (Point::new(8.0, 5.0, 3.0), false),
+            (Point::new(8.0, 1.0, 8.0), false),
+        ];
+
+        for (p, result) in examples {
+            assert_eq!(b.contains_point(p), result, "{:?}", p);
+        }
+    }
+
+    // Scenario Outline: Checking to see if a box contains a given box
+    //  Given box ← bounding_box(min=point(5, -2, 0) max=point(11, 4, 7))
+    //    And box2 ← bounding_box(min=<min> max=<max>)
+    //  Then box_contains_box(box, box2) is <result>
+    //
+    //  Examples:
+    //    | min              | max             | result |
+    //    | point(5, -2, 0)  | point(11, 4, 7) | true   |
+    //    | point(6, -1, 1)  | point(10, 3, 6) | true   |
+    //    | point(4, -3, -1) | point(10, 3, 6) | false  |
+    //    | point(6, -1, 1)  | point(12, 5, 8) | false  |
+    #[test]
+    fn checking_if_box_contains_box() {
+        let b = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+        let examples = [
+            (Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0), true),
+            (Point::new(6.0, -1.0, 1.0), Point::new(10.0, 3.0, 6.0), true),
+            (
+                Point::new(4.0, -3.0, -1.0),
+                Point::new(10.0, 3.0, 6.0),
+                false,
+            ),
+            (
+                Point::new(6.0, -1.0, 1.0),
+                Point::new(12.0, 5.0, 8.0),
+                false,
+            ),
+        ];
+
+        for (min, max, result) in examples {
+            assert_eq!(b.contains_box(&BoundingBox::new(min, max)), result);
+        }
+    }
+
+    // Scenario: Transforming a bounding box
+    //  Given box ← bounding_box(min=point(-1, -1, -1) max=point(1, 1, 1))
+    //    And matrix ← rotation_x(π / 4) * rotation_y(π / 4)
+    //  When box2 ← transform(box, matrix)
+    //  Then box2.min = point(-1.4142, -1.7071, -1.7071)
+    //    And box2.max = point(1.4142, 1.7071, 1.7071)
+    #[test]
+    fn transforming_bounding_box() {
+        let b = BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        let matrix = Matrix4::rotation_x(FRAC_PI_4) * Matrix4::rotation_y(FRAC_PI_4);
+
+        let box2 = b.transform(&matrix);
+
+        assert_eq!(box2.min, Point::new(-SQRT_2, -1.7071, -1.7071));
+        assert_eq!(box2.max, Point::new(SQRT_2, 1.7071, 1.7071));
+    }
+
+    #[test]
+    fn transforming_unbounded_box_keeps_infinite_extents() {
+        let b = Plane::new().bounds();
+
+        let box2 = b.transform(&Matrix4::translation(1.0, 2.0, 3.0));
+
+        assert_eq!(
+            box2.min,
+            Point::new(Float::NEG_INFINITY, 2.0, Float::NEG_INFINITY)
+        );
+        assert_eq!(box2.max, Point::new(Float::INFINITY, 2.0, Float::INFINITY));
+    }
+
+    // Scenario Outline: Intersecting a ray with a bounding box at the origin
+    //  Given box ← bounding_box(min=point(-1, -1, -1) max=point(1, 1, 1))
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  Then intersects(box, r) is <result>
+    //
+    //  Examples:
+    //    | origin            | direction        | result |
+    //    | point(5, 0.5, 0)  | vector(-1, 0, 0) | true   |
+    //    | point(-5, 0.5, 0) | vector(1, 0, 0)  | true   |
+    //    | point(0.5, 5, 0)  | vector(0, -1, 0) | true   |
+    //    | point(0.5, -5, 0) | vector(0, 1, 0)  | true   |
+    //    | point(0.5, 0, 5)  | vector(0, 0, -1) | true   |
+    //    | point(0.5, 0, -5) | vector(0, 0, 1)  | true   |
+    //    | point(0, 0.5, 0)  | vector(0, 0, 1)  | true   |
+    //    | point(-2, 0, 0)   | vector(2, 4, 6)  | false  |
+    //    | point(0, -2, 0)   | vector(6, 2, 4)  | false  |
+    //    | point(0, 0, -2)   | vector(4, 6, 2)  | false  |
+    //    | point(2, 0, 2)    | vector(0, 0, -1) | false  |
+    //    | point(0, 2, 2)    | vector(0, -1, 0) | false  |
+    //    | point(2, 2, 0)    | vector(-1, 0, 0) | false  |
+    #[test]
+    fn intersecting_ray_with_bounding_box_at_origin() {
+        let b = BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        let examples = [
+            (Point::new(5.0, 0.5, 0.0), Vector::new(-1.0, 0.0, 0.0), true),
+            (Point::new(-5.0, 0.5, 0.0), Vector::new(1.0, 0.0, 0.0), true),
+            (Point::new(0.5, 5.0, 0.0), Vector::new(0.0, -1.0, 0.0), true),
+            (Point::new(0.5, -5.0, 0.0), Vector::new(0.0, 1.0, 0.0), true),
+            (Point::new(0.5, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0), true),
+            (Point::new(0.5, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), true),
+            (Point::new(0.0, 0.5, 0.0), Vector::new(0.0, 0.0, 1.0), true),
+            (
+                Point::new(-2.0, 0.0, 0.0),
+                Vector::new(2.0, 4.0, 6.0),
+                false,
+            ),
+            (
+                Point::new(0.0, -2.0, 0.0),
+                Vector::new(6.0, 2.0, 4.0),
+                false,
+            ),
+            (
+                Point::new(0.0, 0.0, -2.0),
+                Vector::new(4.0, 6.0, 2.0),
+                false,
+            ),
+            (
+                Point::new(2.0, 0.0, 2.0),
+                Vector::new(0.0, 0.0, -1.0),
+                false,
+            ),
+            (
+                Point::new(0.0, 2.0, 2.0),
+                Vector::new(0.0, -1.0, 0.0),
+                false,
+            ),
+            (
+                Point::new(2.0, 2.0, 0.0),
+                Vector::new(-1.0, 0.0, 0.0),
+                false,
+            ),
+        ];
+
+        for (origin, direction, result) in examples {
+            let r = Ray::new(origin, direction.normalize());
+            assert_eq!(b.intersects(&r), result, "{:?}", r);
+        }
+    }
+
+    // Scenario Outline: Intersecting a ray with a non-cubic bounding box
+    //  Given box ← bounding_box(min=point(5, -2, 0) max=point(11, 4, 7))
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  Then intersects(box, r) is <result>
+    //
+    //  Examples:
+    //    | origin           | direction        | result |
+    //    | point(15, 1, 2)  | vector(-1, 0, 0) | true   |
+    //    | point(-5, -1, 4) | vector(1, 0, 0)  | true   |
+    //    | point(7, 6, 5)   | vector(0, -1, 0) | true   |
+    //    | point(9, -5, 6)  | vector(0, 1, 0)  | true   |
+    //    | point(8, 2, 12)  | vector(0, 0, -1) | true   |
+    //    | point(6, 0, -5)  | vector(0, 0, 1)  | true   |
+    //    | point(8, 1, 3.5) | vector(0, 0, 1)  | true   |
+    //    | point(9, -1, -8) | vector(2, 4, 6)  | false  |
+    //    | point(8, 3, -4)  | vector(6, 2, 4)  | false  |
+    //    | point(9, -1, -2) | vector(4, 6, 2)  | false  |
+    //    | point(4, 0, 9)   | vector(0, 0, -1) | false  |
+    //    | point(8, 6, -1)  | vector(0, -1, 0) | false  |
+    //    | point(12, 5, 4)  | vector(-1, 0, 0) | false  |
+    #[test]
+    fn intersecting_ray_with_non_cubic_bounding_box() {
+        let b = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+        let examples = [
+            (
+                Point::new(15.0, 1.0, 2.0),
+                Vector::new(-1.0, 0.0, 0.0),
+                true,
+            ),
+            (
+                Point::new(-5.0, -1.0, 4.0),
+                Vector::new(1.0, 0.0, 0.0),
+                true,
+            ),
+            (Point::new(7.0, 6.0, 5.0), Vector::new(0.0, -1.0, 0.0), true),
+            (Point::new(9.0, -5.0, 6.0), Vector::new(0.0, 1.0, 0.0), true),
+            (
+                Point::new(8.0, 2.0, 12.0),
+                Vector::new(0.0, 0.0, -1.0),
+                true,
+            ),
+            (Point::new(6.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), true),
+            (Point::new(8.0, 1.0, 3.5), Vector::new(0.0, 0.0, 1.0), true),
+            (
+                Point::new(9.0, -1.0, -8.0),
+                Vector::new(2.0, 4.0, 6.0),
+                false,
+            ),
+            (
+                Point::new(8.0, 3.0, -4.0),
+                Vector::new(6.0, 2.0, 4.0),
+                false,
+            ),
+            (
+                Point::new(9.0, -1.0, -2.0),
+                Vector::new(4.0, 6.0, 2.0),
+                false,
+            ),
+            (
+                Point::new(4.0, 0.0, 9.0),
+                Vector::new(0.0, 0.0, -1.0),
+                false,
+            ),
+            (
+                Point::new(8.0, 6.0, -1.0),
+                Vector::new(0.0, -1.0, 0.0),
+                false,
+            ),
+            (
+                Point::new(12.0, 5.0, 4.0),
+                Vector::new(-1.0, 0.0, 0.0),
+                false,
+            ),
+        ];
+
+        for (origin, direction, result) in examples {
+            let r = Ray::new(origin, direction.normalize());
+            assert_eq!(b.intersects(&r), result, "{:?}", r);
+        }
+    }
+
+    #[test]
+    fn empty_box_is_never_intersected() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(!BoundingBox::empty().intersects(&r));
+    }
+
+    // Scenario: Splitting a perfect cube
+    //  Given box ← bounding_box(min=point(-1, -4, -5) max=point(9, 6, 5))
+    //  When (left, right) ← split_bounds(box)
+    //  Then left.min = point(-1, -4, -5)
+    //    And left.max = point(4, 6, 5)
+    //    And right.min = point(4, -4, -5)
+    //    And right.max = point(9, 6, 5)
+    #[test]
+    fn splitting_perfect_cube() {
+        let b = BoundingBox::new(Point::new(-1.0, -4.0, -5.0), Point::new(9.0, 6.0, 5.0));
+
+        let (left, right) = b.split();
+
+        assert_eq!(left.min, Point::new(-1.0, -4.0, -5.0));
+        assert_eq!(left.max, Point::new(4.0, 6.0, 5.0));
+        assert_eq!(right.min, Point::new(4.0, -4.0, -5.0));
+        assert_eq!(right.max, Point::new(9.0, 6.0, 5.0));
+    }
+
+    // Scenario: Splitting an x-wide box
+    //  Given box ← bounding_box(min=point(-1, -2, -3) max=point(9, 5.5, 3))
+    //  When (left, right) ← split_bounds(box)
+    //  Then left.min = point(-1, -2, -3)
+    //    And left.max = point(4, 5.5, 3)
+    //    And right.min = point(4, -2, -3)
+    //    And right.max = point(9, 5.5, 3)
+    #[test]
+    fn splitting_x_wide_box() {
+        let b = BoundingBox::new(Point::new(-1.0, -2.0, -3.0), Point::new(9.0, 5.5, 3.0));
+
+        let (left, right) = b.split();
+
+        assert_eq!(left.min, Point::new(-1.0, -2.0, -3.0));
+        assert_eq!(left.max, Point::new(4.0, 5.5, 3.0));
+        assert_eq!(right.min, Point::new(4.0, -2.0, -3.0));
+        assert_eq!(right.max, Point::new(9.0, 5.5, 3.0));
+    }
+
+    // Scenario: Splitting a y-wide box
+    //  Given box ← bounding_box(min=point(-1, -2, -3) max=point(5, 8, 3))
+    //  When (left, right) ← split_bounds(box)
+    //  Then left.min = point(-1, -2, -3)
+    //    And left.max = point(5, 3, 3)
+    //    And right.min = point(-1, 3, -3)
+    //    And right.max = point(5, 8, 3)
+    #[test]
+    fn splitting_y_wide_box() {
+        let b = BoundingBox::new(Point::new(-1.0, -2.0, -3.0), Point::new(5.0, 8.0, 3.0));
+
+        let (left, right) = b.split();
+
+        assert_eq!(left.min, Point::new(-1.0, -2.0, -3.0));
+        assert_eq!(left.max, Point::new(5.0, 3.0, 3.0));
+        assert_eq!(right.min, Point::new(-1.0, 3.0, -3.0));
+        assert_eq!(right.max, Point::new(5.0, 8.0, 3.0));
+    }
+
+    // Scenario: Splitting a z-wide box
+    //  Given box ← bounding_box(min=point(-1, -2, -3) max=point(5, 3, 7))
+    //  When (left, right) ← split_bounds(box)
+    //  Then left.min = point(-1, -2, -3)
+    //    And left.max = point(5, 3, 2)
+    //    And right.min = point(-1, -2, 2)
+    //    And right.max = point(5, 3, 7)
+    #[test]
+    fn splitting_z_wide_box() {
+        let b = BoundingBox::new(Point::new(-1.0, -2.0, -3.0), Point::new(5.0, 3.0, 7.0));
+
+        let (left, right) = b.split();
+
+        assert_eq!(left.min, Point::new(-1.0, -2.0, -3.0));
+        assert_eq!(left.max, Point::new(5.0, 3.0, 2.0));
+        assert_eq!(right.min, Point::new(-1.0, -2.0, 2.0));
+        assert_eq!(right.max, Point::new(5.0, 3.0, 7.0));
+    }
+}
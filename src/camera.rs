@@ -0,0 +1,718 @@
+use crate::math::consts::{FRAC_PI_2, FRAC_PI_4};
+use crate::prelude::*;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::{sync::mpsc, thread};
+
+/// Maps the pixels of a canvas onto rays shot into a world.
+///
+/// The canvas sits one unit in front of the camera, which looks toward -z in its
+/// own space; the transformation orients it in the world.
+#[derive(Debug, Clone)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: Float,
+    transform: Matrix4,
+    inverse: Matrix4,
+    half_width: Float,
+    half_height: Float,
+    pixel_size: Float,
+    samples_per_pixel: usize,
+    max_sample_brightness: Float,
+    aperture: Float,
+    focal_distance: Float,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: Float) -> Self {
+        let mut camera = Self {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            half_width: 0.0,
+            half_height: 0.0,
+            pixel_size: 0.0,
+            samples_per_pixel: 1,
+            max_sample_brightness: Float::INFINITY,
+            aperture: 0.0,
+            focal_distance: 1.0,
+        };
+        camera.set_size(hsize, vsize);
+        camera
+    }
+
+    /// Changes the size of the canvas, keeping the field of view along its longer side and
+    /// every other setting.
+    pub fn set_size(&mut self, hsize: usize, vsize: usize) {
+        let half_view = (self.field_of_view / 2.0).tan();
+        let aspect = hsize as Float / vsize as Float;
+        let (half_width, half_height) = if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        self.hsize = hsize;
+        self.vsize = vsize;
+        self.half_width = half_width;
+        self.half_height = half_height;
+        self.pixel_size = half_width * 2.0 / hsize as Float;
+    }
+
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+
+    pub fn field_of_view(&self) -> Float {
+        self.field_of_view
+    }
+
+    /// World-space size of a pixel on the canvas.
+    pub fn pixel_size(&self) -> Float {
+        self.pixel_size
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    /// Sets the world-to-camera transformation, usually a [`Matrix4::view_transform`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `transform` is not invertible.
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.try_set_transform(transform)
+            .expect("camera transformations must be invertible");
+    }
+
+    /// Sets the transformation like [`Camera::set_transform`], failing instead of panicking
+    /// when it is not invertible.
+    pub fn try_set_transform(&mut self, transform: Matrix4) -> Result<()> {
+        self.inverse = transform.inverse()?;
+        self.transform = transform;
+        Ok(())
+    }
+
+    /// Number of samples averaged along each side of a pixel, 1 when anti-aliasing is off.
+    pub fn samples_per_pixel(&self) -> usize {
+        self.samples_per_pixel
+    }
+
+    /// Anti-aliases renders by averaging `samples` × `samples` rays spread evenly over
+    /// each pixel, instead of a single ray through its center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is 0.
+    pub fn set_samples_per_pixel(&mut self, samples: usize) {
+        self.try_set_samples_per_pixel(samples)
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    /// Sets the samples like [`Camera::set_samples_per_pixel`], failing instead of
+    /// panicking when there are none.
+    pub fn try_set_samples_per_pixel(&mut self, samples: usize) -> Result<()> {
+        if samples == 0 {
+            return Err(RustracerError::InvalidArgument(
+                "pixels need at least one sample",
+            ));
+        }
+        self.samples_per_pixel = samples;
+        Ok(())
+    }
+
+    /// Brightest any component of a sample may be, infinite unless set with
+    /// [`Camera::set_max_sample_brightness`].
+    pub fn max_sample_brightness(&self) -> Float {
+        self.max_sample_brightness
+    }
+
+    /// Keeps a few very bright samples, like a highlight caught by a single ray, from
+    /// leaving blinding white pixels: samples with a component brighter than `max` are
+    /// dimmed down to it, keeping their hue, before being averaged.
+    ///
+    /// Only pixels taking several samples are affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is not positive.
+    pub fn set_max_sample_brightness(&mut self, max: Float) {
+        self.try_set_max_sample_brightness(max)
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    /// Sets the brightness like [`Camera::set_max_sample_brightness`], failing instead of
+    /// panicking when `max` is not positive.
+    pub fn try_set_max_sample_brightness(&mut self, max: Float) -> Result<()> {
+        if max <= 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the maximum sample brightness must be positive",
+            ));
+        }
+        self.max_sample_brightness = max;
+        Ok(())
+    }
+
+    /// Radius of the lens, 0 for a pinhole camera keeping everything in focus.
+    pub fn aperture(&self) -> Float {
+        self.aperture
+    }
+
+    /// Distance from the camera to the plane in perfect focus.
+    pub fn focal_distance(&self) -> Float {
+        self.focal_distance
+    }
+
+    /// Blurs what is not `focal_distance` away from the camera, by shooting rays from
+    /// across a lens of radius `aperture` instead of from a single point.
+    ///
+    /// Each of the samples set with [`Camera::set_samples_per_pixel`] starts from a
+    /// different point of the lens, so the blur gets smoother as they increase; with a
+    /// single sample, rays start from the center of the lens and nothing is blurred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `aperture` is negative or `focal_distance` is not positive.
+    pub fn set_depth_of_field(&mut self, aperture: Float, focal_distance: Float) {
+        self.try_set_depth_of_field(aperture, focal_distance)
+            .unwrap_or_else(|err| panic!("{}", err));
+    }
+
+    /// Sets the depth of field like [`Camera::set_depth_of_field`], failing instead of
+    /// panicking on a bad `aperture` or `focal_distance`.
+    pub fn try_set_depth_of_field(&mut self, aperture: Float, focal_distance: Float) -> Result<()> {
+        if aperture < 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the aperture cannot be negative",
+            ));
+        }
+        if focal_distance <= 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the focal distance must be positive",
+            ));
+        }
+        self.aperture = aperture;
+        self.focal_distance = focal_distance;
+        Ok(())
+    }
+
+    /// Ray from the camera through the center of the pixel at column `px` and row `py`.
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        self.ray_through(px as Float + 0.5, py as Float + 0.5)
+    }
+
+    /// Ray from the camera through a point of the canvas, in pixels from its top left corner.
+    fn ray_through(&self, x: Float, y: Float) -> Ray {
+        self.ray_through_lens(x, y, (0.0, 0.0))
+    }
+
+    /// Ray like [`Camera::ray_through`], starting from a point of the lens given by its
+    /// coordinates on the unit disc.
+    fn ray_through_lens(&self, x: Float, y: Float, (lens_x, lens_y): (Float, Float)) -> Ray {
+        let xoffset = x * self.pixel_size;
+        let yoffset = y * self.pixel_size;
+
+        // The camera looks toward -z, so +x is to the left.
+        let world_x = self.half_width - xoffset;
+        let world_y = self.half_height - yoffset;
+
+        // Rays from every point of the lens meet again on the focal plane, which is
+        // the canvas pushed away from the camera.
+        let d = self.focal_distance;
+        let focus = self.inverse * Point::new(world_x * d, world_y * d, -d);
+        let origin = self.inverse * Point::new(lens_x * self.aperture, lens_y * self.aperture, 0.0);
+        Ray::new(origin, (focus - origin).normalize())
+    }
+
+    pub fn render(&self, world: &World) -> Canvas {
+        self.render_progressive(world, |_, _| {})
+    }
+
+    /// Renders like [`Camera::render`], calling `on_row` with the index and pixels of
+    /// every row as soon as it is done, e.g. to show a preview or report progress.
+    pub fn render_progressive<F: FnMut(usize, &[Color])>(
+        &self,
+        world: &World,
+        mut on_row: F,
+    ) -> Canvas {
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        for y in 0..self.vsize {
+            let row = self.render_row(world, y);
+            write_row(&mut image, y, &row);
+            on_row(y, &row);
+        }
+        image
+    }
+
+    /// Renders like [`Camera::render`], splitting the rows between `num_threads` threads.
+    ///
+    /// With `num_threads` set to 0, one thread is used per available core.
+    #[cfg(feature = "std")]
+    pub fn render_parallel(&self, world: &World, num_threads: usize) -> Canvas {
+        self.render_parallel_progressive(world, num_threads, |_, _| {})
+    }
+
+    /// Renders like [`Camera::render_parallel`], calling `on_row` like
+    /// [`Camera::render_progressive`] does.
+    ///
+    /// `on_row` runs on the calling thread, and rows arrive in the order they finish
+    /// rather than from top to bottom.
+    #[cfg(feature = "std")]
+    pub fn render_parallel_progressive<F: FnMut(usize, &[Color])>(
+        &self,
+        world: &World,
+        num_threads: usize,
+        mut on_row: F,
+    ) -> Canvas {
+        let num_threads = match num_threads {
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        };
+
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        // Threads take the next row as they finish one, so that rows hitting complex
+        // geometry do not hold the others back.
+        let next_row = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for _ in 0..num_threads {
+                let sender = sender.clone();
+                let next_row = &next_row;
+                scope.spawn(move || loop {
+                    let y = next_row.fetch_add(1, Ordering::Relaxed);
+                    if y >= self.vsize || sender.send((y, self.render_row(world, y))).is_err() {
+                        break;
+                    }
+                });
+            }
+            // Only the workers' senders remain, so the loop ends once they are all done.
+            drop(sender);
+
+            for (y, row) in receiver {
+                write_row(&mut image, y, &row);
+                on_row(y, &row);
+            }
+        });
+        image
+    }
+
+    fn render_row(&self, world: &World, y: usize) -> Vec<Color> {
+        (0..self.hsize)
+            .map(|x| self.color_at_pixel(world, x, y))
+            .collect()
+    }
+
+    fn color_at_pixel(&self, world: &World, px: usize, py: usize) -> Color {
+        let samples = self.samples_per_pixel;
+        if samples == 1 {
+            return world.color_at(&self.ray_for_pixel(px, py));
+        }
+
+        // Stratified sampling: one ray through the center of each cell of a grid
+        // dividing the pixel.
+        let step = 1.0 / samples as Float;
+        let count = samples * samples;
+        let mut sum = Color::black();
+        for j in 0..samples {
+            for i in 0..samples {
+                let x = px as Float + (i as Float + 0.5) * step;
+                let y = py as Float + (j as Float + 0.5) * step;
+                let ray = if self.aperture == 0.0 {
+                    self.ray_through(x, y)
+                } else {
+                    self.ray_through_lens(x, y, lens_sample(j * samples + i, count))
+                };
+                sum = sum + self.clamp_sample(world.color_at(&ray));
+            }
+        }
+        sum * (step * step)
+    }
+
+    /// `color` scaled down so that none of its components is brighter than allowed.
+    fn clamp_sample(&self, color: Color) -> Color {
+        let brightest = color.r.max(color.g).max(color.b);
+        if brightest > self.max_sample_brightness {
+            color * (self.max_sample_brightness / brightest)
+        } else {
+            color
+        }
+    }
+}
+
+/// Point `index` of `count` spread evenly over the unit disc, for sampling the lens.
+///
+/// The points form a Hammersley set, which covers the square more evenly than a grid
+/// of the same size and does not line up with the grid of samples over the pixel. They
+/// are then mapped onto the disc with Shirley's concentric mapping, which keeps them
+/// evenly spread.
+fn lens_sample(index: usize, count: usize) -> (Float, Float) {
+    let u = (index as Float + 0.5) / count as Float;
+    let v = (index as u32).reverse_bits() as Float / (1u64 << 32) as Float;
+
+    let (a, b) = (2.0 * u - 1.0, 2.0 * v - 1.0);
+    if a == 0.0 && b == 0.0 {
+        return (0.0, 0.0);
+    }
+    let (r, theta) = if a.abs() > b.abs() {
+        (a, FRAC_PI_4 * (b / a))
+    } else {
+        (b, FRAC_PI_2 - FRAC_PI_4 * (a / b))
+    };
+    (r * theta.cos(), r * theta.sin())
+}
+
+fn write_row(image: &mut Canvas, y: usize, row: &[Color]) {
+    for (x, &color) in row.iter().enumerate() {
+        image.write_pixel(x, y, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_1_SQRT_2, PI};
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+
+    // Scenario: Constructing a camera
+    //  Given hsize ← 160
+    //    And vsize ← 120
+    //    And field_of_view ← π/2
+    //  When c ← camera(hsize, vsize, field_of_view)
+    //  Then c.hsize = 160
+    //    And c.vsize = 120
+    //    And c.field_of_view = π/2
+    //    And c.transform = identity_matrix
+    #[test]
+    fn constructing_camera() {
+        let c = Camera::new(160, 120, PI / 2.0);
+
+        assert_eq!(c.hsize(), 160);
+        assert_eq!(c.vsize(), 120);
+        assert_eq!(c.field_of_view(), PI / 2.0);
+        assert_eq!(*c.transform(), Matrix4::identity());
+    }
+
+    // Scenario: The pixel size for a horizontal canvas
+    //  Given c ← camera(200, 125, π/2)
+    //  Then c.pixel_size = 0.01
+    #[test]
+    fn pixel_size_for_horizontal_canvas() {
+        let c = Camera::new(200, 125, PI / 2.0);
+
+        assert!(is_approx(c.pixel_size(), 0.01, None));
+    }
+
+    // Scenario: The pixel size for a vertical canvas
+    //  Given c ← camera(125, 200, π/2)
+    //  Then c.pixel_size = 0.01
+    #[test]
+    fn pixel_size_for_vertical_canvas() {
+        let c = Camera::new(125, 200, PI / 2.0);
+
+        assert!(is_approx(c.pixel_size(), 0.01, None));
+    }
+
+    // Scenario: Constructing a ray through the center of the canvas
+    //  Given c ← camera(201, 101, π/2)
+    //  When r ← ray_for_pixel(c, 100, 50)
+    //  Then r.origin = point(0, 0, 0)
+    //    And r.direction = vector(0, 0, -1)
+    #[test]
+    fn constructing_ray_through_center_of_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+        let r = c.ray_for_pixel(100, 50);
+
+        assert_eq!(r.origin, Point::origin());
+        assert_eq!(r.direction, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    // Scenario: Constructing a ray through a corner of the canvas
+    //  Given c ← camera(201, 101, π/2)
+    //  When r ← ray_for_pixel(c, 0, 0)
+    //  Then r.origin = point(0, 0, 0)
+    //    And r.direction = vector(0.66519, 0.33259, -0.66851)
+    #[test]
+    fn constructing_ray_through_corner_of_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+        let r = c.ray_for_pixel(0, 0);
+
+        assert_eq!(r.origin, Point::origin());
+        assert_eq!(r.direction, Vector::new(0.66519, 0.33259, -0.66851));
+    }
+
+    // Scenario: Constructing a ray when the camera is transformed
+    //  Given c ← camera(201, 101, π/2)
+    //  When c.transform ← rotation_y(π/4) * translation(0, -2, 5)
+    //    And r ← ray_for_pixel(c, 100, 50)
+    //  Then r.origin = point(0, 2, -5)
+    //    And r.direction = vector(√2/2, 0, -√2/2)
+    #[test]
+    fn constructing_ray_when_camera_is_transformed() {
+        let mut c = Camera::new(201, 101, PI / 2.0);
+        c.set_transform(Matrix4::rotation_y(PI / 4.0) * Matrix4::translation(0.0, -2.0, 5.0));
+        let r = c.ray_for_pixel(100, 50);
+
+        assert_eq!(r.origin, Point::new(0.0, 2.0, -5.0));
+        assert_eq!(r.direction, Vector::new(FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2));
+    }
+
+    // Scenario: Rendering a world with a camera
+    //  Given w ← default_world()
+    //    And c ← camera(11, 11, π/2)
+    //    And from ← point(0, 0, -5)
+    //    And to ← point(0, 0, 0)
+    //    And up ← vector(0, 1, 0)
+    //    And c.transform ← view_transform(from, to, up)
+    //  When image ← render(c, w)
+    //  Then pixel_at(image, 5, 5) = color(0.38066, 0.47583, 0.2855)
+    #[test]
+    fn rendering_world_with_camera() {
+        let w = World::default();
+        let mut c = Camera::new(11, 11, PI / 2.0);
+        let from = Point::new(0.0, 0.0, -5.0);
+        let to = Point::origin();
+        let up = Vector::new(0.0, 1.0, 0.0);
+        c.set_transform(Matrix4::view_transform(from, to, up));
+
+        let image = c.render(&w);
+
+        assert_eq!(image.pixel_at(5, 5), Color::new(0.38066, 0.47583, 0.2855));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rendering_in_parallel_matches_sequential_rendering() {
+        let w = World::default();
+        let mut c = Camera::new(11, 7, PI / 2.0);
+        c.set_transform(Matrix4::view_transform(
+            Point::new(0.0, 0.0, -5.0),
+            Point::origin(),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+
+        let expected = c.render(&w);
+
+        for num_threads in [0, 1, 3, 16] {
+            let image = c.render_parallel(&w, num_threads);
+            assert_eq!(image.pixels(), expected.pixels(), "{} threads", num_threads);
+        }
+    }
+
+    #[test]
+    fn rendering_progressively_reports_every_row() {
+        let w = World::default();
+        let c = Camera::new(5, 4, PI / 2.0);
+        let mut rows = Vec::new();
+
+        let image = c.render_progressive(&w, |y, pixels| rows.push((y, pixels.to_vec())));
+
+        assert_eq!(rows.len(), 4);
+        for (i, (y, pixels)) in rows.iter().enumerate() {
+            assert_eq!(*y, i);
+            assert_eq!(pixels.len(), 5);
+            for (x, &color) in pixels.iter().enumerate() {
+                assert_eq!(color, image.pixel_at(x, *y));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rendering_in_parallel_progressively_reports_every_row_once() {
+        let w = World::default();
+        let c = Camera::new(5, 9, PI / 2.0);
+        let mut seen = vec![0; 9];
+
+        let image = c.render_parallel_progressive(&w, 3, |y, pixels| {
+            seen[y] += 1;
+            assert_eq!(pixels.len(), 5);
+        });
+
+        assert_eq!(seen, vec![1; 9]);
+        assert_eq!(image.pixels(), c.render(&w).pixels());
+    }
+
+    #[test]
+    fn camera_takes_one_sample_per_pixel_by_default() {
+        let c = Camera::new(160, 120, PI / 2.0);
+
+        assert_eq!(c.samples_per_pixel(), 1);
+    }
+
+    #[test]
+    fn supersampling_averages_a_grid_of_rays_over_each_pixel() {
+        let w = World::default();
+        let mut c = Camera::new(11, 11, PI / 2.0);
+        c.set_transform(Matrix4::view_transform(
+            Point::new(0.0, 0.0, -5.0),
+            Point::origin(),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+        c.set_samples_per_pixel(2);
+
+        let image = c.render(&w);
+
+        // The sphere's silhouette crosses pixel (4, 5), which gets a blend of the hits
+        // and misses among the four rays going through it.
+        let mut expected = Color::black();
+        for &(x, y) in &[(4.25, 5.25), (4.75, 5.25), (4.25, 5.75), (4.75, 5.75)] {
+            expected = expected + w.color_at(&c.ray_through(x, y)) * 0.25;
+        }
+        assert_eq!(image.pixel_at(4, 5), expected);
+        assert_ne!(expected, Color::black());
+        assert_ne!(expected, w.color_at(&c.ray_for_pixel(4, 5)));
+    }
+
+    #[test]
+    fn clamping_dims_bright_samples_before_averaging() {
+        let w = World::default();
+        let mut c = Camera::new(11, 11, PI / 2.0);
+        c.set_transform(Matrix4::view_transform(
+            Point::new(0.0, 0.0, -5.0),
+            Point::origin(),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+        c.set_samples_per_pixel(2);
+        assert_eq!(c.max_sample_brightness(), Float::INFINITY);
+
+        c.set_max_sample_brightness(0.3);
+        let image = c.render(&w);
+
+        let mut expected = Color::black();
+        for &(x, y) in &[(4.25, 5.25), (4.75, 5.25), (4.25, 5.75), (4.75, 5.75)] {
+            let sample = w.color_at(&c.ray_through(x, y));
+            let brightest = sample.r.max(sample.g).max(sample.b);
+            expected = expected + sample * (0.25 * (0.3 / brightest).min(1.0));
+        }
+        assert_eq!(image.pixel_at(4, 5), expected);
+        let center = image.pixel_at(5, 5);
+        assert!(is_approx(center.g, 0.3, None));
+        assert!(center.r < center.g);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_max_sample_brightness_panics() {
+        Camera::new(160, 120, PI / 2.0).set_max_sample_brightness(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_samples_per_pixel_panics() {
+        Camera::new(160, 120, PI / 2.0).set_samples_per_pixel(0);
+    }
+
+    #[test]
+    fn resizing_camera_keeps_its_settings() {
+        let mut c = Camera::new(160, 120, PI / 2.0);
+        c.set_transform(Matrix4::translation(0.0, -2.0, 5.0));
+        c.set_depth_of_field(0.5, 3.0);
+        c.set_samples_per_pixel(4);
+
+        c.set_size(200, 125);
+
+        assert_eq!(c.hsize(), 200);
+        assert_eq!(c.vsize(), 125);
+        assert!(is_approx(c.pixel_size(), 0.01, None));
+        assert_eq!(*c.transform(), Matrix4::translation(0.0, -2.0, 5.0));
+        assert_eq!(c.aperture(), 0.5);
+        assert_eq!(c.focal_distance(), 3.0);
+        assert_eq!(c.samples_per_pixel(), 4);
+    }
+
+    #[test]
+    fn camera_is_a_pinhole_by_default() {
+        let c = Camera::new(160, 120, PI / 2.0);
+
+        assert_eq!(c.aperture(), 0.0);
+        assert_eq!(c.focal_distance(), 1.0);
+    }
+
+    #[test]
+    fn lens_rays_converge_on_focal_plane() {
+        let mut c = Camera::new(201, 101, PI / 2.0);
+        c.set_transform(Matrix4::translation(0.0, -2.0, 5.0));
+        c.set_depth_of_field(0.5, 4.0);
+        // The camera looks toward -z, so the focal plane is 4 units away along it.
+        let center = c.ray_for_pixel(20, 30);
+        let focus = center.position(4.0 / -center.direction.z);
+
+        for &lens in &[(1.0, 0.0), (0.0, -1.0), (-0.6, 0.8)] {
+            let r = c.ray_through_lens(20.5, 30.5, lens);
+            let t = (focus - r.origin).magnitude();
+
+            assert_eq!(r.position(t), focus);
+            assert_ne!(r.origin, c.ray_for_pixel(20, 30).origin);
+        }
+    }
+
+    #[test]
+    fn lens_samples_cover_the_unit_disc() {
+        let samples: Vec<(Float, Float)> = (0..16).map(|i| super::lens_sample(i, 16)).collect();
+
+        assert!(samples.iter().all(|&(x, y)| x * x + y * y <= 1.0 + EPSILON));
+        for &(sx, sy) in &[(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)] {
+            assert!(samples.iter().any(|&(x, y)| x * sx > 0.0 && y * sy > 0.0));
+        }
+    }
+
+    #[test]
+    fn depth_of_field_blurs_objects_away_from_focal_plane() {
+        let w = World::default();
+        let mut c = Camera::new(11, 11, PI / 2.0);
+        c.set_transform(Matrix4::view_transform(
+            Point::new(0.0, 0.0, -5.0),
+            Point::origin(),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+        c.set_samples_per_pixel(3);
+        let sharp = c.render(&w);
+
+        // In focus on the front of the sphere, a small lens changes little...
+        c.set_depth_of_field(0.05, 4.0);
+        let focused = c.render(&w);
+        // ...while a wide one focused far behind it smears its silhouette.
+        c.set_depth_of_field(1.0, 20.0);
+        let blurred = c.render(&w);
+
+        let difference = |a: &Canvas, b: &Canvas| {
+            a.pixels()
+                .iter()
+                .zip(b.pixels())
+                .map(|(p, q)| (p.r - q.r).abs() + (p.g - q.g).abs())
+                .sum::<Float>()
+        };
+        assert!(difference(&focused, &sharp) < difference(&blurred, &sharp));
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_aperture_panics() {
+        Camera::new(160, 120, PI / 2.0).set_depth_of_field(-1.0, 1.0);
+    }
+
+    #[test]
+    fn trying_bad_settings_keeps_previous_ones() {
+        let mut c = Camera::new(160, 120, PI / 2.0);
+
+        assert!(matches!(
+            c.try_set_samples_per_pixel(0),
+            Err(RustracerError::InvalidArgument(_))
+        ));
+        assert!(c.try_set_max_sample_brightness(-1.0).is_err());
+        assert!(c.try_set_depth_of_field(0.5, 0.0).is_err());
+        assert_eq!(c.samples_per_pixel(), 1);
+        assert_eq!(c.max_sample_brightness(), Float::INFINITY);
+        assert_eq!(c.aperture(), 0.0);
+    }
+}
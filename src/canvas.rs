@@ -0,0 +1,269 @@
+use crate::prelude::*;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+const PPM_MAX_LINE_LENGTH: usize = 70;
+
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Color::black(); width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Sets the pixel at column `x` and row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are outside the canvas.
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Color) {
+        let index = self.index(x, y);
+        self.pixels[index] = color;
+    }
+
+    /// Returns the pixel at column `x` and row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are outside the canvas.
+    pub fn pixel_at(&self, x: usize, y: usize) -> Color {
+        self.pixels[self.index(x, y)]
+    }
+
+    pub fn fill(&mut self, color: Color) {
+        self.pixels.iter_mut().for_each(|p| *p = color);
+    }
+
+    pub fn clear(&mut self) {
+        self.fill(Color::black());
+    }
+
+    /// Pixels in row-major order, starting from the top-left corner.
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = String::new();
+        let _ = write!(ppm, "P3\n{} {}\n255\n", self.width, self.height);
+
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let mut line = String::new();
+            for component in row.iter().flat_map(|c| [c.r, c.g, c.b]) {
+                let value = to_byte(component);
+                let digits = if value >= 100 {
+                    3
+                } else if value >= 10 {
+                    2
+                } else {
+                    1
+                };
+
+                if !line.is_empty() && line.len() + 1 + digits > PPM_MAX_LINE_LENGTH {
+                    ppm.push_str(&line);
+                    ppm.push('\n');
+                    line.clear();
+                }
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                let _ = write!(line, "{}", value);
+            }
+            ppm.push_str(&line);
+            ppm.push('\n');
+        }
+
+        ppm
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({}, {}) is outside of a {}x{} canvas",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+}
+
+/// Scales a color component from [0, 1] to [0, 255], clamping values outside that range.
+pub fn to_byte(component: Float) -> u8 {
+    let scaled = component * 255.0 + 0.5;
+    if scaled <= 0.0 {
+        0
+    } else if scaled >= 255.0 {
+        255
+    } else {
+        scaled as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // Scenario: Creating a canvas
+    //  Given c ← canvas(10, 20)
+    //   Then c.width = 10
+    //    And c.height = 20
+    //    And every pixel of c is color(0, 0, 0)
+    #[test]
+    fn creating_canvas() {
+        let c = Canvas::new(10, 20);
+
+        assert_eq!(c.width(), 10);
+        assert_eq!(c.height(), 20);
+        assert!(c.pixels().iter().all(|&p| p == Color::black()));
+    }
+
+    // Scenario: Writing pixels to a canvas
+    //  Given c ← canvas(10, 20)
+    //    And red ← color(1, 0, 0)
+    //   When write_pixel(c, 2, 3, red)
+    //   Then pixel_at(c, 2, 3) = red
+    #[test]
+    fn writing_pixels_to_canvas() {
+        let mut c = Canvas::new(10, 20);
+        let red = Color::new(1.0, 0.0, 0.0);
+
+        c.write_pixel(2, 3, red);
+
+        assert_eq!(c.pixel_at(2, 3), red);
+    }
+
+    #[test]
+    #[should_panic]
+    fn writing_pixel_outside_canvas_panics() {
+        let mut c = Canvas::new(10, 20);
+
+        c.write_pixel(10, 0, Color::white());
+    }
+
+    #[test]
+    fn filling_and_clearing_canvas() {
+        let mut c = Canvas::new(4, 3);
+        let color = Color::new(0.2, 0.4, 0.6);
+
+        c.fill(color);
+        assert!(c.pixels().iter().all(|&p| p == color));
+
+        c.clear();
+        assert!(c.pixels().iter().all(|&p| p == Color::black()));
+    }
+
+    // Scenario: Constructing the PPM header
+    //  Given c ← canvas(5, 3)
+    //   When ppm ← canvas_to_ppm(c)
+    //   Then lines 1-3 of ppm are
+    //     """
+    //     P3
+    //     5 3
+    //     255
+    //     """
+    #[test]
+    fn constructing_ppm_header() {
+        let c = Canvas::new(5, 3);
+        let ppm = c.to_ppm();
+
+        let header: Vec<&str> = ppm.lines().take(3).collect();
+        assert_eq!(header, vec!["P3", "5 3", "255"]);
+    }
+
+    // Scenario: Constructing the PPM pixel data
+    //  Given c ← canvas(5, 3)
+    //    And c1 ← color(1.5, 0, 0)
+    //    And c2 ← color(0, 0.5, 0)
+    //    And c3 ← color(-0.5, 0, 1)
+    //   When write_pixel(c, 0, 0, c1)
+    //    And write_pixel(c, 2, 1, c2)
+    //    And write_pixel(c, 4, 2, c3)
+    //    And ppm ← canvas_to_ppm(c)
+    //   Then lines 4-6 of ppm are
+    //     """
+    //     255 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+    //     0 0 0 0 0 0 0 128 0 0 0 0 0 0 0
+    //     0 0 0 0 0 0 0 0 0 0 0 0 0 0 255
+    //     """
+    #[test]
+    fn constructing_ppm_pixel_data() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Color::new(1.5, 0.0, 0.0));
+        c.write_pixel(2, 1, Color::new(0.0, 0.5, 0.0));
+        c.write_pixel(4, 2, Color::new(-0.5, 0.0, 1.0));
+        let ppm = c.to_ppm();
+
+        let data: Vec<&str> = ppm.lines().skip(3).take(3).collect();
+        assert_eq!(
+            data,
+            vec![
+                "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+            ]
+        );
+    }
+
+    // Scenario: Splitting long lines in PPM files
+    //  Given c ← canvas(10, 2)
+    //   When every pixel of c is set to color(1, 0.8, 0.6)
+    //    And ppm ← canvas_to_ppm(c)
+    //   Then lines 4-7 of ppm are
+    //     """
+    //     255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204
+    //     153 255 204 153 255 204 153 255 204 153 255 204 153
+    //     255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204
+    //     153 255 204 153 255 204 153 255 204 153 255 204 153
+    //     """
+    #[test]
+    fn splitting_long_lines_in_ppm() {
+        let mut c = Canvas::new(10, 2);
+        c.fill(Color::new(1.0, 0.8, 0.6));
+        let ppm = c.to_ppm();
+
+        let data: Vec<&str> = ppm.lines().skip(3).take(4).collect();
+        assert_eq!(
+            data,
+            vec![
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            ]
+        );
+    }
+
+    // Scenario: PPM files are terminated by a newline character
+    //  Given c ← canvas(5, 3)
+    //   When ppm ← canvas_to_ppm(c)
+    //   Then ppm ends with a newline character
+    #[test]
+    fn ppm_files_are_terminated_by_newline() {
+        let c = Canvas::new(5, 3);
+
+        assert!(c.to_ppm().ends_with('\n'));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use core::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Color {
+    pub r: Float,
+    pub g: Float,
+    pub b: Float,
+}
+
+impl Color {
+    pub const fn new(r: Float, g: Float, b: Float) -> Self {
+        Self { r, g, b }
+    }
+
+    pub const fn black() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    pub const fn white() -> Self {
+        Self::new(1.0, 1.0, 1.0)
+    }
+
+    /// Blends two colors component by component (Hadamard or Schur product).
+    pub fn hadamard_product(&self, rhs: &Self) -> Self {
+        Self::new(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b)
+    }
+}
+
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        is_approx(self.r, other.r, None)
+            && is_approx(self.g, other.g, None)
+            && is_approx(self.b, other.b, None)
+    }
+}
+
+impl Add for Color {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b)
+    }
+}
+
+impl Sub for Color {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b)
+    }
+}
+
+impl Mul<Float> for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: Float) -> Self::Output {
+        Self::new(self.r * rhs, self.g * rhs, self.b * rhs)
+    }
+}
+
+impl Mul for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.hadamard_product(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: Colors are (red, green, blue) tuples
+    //  Given c ← color(-0.5, 0.4, 1.7)
+    //   Then c.red = -0.5
+    //    And c.green = 0.4
+    //    And c.blue = 1.7
+    #[test]
+    fn colors_are_red_green_blue_tuples() {
+        let c = Color::new(-0.5, 0.4, 1.7);
+
+        assert_eq!(c.r, -0.5);
+        assert_eq!(c.g, 0.4);
+        assert_eq!(c.b, 1.7);
+    }
+
+    // Scenario: Adding colors
+    //  Given c1 ← color(0.9, 0.6, 0.75)
+    //    And c2 ← color(0.7, 0.1, 0.25)
+    //   Then c1 + c2 = color(1.6, 0.7, 1.0)
+    #[test]
+    fn adding_colors() {
+        let c1 = Color::new(0.9, 0.6, 0.75);
+        let c2 = Color::new(0.7, 0.1, 0.25);
+        let expected = Color::new(1.6, 0.7, 1.0);
+
+        assert_eq!(c1 + c2, expected);
+    }
+
+    // Scenario: Subtracting colors
+    //  Given c1 ← color(0.9, 0.6, 0.75)
+    //    And c2 ← color(0.7, 0.1, 0.25)
+    //   Then c1 - c2 = color(0.2, 0.5, 0.5)
+    #[test]
+    fn subtracting_colors() {
+        let c1 = Color::new(0.9, 0.6, 0.75);
+        let c2 = Color::new(0.7, 0.1, 0.25);
+        let expected = Color::new(0.2, 0.5, 0.5);
+
+        assert_eq!(c1 - c2, expected);
+    }
+
+    // Scenario: Multiplying a color by a scalar
+    //  Given c ← color(0.2, 0.3, 0.4)
+    //  Then c * 2 = color(0.4, 0.6, 0.8)
+    #[test]
+    fn multiplying_color_by_scalar() {
+        let c = Color::new(0.2, 0.3, 0.4);
+        let expected = Color::new(0.4, 0.6, 0.8);
+
+        assert_eq!(c * 2.0, expected);
+    }
+
+    // Scenario: Multiplying colors
+    //  Given c1 ← color(1, 0.2, 0.4)
+    //    And c2 ← color(0.9, 1, 0.1)
+    //   Then c1 * c2 = color(0.9, 0.2, 0.04)
+    #[test]
+    fn multiplying_colors() {
+        let c1 = Color::new(1.0, 0.2, 0.4);
+        let c2 = Color::new(0.9, 1.0, 0.1);
+        let expected = Color::new(0.9, 0.2, 0.04);
+
+        assert_eq!(c1 * c2, expected);
+        assert_eq!(c1.hadamard_product(&c2), expected);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::cylinder::check_cap;
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A double-napped cone around the y axis of its object space, whose radius at any `y`
+/// is `|y|`.
+///
+/// It extends infinitely unless truncated to `minimum < y < maximum`, in which case the
+/// ends are left open unless `closed` is set.
+#[derive(Debug, Clone)]
+pub struct Cone {
+    properties: ShapeProperties,
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Self::truncated(Float::NEG_INFINITY, Float::INFINITY, false)
+    }
+
+    pub fn truncated(minimum: Float, maximum: Float, closed: bool) -> Self {
+        Self {
+            properties: ShapeProperties::new(),
+            minimum,
+            maximum,
+            closed,
+        }
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // Caps only matter when closed, and a ray parallel to them can never cross them.
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        for &y in &[self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t, y.abs()) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+
+    fn push_if_within_bounds<'a>(&'a self, ray: &Ray, t: Float, xs: &mut Vec<Intersection<'a>>) {
+        let y = ray.origin.y + t * ray.direction.y;
+        if self.minimum < y && y < self.maximum {
+            xs.push(Intersection::new(t, self));
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Cone {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let (o, d) = (ray.origin, ray.direction);
+        let a = d.x * d.x - d.y * d.y + d.z * d.z;
+        let b = 2.0 * o.x * d.x - 2.0 * o.y * d.y + 2.0 * o.z * d.z;
+        let c = o.x * o.x - o.y * o.y + o.z * o.z;
+
+        let mut xs = Vec::new();
+        if a.abs() < EPSILON {
+            // Parallel to one of the halves, so the ray crosses the other one only once.
+            if b.abs() >= EPSILON {
+                self.push_if_within_bounds(ray, -c / (2.0 * b), &mut xs);
+            }
+        } else {
+            // Rays grazing the surface can round to a slightly negative discriminant.
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant < -EPSILON {
+                return Intersections::default();
+            }
+
+            let root = discriminant.max(0.0).sqrt();
+            self.push_if_within_bounds(ray, (-b - root) / (2.0 * a), &mut xs);
+            self.push_if_within_bounds(ray, (-b + root) / (2.0 * a), &mut xs);
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let dist = point.x * point.x + point.z * point.z;
+
+        if dist < point.y * point.y && point.y >= self.maximum - EPSILON {
+            Vector::new(0.0, 1.0, 0.0)
+        } else if dist < point.y * point.y && point.y <= self.minimum + EPSILON {
+            Vector::new(0.0, -1.0, 0.0)
+        } else {
+            let y = dist.sqrt();
+            Vector::new(point.x, if point.y > 0.0 { -y } else { y }, point.z)
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        // The radius equals |y|, so the widest end sets the extent along x and z.
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        BoundingBox::new(
+            Point::new(-limit, self.minimum, -limit),
+            Point::new(limit, self.maximum, limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::math::consts::SQRT_2;
+
+    // Scenario Outline: Intersecting a cone with a ray
+    //  Given shape ← cone()
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  When xs ← local_intersect(shape, r)
+    //  Then xs.count = 2
+    //    And xs[0].t = <t0>
+    //    And xs[1].t = <t1>
+    //
+    //  Examples:
+    //    | origin          | direction           | t0      | t1       |
+    //    | point(0, 0, -5) | vector(0, 0, 1)     | 5       |  5       |
+    //    | point(0, 0, -5) | vector(1, 1, 1)     | 8.66025 |  8.66025 |
+    //    | point(1, 1, -5) | vector(-0.5, -1, 1) | 4.55006 | 49.44994 |
+    #[test]
+    fn intersecting_cone_with_ray() {
+        let shape = Cone::new();
+        let examples = [
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(0.0, 0.0, 1.0),
+                5.0,
+                5.0,
+            ),
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(1.0, 1.0, 1.0),
+                8.66025,
+                8.66025,
+            ),
+            (
+                Point::new(1.0, 1.0, -5.0),
+                Vector::new(-0.5, -1.0, 1.0),
+                4.55006,
+                49.44994,
+            ),
+        ];
+
+        for &(origin, direction, t0, t1) in &examples {
+            let r = Ray::new(origin, direction.normalize());
+            let xs = shape.local_intersect(&r);
+
+            assert_eq!(xs.len(), 2);
+            // Single precision loses a few digits on the far intersection.
+            assert!(is_approx(xs[0].t, t0, Some(1e-3)));
+            assert!(is_approx(xs[1].t, t1, Some(1e-3)));
+        }
+    }
+
+    // Scenario: Intersecting a cone with a ray parallel to one of its halves
+    //  Given shape ← cone()
+    //    And direction ← normalize(vector(0, 1, 1))
+    //    And r ← ray(point(0, 0, -1), direction)
+    //  When xs ← local_intersect(shape, r)
+    //  Then xs.count = 1
+    //    And xs[0].t = 0.35355
+    #[test]
+    fn intersecting_cone_with_ray_parallel_to_one_half() {
+        let shape = Cone::new();
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -1.0),
+            Vector::new(0.0, 1.0, 1.0).normalize(),
+        );
+        let xs = shape.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert!(is_approx(xs[0].t, 0.35355, None));
+    }
+
+    // Scenario Outline: Intersecting a cone's end caps
+    //  Given shape ← cone()
+    //    And shape.minimum ← -0.5
+    //    And shape.maximum ← 0.5
+    //    And shape.closed ← true
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  When xs ← local_intersect(shape, r)
+    //  Then xs.count = <count>
+    //
+    //  Examples:
+    //    | origin             | direction       | count |
+    //    | point(0, 0, -5)    | vector(0, 1, 0) | 0     |
+    //    | point(0, 0, -0.25) | vector(0, 1, 1) | 2     |
+    //    | point(0, 0, -0.25) | vector(0, 1, 0) | 4     |
+    #[test]
+    fn intersecting_cone_end_caps() {
+        let shape = Cone::truncated(-0.5, 0.5, true);
+        let examples = [
+            (Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0), 0),
+            (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 1.0), 2),
+            (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 0.0), 4),
+        ];
+
+        for &(origin, direction, count) in &examples {
+            let r = Ray::new(origin, direction.normalize());
+
+            assert_eq!(shape.local_intersect(&r).len(), count);
+        }
+    }
+
+    // Scenario Outline: Computing the normal vector on a cone
+    //  Given shape ← cone()
+    //  When n ← local_normal_at(shape, <point>)
+    //  Then n = <normal>
+    //
+    //  Examples:
+    //    | point             | normal                 |
+    //    | point(0, 0, 0)    | vector(0, 0, 0)        |
+    //    | point(1, 1, 1)    | vector(1, -√2, 1)      |
+    //    | point(-1, -1, 0)  | vector(-1, 1, 0)       |
+    #[test]
+    fn computing_normal_vector_on_cone() {
+        let shape = Cone::new();
+        let examples = [
+            (Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0)),
+            (Point::new(1.0, 1.0, 1.0), Vector::new(1.0, -SQRT_2, 1.0)),
+            (Point::new(-1.0, -1.0, 0.0), Vector::new(-1.0, 1.0, 0.0)),
+        ];
+
+        for &(point, normal) in &examples {
+            assert_eq!(shape.local_normal_at(point), normal);
+        }
+    }
+
+    // Scenario: An unbounded cone has a bounding box
+    //  Given shape ← cone()
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-infinity, -infinity, -infinity)
+    //    And box.max = point(infinity, infinity, infinity)
+    #[test]
+    fn unbounded_cone_has_bounding_box() {
+        let b = Cone::new().bounds();
+
+        assert_eq!(
+            b.min,
+            Point::new(Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY)
+        );
+        assert_eq!(
+            b.max,
+            Point::new(Float::INFINITY, Float::INFINITY, Float::INFINITY)
+        );
+    }
+
+    // Scenario: A bounded cone has a bounding box
+    //  Given shape ← cone()
+    //    And shape.minimum ← -5
+    //    And shape.maximum ← 3
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-5, -5, -5)
+    //    And box.max = point(5, 3, 5)
+    #[test]
+    fn bounded_cone_has_bounding_box() {
+        let b = Cone::truncated(-5.0, 3.0, false).bounds();
+
+        assert_eq!(b.min, Point::new(-5.0, -5.0, -5.0));
+        assert_eq!(b.max, Point::new(5.0, 3.0, 5.0));
+    }
+}
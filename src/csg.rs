@@ -0,0 +1,494 @@
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ptr;
+
+/// How the two shapes of a [`Csg`] are combined.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CsgOperation {
+    /// Everything in either shape.
+    Union,
+    /// Only what the shapes have in common.
+    Intersection,
+    /// The left shape with the right one carved out of it.
+    Difference,
+}
+
+impl CsgOperation {
+    /// Whether an intersection is part of the combined surface.
+    ///
+    /// `left_hit` tells which of the two shapes was hit, while `in_left` and `in_right`
+    /// tell whether the hit lies inside the left and right shapes.
+    pub fn is_intersection_allowed(self, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match self {
+            Self::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            Self::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            Self::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+}
+
+/// Constructive solid geometry: two shapes combined by a [`CsgOperation`].
+///
+/// Like a [`Group`], its transformation applies on top of those of its two shapes.
+#[derive(Debug)]
+pub struct Csg {
+    properties: ShapeProperties,
+    operation: CsgOperation,
+    left: Box<dyn Shape>,
+    right: Box<dyn Shape>,
+    /// Union of the bounds of both shapes, in the CSG's object space.
+    bounds: BoundingBox,
+}
+
+impl Csg {
+    pub fn new<L: Shape + 'static, R: Shape + 'static>(
+        operation: CsgOperation,
+        left: L,
+        right: R,
+    ) -> Self {
+        Self::from_boxed(operation, Box::new(left), Box::new(right))
+    }
+
+    pub fn from_boxed(
+        operation: CsgOperation,
+        mut left: Box<dyn Shape>,
+        mut right: Box<dyn Shape>,
+    ) -> Self {
+        let properties = ShapeProperties::new();
+        left.set_parent_transform(properties.world_transform());
+        right.set_parent_transform(properties.world_transform());
+        left.set_parent_visibility(properties.world_visibility());
+        right.set_parent_visibility(properties.world_visibility());
+        let mut bounds = left.parent_space_bounds();
+        bounds.add_box(&right.parent_space_bounds());
+
+        Self {
+            properties,
+            operation,
+            left,
+            right,
+            bounds,
+        }
+    }
+
+    pub fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.left.as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.right.as_ref()
+    }
+
+    /// Keeps the intersections that lie on the surface of the combined shape.
+    pub fn filter_intersections<'a>(&self, xs: Intersections<'a>) -> Intersections<'a> {
+        let mut in_left = false;
+        let mut in_right = false;
+        let mut result = Vec::new();
+
+        for i in xs {
+            let left_hit = self.left.includes(i.object);
+            if self
+                .operation
+                .is_intersection_allowed(left_hit, in_left, in_right)
+            {
+                result.push(i);
+            }
+
+            // Every intersection enters or leaves the shape that was hit.
+            if left_hit {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+
+        Intersections::new(result)
+    }
+}
+
+impl Shape for Csg {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.properties.set_parent_transform(parent_transform);
+
+        let world_transform = self.properties.world_transform();
+        self.left.set_parent_transform(world_transform);
+        self.right.set_parent_transform(world_transform);
+    }
+
+    fn set_parent_visibility(&mut self, parent_visibility: Visibility) {
+        self.properties.set_parent_visibility(parent_visibility);
+
+        let world_visibility = self.properties.world_visibility();
+        self.left.set_parent_visibility(world_visibility);
+        self.right.set_parent_visibility(world_visibility);
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        if !self.bounds.intersects(ray) {
+            return Intersections::default();
+        }
+
+        let mut xs = self.left.intersect(ray);
+        xs.extend(self.right.intersect(ray));
+        self.filter_intersections(xs)
+    }
+
+    /// # Panics
+    ///
+    /// Always panics: rays only ever hit the shapes of a CSG, never the CSG itself.
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        unreachable!("CSG shapes have no surface of their own")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        self.left.divide(threshold);
+        self.right.divide(threshold);
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        ptr::addr_eq(self, other) || self.left.includes(other) || self.right.includes(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::shape::TestShape;
+    use alloc::vec;
+    use core::ptr;
+
+    // Scenario: CSG is created with an operation and two shapes
+    //  Given s1 ← sphere()
+    //    And s2 ← cube()
+    //  When c ← csg("union", s1, s2)
+    //  Then c.operation = "union"
+    //    And c.left = s1
+    //    And c.right = s2
+    //    And s1.parent = c
+    //    And s2.parent = c
+    #[test]
+    fn csg_is_created_with_operation_and_two_shapes() {
+        let c = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new())
+            .with_transform(Matrix4::translation(1.0, 2.0, 3.0));
+
+        assert_eq!(c.operation(), CsgOperation::Union);
+        assert!(c.left().downcast_ref::<Sphere>().is_some());
+        assert!(c.right().downcast_ref::<Cube>().is_some());
+        assert_eq!(*c.left().properties().parent_transform(), *c.transform());
+        assert_eq!(*c.right().properties().parent_transform(), *c.transform());
+    }
+
+    // Scenario Outline: Evaluating the rule for a CSG operation
+    //  When result ← intersection_allowed("<op>", <lhit>, <inl>, <inr>)
+    //  Then result = <result>
+    //
+    //  Examples:
+    //  | op           | lhit  | inl   | inr   | result |
+    //  | union        | true  | true  | true  | false  |
+    //  | union        | true  | true  | false | true   |
+    //  | union        | true  | false | true  | false  |
+    //  | union        | true  | false | false | true   |
+    //  | union        | false | true  | true  | false  |
+    //  | union        | false | true  | false | false  |
+    //  | union        | false | false | true  | true   |
+    //  | union        | false | false | false | true   |
+    //  | intersection | true  | true  | true  | true   |
+    //  | intersection | true  | true  | false | false  |
+    //  | intersection | true  | false | true  | true   |
+    //  | intersection | true  | false | false | false  |
+    //  | intersection | false | true  | true  | true   |
+    //  | intersection | false | true  | false | true   |
+    //  | intersection | false | false | true  | false  |
+    //  | intersection | false | false | false | false  |
+    //  | difference   | true  | true  | true  | false  |
+    //  | difference   | true  | true  | false | true   |
+    //  | difference   | true  | false | true  | false  |
+    //  | difference   | true  | false | false | true   |
+    //  | difference   | false | true  | true  | true   |
+    //  | difference   | false | true  | false | true   |
+    //  | difference   | false | false | true  | false  |
+    //  | difference   | false | false | false | false  |
+    #[test]
+    fn evaluating_rule_for_csg_operation() {
+        let examples = [
+            (CsgOperation::Union, true, true, true, false),
+            (CsgOperation::Union, true, true, false, true),
+            (CsgOperation::Union, true, false, true, false),
+            (CsgOperation::Union, true, false, false, true),
+            (CsgOperation::Union, false, true, true, false),
+            (CsgOperation::Union, false, true, false, false),
+            (CsgOperation::Union, false, false, true, true),
+            (CsgOperation::Union, false, false, false, true),
+            (CsgOperation::Intersection, true, true, true, true),
+            (CsgOperation::Intersection, true, true, false, false),
+            (CsgOperation::Intersection, true, false, true, true),
+            (CsgOperation::Intersection, true, false, false, false),
+            (CsgOperation::Intersection, false, true, true, true),
+            (CsgOperation::Intersection, false, true, false, true),
+            (CsgOperation::Intersection, false, false, true, false),
+            (CsgOperation::Intersection, false, false, false, false),
+            (CsgOperation::Difference, true, true, true, false),
+            (CsgOperation::Difference, true, true, false, true),
+            (CsgOperation::Difference, true, false, true, false),
+            (CsgOperation::Difference, true, false, false, true),
+            (CsgOperation::Difference, false, true, true, true),
+            (CsgOperation::Difference, false, true, false, true),
+            (CsgOperation::Difference, false, false, true, false),
+            (CsgOperation::Difference, false, false, false, false),
+        ];
+
+        for (op, lhit, inl, inr, result) in examples {
+            assert_eq!(
+                op.is_intersection_allowed(lhit, inl, inr),
+                result,
+                "{:?} {} {} {}",
+                op,
+                lhit,
+                inl,
+                inr
+            );
+        }
+    }
+
+    // Scenario Outline: Filtering a list of intersections
+    //  Given s1 ← sphere()
+    //    And s2 ← cube()
+    //    And c ← csg("<operation>", s1, s2)
+    //    And xs ← intersections(1:s1, 2:s2, 3:s1, 4:s2)
+    //  When result ← filter_intersections(c, xs)
+    //  Then result.count = 2
+    //    And result[0] = xs[<x0>]
+    //    And result[1] = xs[<x1>]
+    //
+    //  Examples:
+    //  | operation    | x0 | x1 |
+    //  | union        |  0 |  3 |
+    //  | intersection |  1 |  2 |
+    //  | difference   |  0 |  1 |
+    #[test]
+    fn filtering_list_of_intersections() {
+        let examples = [
+            (CsgOperation::Union, 0, 3),
+            (CsgOperation::Intersection, 1, 2),
+            (CsgOperation::Difference, 0, 1),
+        ];
+
+        for (operation, x0, x1) in examples {
+            let c = Csg::new(operation, Sphere::new(), Cube::new());
+            let s1 = c.left();
+            let s2 = c.right();
+            let xs = Intersections::new(vec![
+                Intersection::new(1.0, s1),
+                Intersection::new(2.0, s2),
+                Intersection::new(3.0, s1),
+                Intersection::new(4.0, s2),
+            ]);
+
+            let result = c.filter_intersections(xs.clone());
+
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0], xs[x0]);
+            assert_eq!(result[1], xs[x1]);
+        }
+    }
+
+    // Scenario: A ray misses a CSG object
+    //  Given c ← csg("union", sphere(), cube())
+    //    And r ← ray(point(0, 2, -5), vector(0, 0, 1))
+    //  When xs ← local_intersect(c, r)
+    //  Then xs is empty
+    #[test]
+    fn ray_misses_csg_object() {
+        let c = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new());
+        let r = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(c.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray hits a CSG object
+    //  Given s1 ← sphere()
+    //    And s2 ← sphere()
+    //    And set_transform(s2, translation(0, 0, 0.5))
+    //    And c ← csg("union", s1, s2)
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //  When xs ← local_intersect(c, r)
+    //  Then xs.count = 2
+    //    And xs[0].t = 4
+    //    And xs[0].object = s1
+    //    And xs[1].t = 6.5
+    //    And xs[1].object = s2
+    #[test]
+    fn ray_hits_csg_object() {
+        let c = Csg::new(
+            CsgOperation::Union,
+            Sphere::new(),
+            Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, 0.5)),
+        );
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = c.local_intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert!(is_approx(xs[0].t, 4.0, None));
+        assert!(ptr::addr_eq(xs[0].object, c.left()));
+        assert!(is_approx(xs[1].t, 6.5, None));
+        assert!(ptr::addr_eq(xs[1].object, c.right()));
+    }
+
+    #[test]
+    fn filtering_uses_shapes_nested_in_groups() {
+        let c = Csg::new(
+            CsgOperation::Difference,
+            Group::new().with_child(Cube::new()),
+            Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, -1.0)),
+        );
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = c.local_intersect(&r);
+
+        // The sphere carves out the front of the cube, leaving its back face and the
+        // inside of the sphere.
+        assert_eq!(xs.len(), 2);
+        assert!(is_approx(xs[0].t, 5.0, None));
+        assert!(ptr::addr_eq(xs[0].object, c.right()));
+        assert!(is_approx(xs[1].t, 6.0, None));
+        assert!(c.left().includes(xs[1].object));
+    }
+
+    // Scenario: A CSG shape has a bounding box that contains its children
+    //  Given left ← sphere()
+    //    And right ← sphere() with:
+    //      | transform | translation(2, 3, 4) |
+    //    And shape ← csg("difference", left, right)
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-1, -1, -1)
+    //    And box.max = point(3, 4, 5)
+    #[test]
+    fn csg_has_bounding_box_containing_its_children() {
+        let shape = Csg::new(
+            CsgOperation::Difference,
+            Sphere::new(),
+            Sphere::new().with_transform(Matrix4::translation(2.0, 3.0, 4.0)),
+        );
+
+        let b = shape.bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(b.max, Point::new(3.0, 4.0, 5.0));
+    }
+
+    // Scenario: Intersecting ray+csg doesn't test children if box is missed
+    //  Given left ← test_shape()
+    //    And right ← test_shape()
+    //    And shape ← csg("difference", left, right)
+    //    And r ← ray(point(0, 0, -5), vector(0, 1, 0))
+    //  When xs ← intersect(shape, r)
+    //  Then left.saved_ray is unset
+    //    And right.saved_ray is unset
+    #[test]
+    fn intersecting_csg_does_not_test_children_if_box_is_missed() {
+        let shape = Csg::new(CsgOperation::Difference, TestShape::new(), TestShape::new());
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        shape.intersect(&r);
+
+        assert!(test_shape(shape.left()).saved_ray().is_none());
+        assert!(test_shape(shape.right()).saved_ray().is_none());
+    }
+
+    // Scenario: Intersecting ray+csg tests children if box is hit
+    //  Given left ← test_shape()
+    //    And right ← test_shape()
+    //    And shape ← csg("difference", left, right)
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //  When xs ← intersect(shape, r)
+    //  Then left.saved_ray is set
+    //    And right.saved_ray is set
+    #[test]
+    fn intersecting_csg_tests_children_if_box_is_hit() {
+        let shape = Csg::new(CsgOperation::Difference, TestShape::new(), TestShape::new());
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        shape.intersect(&r);
+
+        assert!(test_shape(shape.left()).saved_ray().is_some());
+        assert!(test_shape(shape.right()).saved_ray().is_some());
+    }
+
+    // Scenario: Subdividing a CSG shape subdivides its children
+    //  Given s1 ← sphere() with:
+    //      | transform | translation(-1.5, 0, 0) |
+    //    And s2 ← sphere() with:
+    //      | transform | translation(1.5, 0, 0) |
+    //    And left ← group of [s1, s2]
+    //    And s3 ← sphere() with:
+    //      | transform | translation(0, 0, -1.5) |
+    //    And s4 ← sphere() with:
+    //      | transform | translation(0, 0, 1.5) |
+    //    And right ← group of [s3, s4]
+    //    And shape ← csg("difference", left, right)
+    //  When divide(shape, 1)
+    //  Then left[0] is a group of [s1]
+    //    And left[1] is a group of [s2]
+    //    And right[0] is a group of [s3]
+    //    And right[1] is a group of [s4]
+    #[test]
+    fn subdividing_csg_subdivides_its_children() {
+        let left = Group::new()
+            .with_child(Sphere::new().with_transform(Matrix4::translation(-1.5, 0.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(1.5, 0.0, 0.0)));
+        let right = Group::new()
+            .with_child(Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, -1.5)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, 1.5)));
+        let mut shape = Csg::new(CsgOperation::Difference, left, right);
+
+        shape.divide(1);
+
+        let expected = [
+            (shape.left(), [(-1.5, 0.0), (1.5, 0.0)]),
+            (shape.right(), [(0.0, -1.5), (0.0, 1.5)]),
+        ];
+        for (side, translations) in expected {
+            let side = group(side);
+            for (child, (x, z)) in side.children().iter().zip(translations) {
+                let subgroup = group(child.as_ref());
+                assert_eq!(subgroup.children().len(), 1);
+                assert_eq!(
+                    *subgroup.children()[0].transform(),
+                    Matrix4::translation(x, 0.0, z)
+                );
+            }
+        }
+    }
+
+    fn group(shape: &dyn Shape) -> &Group {
+        shape.downcast_ref().unwrap()
+    }
+
+    fn test_shape(shape: &dyn Shape) -> &TestShape {
+        shape.downcast_ref().unwrap()
+    }
+
+    #[test]
+    #[should_panic]
+    fn normal_of_csg_itself_panics() {
+        Csg::new(CsgOperation::Union, Sphere::new(), Cube::new()).local_normal_at(Point::origin());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::prelude::*;
+use alloc::vec;
+
+/// An axis-aligned cube spanning -1 to 1 on every axis of its object space.
+#[derive(Debug, Clone, Default)]
+pub struct Cube {
+    properties: ShapeProperties,
+}
+
+impl Cube {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Distances at which a ray enters and leaves the slab between -1 and 1 on one axis.
+///
+/// A direction of zero divides to infinities, which is exactly the behavior needed for a
+/// ray parallel to the slab.
+fn check_axis(origin: Float, direction: Float) -> (Float, Float) {
+    let tmin = (-1.0 - origin) / direction;
+    let tmax = (1.0 - origin) / direction;
+
+    if tmin > tmax {
+        (tmax, tmin)
+    } else {
+        (tmin, tmax)
+    }
+}
+
+impl Shape for Cube {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            return Intersections::default();
+        }
+
+        Intersections::new(vec![
+            Intersection::new(tmin, self),
+            Intersection::new(tmax, self),
+        ])
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        // The face being hit is the one whose axis has the largest coordinate.
+        let maxc = point.x.abs().max(point.y.abs()).max(point.z.abs());
+
+        if maxc == point.x.abs() {
+            Vector::new(point.x, 0.0, 0.0)
+        } else if maxc == point.y.abs() {
+            Vector::new(0.0, point.y, 0.0)
+        } else {
+            Vector::new(0.0, 0.0, point.z)
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario Outline: A ray intersects a cube
+    //  Given c ← cube()
+    //    And r ← ray(<origin>, <direction>)
+    //  When xs ← local_intersect(c, r)
+    //  Then xs.count = 2
+    //    And xs[0].t = <t1>
+    //    And xs[1].t = <t2>
+    //
+    //  Examples:
+    //    |        | origin            | direction        | t1 | t2 |
+    //    | +x     | point(5, 0.5, 0)  | vector(-1, 0, 0) |  4 |  6 |
+    //    | -x     | point(-5, 0.5, 0) | vector(1, 0, 0)  |  4 |  6 |
+    //    | +y     | point(0.5, 5, 0)  | vector(0, -1, 0) |  4 |  6 |
+    //    | -y     | point(0.5, -5, 0) | vector(0, 1, 0)  |  4 |  6 |
+    //    | +z     | point(0.5, 0, 5)  | vector(0, 0, -1) |  4 |  6 |
+    //    | -z     | point(0.5, 0, -5) | vector(0, 0, 1)  |  4 |  6 |
+    //    | inside | point(0, 0.5, 0)  | vector(0, 0, 1)  | -1 |  1 |
+    #[test]
+    fn ray_intersects_cube() {
+        let c = Cube::new();
+        let examples = [
+            (
+                Point::new(5.0, 0.5, 0.0),
+                Vector::new(-1.0, 0.0, 0.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(-5.0, 0.5, 0.0),
+                Vector::new(1.0, 0.0, 0.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.5, 5.0, 0.0),
+                Vector::new(0.0, -1.0, 0.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.5, -5.0, 0.0),
+                Vector::new(0.0, 1.0, 0.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.5, 0.0, 5.0),
+                Vector::new(0.0, 0.0, -1.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.5, 0.0, -5.0),
+                Vector::new(0.0, 0.0, 1.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.0, 0.5, 0.0),
+                Vector::new(0.0, 0.0, 1.0),
+                -1.0,
+                1.0,
+            ),
+        ];
+
+        for &(origin, direction, t1, t2) in &examples {
+            let xs = c.local_intersect(&Ray::new(origin, direction));
+
+            assert_eq!(xs.len(), 2);
+            assert_eq!(xs[0].t, t1);
+            assert_eq!(xs[1].t, t2);
+        }
+    }
+
+    // Scenario Outline: A ray misses a cube
+    //  Given c ← cube()
+    //    And r ← ray(<origin>, <direction>)
+    //  When xs ← local_intersect(c, r)
+    //  Then xs.count = 0
+    //
+    //  Examples:
+    //    | origin           | direction                      |
+    //    | point(-2, 0, 0)  | vector(0.2673, 0.5345, 0.8018) |
+    //    | point(0, -2, 0)  | vector(0.8018, 0.2673, 0.5345) |
+    //    | point(0, 0, -2)  | vector(0.5345, 0.8018, 0.2673) |
+    //    | point(2, 0, 2)   | vector(0, 0, -1)               |
+    //    | point(0, 2, 2)   | vector(0, -1, 0)               |
+    //    | point(2, 2, 0)   | vector(-1, 0, 0)               |
+    #[test]
+    fn ray_misses_cube() {
+        let c = Cube::new();
+        let examples = [
+            (
+                Point::new(-2.0, 0.0, 0.0),
+                Vector::new(0.2673, 0.5345, 0.8018),
+            ),
+            (
+                Point::new(0.0, -2.0, 0.0),
+                Vector::new(0.8018, 0.2673, 0.5345),
+            ),
+            (
+                Point::new(0.0, 0.0, -2.0),
+                Vector::new(0.5345, 0.8018, 0.2673),
+            ),
+            (Point::new(2.0, 0.0, 2.0), Vector::new(0.0, 0.0, -1.0)),
+            (Point::new(0.0, 2.0, 2.0), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(2.0, 2.0, 0.0), Vector::new(-1.0, 0.0, 0.0)),
+        ];
+
+        for &(origin, direction) in &examples {
+            assert!(c.local_intersect(&Ray::new(origin, direction)).is_empty());
+        }
+    }
+
+    // Scenario Outline: The normal on the surface of a cube
+    //  Given c ← cube()
+    //    And p ← <point>
+    //  When normal ← local_normal_at(c, p)
+    //  Then normal = <normal>
+    //
+    //  Examples:
+    //    | point                | normal           |
+    //    | point(1, 0.5, -0.8)  | vector(1, 0, 0)  |
+    //    | point(-1, -0.2, 0.9) | vector(-1, 0, 0) |
+    //    | point(-0.4, 1, -0.1) | vector(0, 1, 0)  |
+    //    | point(0.3, -1, -0.7) | vector(0, -1, 0) |
+    //    | point(-0.6, 0.3, 1)  | vector(0, 0, 1)  |
+    //    | point(0.4, 0.4, -1)  | vector(0, 0, -1) |
+    //    | point(1, 1, 1)       | vector(1, 0, 0)  |
+    //    | point(-1, -1, -1)    | vector(-1, 0, 0) |
+    #[test]
+    fn normal_on_surface_of_cube() {
+        let c = Cube::new();
+        let examples = [
+            (Point::new(1.0, 0.5, -0.8), Vector::new(1.0, 0.0, 0.0)),
+            (Point::new(-1.0, -0.2, 0.9), Vector::new(-1.0, 0.0, 0.0)),
+            (Point::new(-0.4, 1.0, -0.1), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.3, -1.0, -0.7), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(-0.6, 0.3, 1.0), Vector::new(0.0, 0.0, 1.0)),
+            (Point::new(0.4, 0.4, -1.0), Vector::new(0.0, 0.0, -1.0)),
+            (Point::new(1.0, 1.0, 1.0), Vector::new(1.0, 0.0, 0.0)),
+            (Point::new(-1.0, -1.0, -1.0), Vector::new(-1.0, 0.0, 0.0)),
+        ];
+
+        for &(point, normal) in &examples {
+            assert_eq!(c.local_normal_at(point), normal);
+        }
+    }
+
+    // Scenario: A cube has a bounding box
+    //  Given shape ← cube()
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-1, -1, -1)
+    //    And box.max = point(1, 1, 1)
+    #[test]
+    fn cube_has_bounding_box() {
+        let b = Cube::new().bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(b.max, Point::new(1.0, 1.0, 1.0));
+    }
+}
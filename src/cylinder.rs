@@ -0,0 +1,376 @@
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A cylinder of radius 1 around the y axis of its object space.
+///
+/// It extends infinitely unless truncated to `minimum < y < maximum`, in which case the
+/// ends are left open unless `closed` is set.
+#[derive(Debug, Clone)]
+pub struct Cylinder {
+    properties: ShapeProperties,
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+}
+
+impl Cylinder {
+    pub fn new() -> Self {
+        Self::truncated(Float::NEG_INFINITY, Float::INFINITY, false)
+    }
+
+    pub fn truncated(minimum: Float, maximum: Float, closed: bool) -> Self {
+        Self {
+            properties: ShapeProperties::new(),
+            minimum,
+            maximum,
+            closed,
+        }
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // Caps only matter when closed, and a ray parallel to them can never cross them.
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        for &y in &[self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t, 1.0) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `ray` at `t` lies within `radius` of the y axis.
+///
+/// Points on the rim count as inside, with some slack for rounding.
+pub(crate) fn check_cap(ray: &Ray, t: Float, radius: Float) -> bool {
+    let x = ray.origin.x + t * ray.direction.x;
+    let z = ray.origin.z + t * ray.direction.z;
+
+    x * x + z * z <= radius * radius + EPSILON
+}
+
+impl Shape for Cylinder {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut xs = Vec::new();
+        let a = ray.direction.x * ray.direction.x + ray.direction.z * ray.direction.z;
+
+        // A ray parallel to the y axis can only hit the caps.
+        if a.abs() >= EPSILON {
+            let b = 2.0 * ray.origin.x * ray.direction.x + 2.0 * ray.origin.z * ray.direction.z;
+            let c = ray.origin.x * ray.origin.x + ray.origin.z * ray.origin.z - 1.0;
+            let discriminant = b * b - 4.0 * a * c;
+
+            if discriminant < 0.0 {
+                return Intersections::default();
+            }
+
+            let root = discriminant.sqrt();
+            for &t in &[(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)] {
+                let y = ray.origin.y + t * ray.direction.y;
+                if self.minimum < y && y < self.maximum {
+                    xs.push(Intersection::new(t, self));
+                }
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let dist = point.x * point.x + point.z * point.z;
+
+        if dist < 1.0 && point.y >= self.maximum - EPSILON {
+            Vector::new(0.0, 1.0, 0.0)
+        } else if dist < 1.0 && point.y <= self.minimum + EPSILON {
+            Vector::new(0.0, -1.0, 0.0)
+        } else {
+            Vector::new(point.x, 0.0, point.z)
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(-1.0, self.minimum, -1.0),
+            Point::new(1.0, self.maximum, 1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario Outline: A ray misses a cylinder
+    //  Given cyl ← cylinder()
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  When xs ← local_intersect(cyl, r)
+    //  Then xs.count = 0
+    //
+    //  Examples:
+    //    | origin          | direction       |
+    //    | point(1, 0, 0)  | vector(0, 1, 0) |
+    //    | point(0, 0, 0)  | vector(0, 1, 0) |
+    //    | point(0, 0, -5) | vector(1, 1, 1) |
+    #[test]
+    fn ray_misses_cylinder() {
+        let cyl = Cylinder::new();
+        let examples = [
+            (Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.0, 0.0, -5.0), Vector::new(1.0, 1.0, 1.0)),
+        ];
+
+        for &(origin, direction) in &examples {
+            let r = Ray::new(origin, direction.normalize());
+
+            assert!(cyl.local_intersect(&r).is_empty());
+        }
+    }
+
+    // Scenario Outline: A ray strikes a cylinder
+    //  Given cyl ← cylinder()
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<origin>, direction)
+    //  When xs ← local_intersect(cyl, r)
+    //  Then xs.count = 2
+    //    And xs[0].t = <t0>
+    //    And xs[1].t = <t1>
+    //
+    //  Examples:
+    //    | origin            | direction         | t0      | t1      |
+    //    | point(1, 0, -5)   | vector(0, 0, 1)   | 5       | 5       |
+    //    | point(0, 0, -5)   | vector(0, 0, 1)   | 4       | 6       |
+    //    | point(0.5, 0, -5) | vector(0.1, 1, 1) | 6.80798 | 7.08872 |
+    #[test]
+    fn ray_strikes_cylinder() {
+        let cyl = Cylinder::new();
+        let examples = [
+            (
+                Point::new(1.0, 0.0, -5.0),
+                Vector::new(0.0, 0.0, 1.0),
+                5.0,
+                5.0,
+            ),
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(0.0, 0.0, 1.0),
+                4.0,
+                6.0,
+            ),
+            (
+                Point::new(0.5, 0.0, -5.0),
+                Vector::new(0.1, 1.0, 1.0),
+                6.80798,
+                7.08872,
+            ),
+        ];
+
+        for &(origin, direction, t0, t1) in &examples {
+            let r = Ray::new(origin, direction.normalize());
+            let xs = cyl.local_intersect(&r);
+
+            assert_eq!(xs.len(), 2);
+            assert!(is_approx(xs[0].t, t0, None));
+            assert!(is_approx(xs[1].t, t1, None));
+        }
+    }
+
+    // Scenario Outline: Normal vector on a cylinder
+    //  Given cyl ← cylinder()
+    //  When n ← local_normal_at(cyl, <point>)
+    //  Then n = <normal>
+    //
+    //  Examples:
+    //    | point           | normal           |
+    //    | point(1, 0, 0)  | vector(1, 0, 0)  |
+    //    | point(0, 5, -1) | vector(0, 0, -1) |
+    //    | point(0, -2, 1) | vector(0, 0, 1)  |
+    //    | point(-1, 1, 0) | vector(-1, 0, 0) |
+    #[test]
+    fn normal_vector_on_cylinder() {
+        let cyl = Cylinder::new();
+        let examples = [
+            (Point::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)),
+            (Point::new(0.0, 5.0, -1.0), Vector::new(0.0, 0.0, -1.0)),
+            (Point::new(0.0, -2.0, 1.0), Vector::new(0.0, 0.0, 1.0)),
+            (Point::new(-1.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0)),
+        ];
+
+        for &(point, normal) in &examples {
+            assert_eq!(cyl.local_normal_at(point), normal);
+        }
+    }
+
+    // Scenario: The default minimum and maximum for a cylinder
+    //  Given cyl ← cylinder()
+    //  Then cyl.minimum = -infinity
+    //    And cyl.maximum = infinity
+    #[test]
+    fn default_minimum_and_maximum_for_cylinder() {
+        let cyl = Cylinder::new();
+
+        assert_eq!(cyl.minimum, Float::NEG_INFINITY);
+        assert_eq!(cyl.maximum, Float::INFINITY);
+    }
+
+    // Scenario Outline: Intersecting a constrained cylinder
+    //  Given cyl ← cylinder()
+    //    And cyl.minimum ← 1
+    //    And cyl.maximum ← 2
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<point>, direction)
+    //  When xs ← local_intersect(cyl, r)
+    //  Then xs.count = <count>
+    //
+    //  Examples:
+    //    |   | point             | direction         | count |
+    //    | 1 | point(0, 1.5, 0)  | vector(0.1, 1, 0) | 0     |
+    //    | 2 | point(0, 3, -5)   | vector(0, 0, 1)   | 0     |
+    //    | 3 | point(0, 0, -5)   | vector(0, 0, 1)   | 0     |
+    //    | 4 | point(0, 2, -5)   | vector(0, 0, 1)   | 0     |
+    //    | 5 | point(0, 1, -5)   | vector(0, 0, 1)   | 0     |
+    //    | 6 | point(0, 1.5, -2) | vector(0, 0, 1)   | 2     |
+    #[test]
+    fn intersecting_constrained_cylinder() {
+        let cyl = Cylinder::truncated(1.0, 2.0, false);
+        let examples = [
+            (Point::new(0.0, 1.5, 0.0), Vector::new(0.1, 1.0, 0.0), 0),
+            (Point::new(0.0, 3.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+            (Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+            (Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+            (Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+            (Point::new(0.0, 1.5, -2.0), Vector::new(0.0, 0.0, 1.0), 2),
+        ];
+
+        for &(point, direction, count) in &examples {
+            let r = Ray::new(point, direction.normalize());
+
+            assert_eq!(cyl.local_intersect(&r).len(), count);
+        }
+    }
+
+    // Scenario: The default closed value for a cylinder
+    //  Given cyl ← cylinder()
+    //  Then cyl.closed = false
+    #[test]
+    fn default_closed_value_for_cylinder() {
+        let cyl = Cylinder::new();
+
+        assert!(!cyl.closed);
+    }
+
+    // Scenario Outline: Intersecting the caps of a closed cylinder
+    //  Given cyl ← cylinder()
+    //    And cyl.minimum ← 1
+    //    And cyl.maximum ← 2
+    //    And cyl.closed ← true
+    //    And direction ← normalize(<direction>)
+    //    And r ← ray(<point>, direction)
+    //  When xs ← local_intersect(cyl, r)
+    //  Then xs.count = <count>
+    //
+    //  Examples:
+    //    |   | point            | direction        | count |
+    //    | 1 | point(0, 3, 0)   | vector(0, -1, 0) | 2     |
+    //    | 2 | point(0, 3, -2)  | vector(0, -1, 2) | 2     |
+    //    | 3 | point(0, 4, -2)  | vector(0, -1, 1) | 2     | # corner case
+    //    | 4 | point(0, 0, -2)  | vector(0, 1, 2)  | 2     |
+    //    | 5 | point(0, -1, -2) | vector(0, 1, 1)  | 2     | # corner case
+    #[test]
+    fn intersecting_caps_of_closed_cylinder() {
+        let cyl = Cylinder::truncated(1.0, 2.0, true);
+        let examples = [
+            (Point::new(0.0, 3.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(0.0, 3.0, -2.0), Vector::new(0.0, -1.0, 2.0)),
+            (Point::new(0.0, 4.0, -2.0), Vector::new(0.0, -1.0, 1.0)),
+            (Point::new(0.0, 0.0, -2.0), Vector::new(0.0, 1.0, 2.0)),
+            (Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 1.0)),
+        ];
+
+        for &(point, direction) in &examples {
+            let r = Ray::new(point, direction.normalize());
+
+            assert_eq!(cyl.local_intersect(&r).len(), 2);
+        }
+    }
+
+    // Scenario Outline: The normal vector on a cylinder's end caps
+    //  Given cyl ← cylinder()
+    //    And cyl.minimum ← 1
+    //    And cyl.maximum ← 2
+    //    And cyl.closed ← true
+    //  When n ← local_normal_at(cyl, <point>)
+    //  Then n = <normal>
+    //
+    //  Examples:
+    //    | point            | normal           |
+    //    | point(0, 1, 0)   | vector(0, -1, 0) |
+    //    | point(0.5, 1, 0) | vector(0, -1, 0) |
+    //    | point(0, 1, 0.5) | vector(0, -1, 0) |
+    //    | point(0, 2, 0)   | vector(0, 1, 0)  |
+    //    | point(0.5, 2, 0) | vector(0, 1, 0)  |
+    //    | point(0, 2, 0.5) | vector(0, 1, 0)  |
+    #[test]
+    fn normal_vector_on_cylinder_end_caps() {
+        let cyl = Cylinder::truncated(1.0, 2.0, true);
+        let examples = [
+            (Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(0.5, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(0.0, 1.0, 0.5), Vector::new(0.0, -1.0, 0.0)),
+            (Point::new(0.0, 2.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.5, 2.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+            (Point::new(0.0, 2.0, 0.5), Vector::new(0.0, 1.0, 0.0)),
+        ];
+
+        for &(point, normal) in &examples {
+            assert_eq!(cyl.local_normal_at(point), normal);
+        }
+    }
+
+    // Scenario: An unbounded cylinder has a bounding box
+    //  Given shape ← cylinder()
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-1, -infinity, -1)
+    //    And box.max = point(1, infinity, 1)
+    #[test]
+    fn unbounded_cylinder_has_bounding_box() {
+        let b = Cylinder::new().bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, Float::NEG_INFINITY, -1.0));
+        assert_eq!(b.max, Point::new(1.0, Float::INFINITY, 1.0));
+    }
+
+    // Scenario: A bounded cylinder has a bounding box
+    //  Given shape ← cylinder()
+    //    And shape.minimum ← -5
+    //    And shape.maximum ← 3
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-1, -5, -1)
+    //    And box.max = point(1, 3, 1)
+    #[test]
+    fn bounded_cylinder_has_bounding_box() {
+        let b = Cylinder::truncated(-5.0, 3.0, false).bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, -5.0, -1.0));
+        assert_eq!(b.max, Point::new(1.0, 3.0, 1.0));
+    }
+}
@@ -0,0 +1,373 @@
+use crate::prelude::*;
+use alloc::format;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// Turns a canvas into the bytes of an image file.
+pub trait ImageEncoder {
+    fn encode(&self, canvas: &Canvas) -> Vec<u8>;
+}
+
+/// Plain-text portable pixmap (P3), readable by almost anything but very large.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PpmEncoder;
+
+impl ImageEncoder for PpmEncoder {
+    fn encode(&self, canvas: &Canvas) -> Vec<u8> {
+        canvas.to_ppm().into_bytes()
+    }
+}
+
+/// Turns the bytes of an image file back into a canvas.
+pub trait ImageDecoder {
+    fn decode(&self, bytes: &[u8]) -> Result<Canvas>;
+}
+
+/// Most pixels a decoder makes a canvas for, e.g. 8192 by 8192, so that a corrupted
+/// header cannot ask for more memory than there is.
+pub const MAX_IMAGE_PIXELS: usize = 1 << 26;
+
+/// Whether a decoder can make a canvas of `width` by `height`, which must hold at least one
+/// and at most [`MAX_IMAGE_PIXELS`] pixels.
+pub(crate) fn is_decodable_size(width: usize, height: usize) -> bool {
+    matches!(width.checked_mul(height), Some(pixels) if pixels > 0 && pixels <= MAX_IMAGE_PIXELS)
+}
+
+/// Portable pixmap, either plain-text (P3) or binary (P6), with any maximum value.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PpmDecoder;
+
+impl ImageDecoder for PpmDecoder {
+    fn decode(&self, bytes: &[u8]) -> Result<Canvas> {
+        let mut input = PpmReader { bytes, position: 0 };
+        let binary = match input.token()? {
+            b"P3" => false,
+            b"P6" => true,
+            _ => return Err(invalid_ppm("expected a P3 or P6 magic number")),
+        };
+        let width = input.number()?;
+        let height = input.number()?;
+        if !is_decodable_size(width, height) {
+            return Err(invalid_ppm("the image is empty or too large"));
+        }
+        let max = input.number()?;
+        if max == 0 || max > 65535 {
+            return Err(invalid_ppm("the maximum value must be between 1 and 65535"));
+        }
+        // Binary pixels start after the single whitespace ending the header.
+        input.position += 1;
+
+        let mut canvas = Canvas::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let mut component = || -> Result<Float> {
+                    let value = if binary {
+                        input.binary_sample(max > 255)?
+                    } else {
+                        input.number()?
+                    };
+                    Ok(value as Float / max as Float)
+                };
+                let color = Color::new(component()?, component()?, component()?);
+                canvas.write_pixel(x, y, color);
+            }
+        }
+        Ok(canvas)
+    }
+}
+
+fn invalid_ppm(reason: &str) -> RustracerError {
+    RustracerError::InvalidImage(format!("PPM: {}", reason))
+}
+
+struct PpmReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PpmReader<'a> {
+    /// Next whitespace-separated token, skipping comments that run to the end of a line.
+    fn token(&mut self) -> Result<&'a [u8]> {
+        loop {
+            match self.bytes.get(self.position) {
+                Some(b'#') => {
+                    while !matches!(self.bytes.get(self.position), None | Some(b'\n')) {
+                        self.position += 1;
+                    }
+                }
+                Some(byte) if byte.is_ascii_whitespace() => self.position += 1,
+                Some(_) => break,
+                None => return Err(invalid_ppm("unexpected end of file")),
+            }
+        }
+
+        let start = self.position;
+        while matches!(self.bytes.get(self.position), Some(byte) if !byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+        Ok(&self.bytes[start..self.position])
+    }
+
+    fn number(&mut self) -> Result<usize> {
+        let token = self.token()?;
+        core::str::from_utf8(token)
+            .ok()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(|| invalid_ppm("expected a number"))
+    }
+
+    fn binary_sample(&mut self, wide: bool) -> Result<usize> {
+        let size = if wide { 2 } else { 1 };
+        let sample = self
+            .bytes
+            .get(self.position..self.position + size)
+            .ok_or_else(|| invalid_ppm("unexpected end of file"))?;
+        self.position += size;
+        Ok(sample
+            .iter()
+            .fold(0, |value, &byte| value << 8 | byte as usize))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Canvas {
+    pub fn save<P: AsRef<Path>>(&self, path: P, encoder: &dyn ImageEncoder) -> Result<()> {
+        fs::write(path, encoder.encode(self))?;
+        Ok(())
+    }
+
+    pub fn save_ppm<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.save(path, &PpmEncoder)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P, decoder: &dyn ImageDecoder) -> Result<Self> {
+        decoder.decode(&fs::read(path)?)
+    }
+
+    pub fn load_ppm<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::load(path, &PpmDecoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::format;
+
+    #[test]
+    fn ppm_encoder_matches_to_ppm() {
+        let mut c = Canvas::new(3, 2);
+        c.write_pixel(1, 1, Color::new(0.5, 1.0, 0.0));
+
+        assert_eq!(PpmEncoder.encode(&c), c.to_ppm().into_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn saving_canvas_writes_encoded_file() {
+        let c = Canvas::new(2, 2);
+        let path = std::env::temp_dir().join("rustracer_saving_canvas_writes_encoded_file.ppm");
+
+        c.save_ppm(&path).unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), c.to_ppm().into_bytes());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // Scenario: Reading a file with the wrong magic number
+    //  Given ppm ← a file containing:
+    //    """
+    //    P32
+    //    1 1
+    //    255
+    //    0 0 0
+    //    """
+    //  Then canvas_from_ppm(ppm) should fail
+    #[test]
+    fn reading_file_with_wrong_magic_number() {
+        let ppm = "P32\n1 1\n255\n0 0 0\n";
+
+        assert!(matches!(
+            PpmDecoder.decode(ppm.as_bytes()),
+            Err(RustracerError::InvalidImage(_))
+        ));
+    }
+
+    #[test]
+    fn reading_empty_or_huge_ppm_fails() {
+        for ppm in ["P3\n0 0\n255\n", "P3\n4000000000 4000000000\n255\n0 0 0\n"] {
+            assert!(matches!(
+                PpmDecoder.decode(ppm.as_bytes()),
+                Err(RustracerError::InvalidImage(_))
+            ));
+        }
+    }
+
+    // Scenario: Reading a PPM returns a canvas of the right size
+    //  Given ppm ← a file containing:
+    //    """
+    //    P3
+    //    10 2
+    //    255
+    //    0 0 0  0 0 0  0 0 0  0 0 0  0 0 0
+    //    0 0 0  0 0 0  0 0 0  0 0 0  0 0 0
+    //    0 0 0  0 0 0  0 0 0  0 0 0  0 0 0
+    //    0 0 0  0 0 0  0 0 0  0 0 0  0 0 0
+    //    """
+    //    And canvas ← canvas_from_ppm(ppm)
+    //  Then canvas.width = 10
+    //    And canvas.height = 2
+    #[test]
+    fn reading_ppm_returns_canvas_of_right_size() {
+        let ppm = format!(
+            "P3\n10 2\n255\n{}",
+            "0 0 0  0 0 0  0 0 0  0 0 0  0 0 0\n".repeat(4)
+        );
+
+        let canvas = PpmDecoder.decode(ppm.as_bytes()).unwrap();
+
+        assert_eq!(canvas.width(), 10);
+        assert_eq!(canvas.height(), 2);
+    }
+
+    // Scenario Outline: Reading pixel data from a PPM file
+    //  Given ppm ← a file containing:
+    //    """
+    //    P3
+    //    4 3
+    //    255
+    //    255 127 0  0 127 255  127 255 0  255 255 255
+    //    0 0 0  255 0 0  0 255 0  0 0 255
+    //    255 255 0  0 255 255  255 0 255  127 127 127
+    //    """
+    //    And canvas ← canvas_from_ppm(ppm)
+    //  Then pixel_at(canvas, <x>, <y>) = <color>
+    #[test]
+    fn reading_pixel_data_from_ppm_file() {
+        let ppm = "P3
+4 3
+255
+255 127 0  0 127 255  127 255 0  255 255 255
+0 0 0  255 0 0  0 255 0  0 0 255
+255 255 0  0 255 255  255 0 255  127 127 127
+";
+        let canvas = PpmDecoder.decode(ppm.as_bytes()).unwrap();
+
+        let examples = [
+            (0, 0, Color::new(1.0, 0.49804, 0.0)),
+            (1, 0, Color::new(0.0, 0.49804, 1.0)),
+            (2, 0, Color::new(0.49804, 1.0, 0.0)),
+            (3, 0, Color::new(1.0, 1.0, 1.0)),
+            (0, 1, Color::new(0.0, 0.0, 0.0)),
+            (1, 1, Color::new(1.0, 0.0, 0.0)),
+            (2, 1, Color::new(0.0, 1.0, 0.0)),
+            (3, 1, Color::new(0.0, 0.0, 1.0)),
+            (0, 2, Color::new(1.0, 1.0, 0.0)),
+            (1, 2, Color::new(0.0, 1.0, 1.0)),
+            (2, 2, Color::new(1.0, 0.0, 1.0)),
+            (3, 2, Color::new(0.49804, 0.49804, 0.49804)),
+        ];
+        for &(x, y, color) in &examples {
+            assert_eq!(canvas.pixel_at(x, y), color);
+        }
+    }
+
+    // Scenario: PPM parsing ignores comment lines
+    //  Given ppm ← a file containing:
+    //    """
+    //    P3
+    //    # this is a comment
+    //    2 1
+    //    # this, too
+    //    255
+    //    # another comment
+    //    255 255 255
+    //    # oh, no, comments in the pixel data!
+    //    255 0 255
+    //    """
+    //    And canvas ← canvas_from_ppm(ppm)
+    //  Then pixel_at(canvas, 0, 0) = color(1, 1, 1)
+    //    And pixel_at(canvas, 1, 0) = color(1, 0, 1)
+    #[test]
+    fn ppm_parsing_ignores_comment_lines() {
+        let ppm = "P3
+# this is a comment
+2 1
+# this, too
+255
+# another comment
+255 255 255
+# oh, no, comments in the pixel data!
+255 0 255
+";
+        let canvas = PpmDecoder.decode(ppm.as_bytes()).unwrap();
+
+        assert_eq!(canvas.pixel_at(0, 0), Color::new(1.0, 1.0, 1.0));
+        assert_eq!(canvas.pixel_at(1, 0), Color::new(1.0, 0.0, 1.0));
+    }
+
+    // Scenario: PPM parsing allows an RGB triple to span lines
+    //  Given ppm ← a file containing:
+    //    """
+    //    P3
+    //    1 1
+    //    255
+    //    51
+    //    153
+    //
+    //    204
+    //    """
+    //    And canvas ← canvas_from_ppm(ppm)
+    //  Then pixel_at(canvas, 0, 0) = color(0.2, 0.6, 0.8)
+    #[test]
+    fn ppm_parsing_allows_rgb_triple_to_span_lines() {
+        let ppm = "P3\n1 1\n255\n51\n153\n\n204\n";
+
+        let canvas = PpmDecoder.decode(ppm.as_bytes()).unwrap();
+
+        assert_eq!(canvas.pixel_at(0, 0), Color::new(0.2, 0.6, 0.8));
+    }
+
+    // Scenario: PPM parsing respects the scale setting
+    //  Given ppm ← a file containing:
+    //    """
+    //    P3
+    //    2 2
+    //    100
+    //    100 100 100  50 50 50
+    //    75 50 25  0 0 0
+    //    """
+    //    And canvas ← canvas_from_ppm(ppm)
+    //  Then pixel_at(canvas, 0, 1) = color(0.75, 0.5, 0.25)
+    #[test]
+    fn ppm_parsing_respects_scale_setting() {
+        let ppm = "P3\n2 2\n100\n100 100 100  50 50 50\n75 50 25  0 0 0\n";
+
+        let canvas = PpmDecoder.decode(ppm.as_bytes()).unwrap();
+
+        assert_eq!(canvas.pixel_at(0, 1), Color::new(0.75, 0.5, 0.25));
+    }
+
+    #[test]
+    fn reading_binary_ppm() {
+        let mut ppm = b"P6 2 1 255\n".to_vec();
+        ppm.extend_from_slice(&[255, 0, 51, 0, 255, 0]);
+
+        let canvas = PpmDecoder.decode(&ppm).unwrap();
+
+        assert_eq!(canvas.pixel_at(0, 0), Color::new(1.0, 0.0, 0.2));
+        assert_eq!(canvas.pixel_at(1, 0), Color::new(0.0, 1.0, 0.0));
+        assert!(PpmDecoder.decode(&ppm[..ppm.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn decoding_encoded_ppm_round_trips() {
+        let mut c = Canvas::new(3, 2);
+        c.write_pixel(1, 1, Color::new(0.2, 1.0, 0.0));
+
+        let decoded = PpmDecoder.decode(&PpmEncoder.encode(&c)).unwrap();
+
+        assert_eq!(decoded.pixels(), c.pixels());
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Errors returned by the fallible parts of the library instead of panicking.
+#[derive(Debug)]
+pub enum RustracerError {
+    /// The matrix has a determinant of zero and cannot be inverted.
+    NonInvertibleMatrix,
+    /// The scene description is malformed or references something that does not exist.
+    InvalidScene(String),
+    /// An image file is malformed or uses a format that cannot be read.
+    InvalidImage(String),
+    /// An argument is outside the values the function accepts.
+    InvalidArgument(&'static str),
+    /// Reading or writing a file failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// A vector of magnitude zero has no direction and cannot be normalized.
+    NormalizeZeroVector,
+    /// The cross product is only defined for vectors.
+    CrossOnPoints,
+}
+
+pub type Result<T> = core::result::Result<T, RustracerError>;
+
+impl fmt::Display for RustracerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustracerError::NonInvertibleMatrix => write!(f, "matrix is not invertible"),
+            RustracerError::InvalidScene(reason) => write!(f, "invalid scene: {}", reason),
+            RustracerError::InvalidImage(reason) => write!(f, "invalid image: {}", reason),
+            RustracerError::InvalidArgument(reason) => write!(f, "invalid argument: {}", reason),
+            #[cfg(feature = "std")]
+            RustracerError::Io(err) => write!(f, "I/O error: {}", err),
+            RustracerError::NormalizeZeroVector => write!(f, "cannot normalize a zero vector"),
+            RustracerError::CrossOnPoints => {
+                write!(f, "cross product is only defined for vectors")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RustracerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RustracerError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for RustracerError {
+    fn from(err: io::Error) -> Self {
+        RustracerError::Io(err)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn io_errors_are_wrapped_with_their_source() {
+        let err: RustracerError = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+
+        assert!(matches!(err, RustracerError::Io(_)));
+        assert!(err.source().is_some());
+        assert_eq!(err.to_string(), "I/O error: missing");
+    }
+}
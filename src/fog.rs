@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+/// How fog thickens with the distance to what is seen through it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FogFalloff {
+    /// No fog up to `start`, then evenly thicker until everything past `end` is hidden.
+    Linear { start: Float, end: Float },
+    /// Hides `1 - e^(-density * d)` of what is `d` away, so it never quite clears up close.
+    Exponential { density: Float },
+}
+
+/// Fog blended over what rays hit, hiding distant objects behind its color.
+///
+/// Set on [`World::fog`], it only needs the distance to every hit, unlike light scattered
+/// through a volume.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Fog {
+    pub color: Color,
+    pub falloff: FogFalloff,
+}
+
+impl Fog {
+    /// # Panics
+    ///
+    /// Panics if `start` is negative or `end` is not past it.
+    pub fn linear(color: Color, start: Float, end: Float) -> Self {
+        Self::try_linear(color, start, end).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fog like [`Fog::linear`], failing instead of panicking on a bad `start` or `end`.
+    pub fn try_linear(color: Color, start: Float, end: Float) -> Result<Self> {
+        if start < 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "fog cannot start behind the eye",
+            ));
+        }
+        if end <= start {
+            return Err(RustracerError::InvalidArgument(
+                "fog must end past where it starts",
+            ));
+        }
+        Ok(Self {
+            color,
+            falloff: FogFalloff::Linear { start, end },
+        })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `density` is negative.
+    pub fn exponential(color: Color, density: Float) -> Self {
+        Self::try_exponential(color, density).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fog like [`Fog::exponential`], failing instead of panicking on a negative `density`.
+    pub fn try_exponential(color: Color, density: Float) -> Result<Self> {
+        if density < 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the density of fog cannot be negative",
+            ));
+        }
+        Ok(Self {
+            color,
+            falloff: FogFalloff::Exponential { density },
+        })
+    }
+
+    /// Fraction of something `distance` away hidden by the fog, from 0 to 1.
+    pub fn amount(&self, distance: Float) -> Float {
+        match self.falloff {
+            FogFalloff::Linear { start, end } => {
+                ((distance - start) / (end - start)).clamp(0.0, 1.0)
+            }
+            FogFalloff::Exponential { density } => 1.0 - (-density * distance).exp(),
+        }
+    }
+
+    /// `color` of something `distance` away, as seen through the fog.
+    pub fn apply(&self, color: Color, distance: Float) -> Color {
+        let amount = self.amount(distance);
+        color * (1.0 - amount) + self.color * amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn linear_fog_thickens_between_start_and_end() {
+        let fog = Fog::linear(Color::white(), 10.0, 30.0);
+
+        assert_eq!(fog.amount(0.0), 0.0);
+        assert_eq!(fog.amount(10.0), 0.0);
+        assert_eq!(fog.amount(15.0), 0.25);
+        assert_eq!(fog.amount(30.0), 1.0);
+        assert_eq!(fog.amount(100.0), 1.0);
+    }
+
+    #[test]
+    fn exponential_fog_thickens_with_distance() {
+        let fog = Fog::exponential(Color::white(), 0.5);
+
+        assert_eq!(fog.amount(0.0), 0.0);
+        assert!(is_approx(fog.amount(2.0), 0.63212, None));
+        assert!(fog.amount(20.0) > 0.9999);
+    }
+
+    #[test]
+    fn fog_blends_its_color_over_what_is_seen() {
+        let fog = Fog::linear(Color::new(0.5, 0.5, 1.0), 0.0, 4.0);
+
+        assert_eq!(
+            fog.apply(Color::new(1.0, 0.0, 0.0), 1.0),
+            Color::new(0.875, 0.125, 0.25)
+        );
+        assert_eq!(fog.apply(Color::black(), 8.0), fog.color);
+    }
+
+    #[test]
+    fn trying_fog_with_bad_values_fails() {
+        assert!(Fog::try_linear(Color::white(), -1.0, 5.0).is_err());
+        assert!(Fog::try_linear(Color::white(), 5.0, 5.0).is_err());
+        assert!(Fog::try_exponential(Color::white(), -0.1).is_err());
+        assert_eq!(
+            Fog::try_exponential(Color::white(), 0.5).unwrap(),
+            Fog::exponential(Color::white(), 0.5)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fog_ending_before_it_starts_panics() {
+        Fog::linear(Color::white(), 5.0, 5.0);
+    }
+}
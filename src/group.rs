@@ -0,0 +1,657 @@
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::{mem, ptr};
+
+type Children = Vec<Box<dyn Shape>>;
+
+/// A collection of shapes transformed together as a single unit.
+///
+/// The group's transformation applies on top of each child's own, so groups can be
+/// nested to build up complex models from simpler parts. Rays that miss the box enclosing
+/// all the children are not tested against any of them.
+#[derive(Debug, Default)]
+pub struct Group {
+    properties: ShapeProperties,
+    children: Children,
+    /// Union of the children's bounds, in the group's object space.
+    bounds: BoundingBox,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Adds `child` to the group, placing it under the group's transformation.
+    pub fn add_child<S: Shape + 'static>(&mut self, child: S) {
+        self.add_boxed_child(Box::new(child));
+    }
+
+    pub fn add_boxed_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_transform(self.properties.world_transform());
+        child.set_parent_visibility(self.properties.world_visibility());
+        self.bounds.add_box(&child.parent_space_bounds());
+        self.children.push(child);
+    }
+
+    pub fn with_child<S: Shape + 'static>(mut self, child: S) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    /// Takes out the children that fit entirely in either half of the group's bounds.
+    ///
+    /// Children straddling both halves stay in the group.
+    fn partition_children(&mut self) -> (Children, Children) {
+        let (left_bounds, right_bounds) = self.bounds.split();
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for child in mem::take(&mut self.children) {
+            let bounds = child.parent_space_bounds();
+            if left_bounds.contains_box(&bounds) {
+                left.push(child);
+            } else if right_bounds.contains_box(&bounds) {
+                right.push(child);
+            } else {
+                self.children.push(child);
+            }
+        }
+
+        (left, right)
+    }
+
+    /// Adds a group holding `children` as a single child.
+    fn make_subgroup(&mut self, children: Children) {
+        let mut subgroup = Group::new();
+        for child in children {
+            subgroup.add_boxed_child(child);
+        }
+        self.add_child(subgroup);
+    }
+}
+
+impl Shape for Group {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.properties.set_parent_transform(parent_transform);
+
+        let world_transform = self.properties.world_transform();
+        for child in &mut self.children {
+            child.set_parent_transform(world_transform);
+        }
+    }
+
+    fn set_parent_visibility(&mut self, parent_visibility: Visibility) {
+        self.properties.set_parent_visibility(parent_visibility);
+
+        let world_visibility = self.properties.world_visibility();
+        for child in &mut self.children {
+            child.set_parent_visibility(world_visibility);
+        }
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut xs = Intersections::default();
+        if !self.bounds.intersects(ray) {
+            return xs;
+        }
+
+        for child in &self.children {
+            xs.extend(child.intersect(ray));
+        }
+        xs
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        if threshold <= self.children.len() {
+            let (left, right) = self.partition_children();
+            if self.children.is_empty() && (left.is_empty() || right.is_empty()) {
+                // Every child fits in the same half, as when they all have no extent, so a
+                // subgroup would just be split the same way again.
+                self.children = if left.is_empty() { right } else { left };
+            } else {
+                if !left.is_empty() {
+                    self.make_subgroup(left);
+                }
+                if !right.is_empty() {
+                    self.make_subgroup(right);
+                }
+            }
+        }
+
+        for child in &mut self.children {
+            child.divide(threshold);
+        }
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        ptr::addr_eq(self, other) || self.children.iter().any(|child| child.includes(other))
+    }
+
+    /// # Panics
+    ///
+    /// Always panics: rays only ever hit the children of a group, never the group itself.
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        unreachable!("groups have no surface of their own")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_PI_2, PI};
+    use crate::prelude::*;
+    use crate::shape::TestShape;
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ptr;
+
+    // Scenario: Creating a new group
+    //  Given g ← group()
+    //  Then g.transform = identity_matrix
+    //    And g is empty
+    #[test]
+    fn creating_new_group() {
+        let g = Group::new();
+
+        assert_eq!(*g.transform(), Matrix4::identity());
+        assert!(g.is_empty());
+    }
+
+    // Scenario: Adding a child to a group
+    //  Given g ← group()
+    //    And s ← test_shape()
+    //  When add_child(g, s)
+    //  Then g is not empty
+    //    And g includes s
+    //    And s.parent = g
+    #[test]
+    fn adding_child_to_group() {
+        let mut g = Group::new().with_transform(Matrix4::translation(1.0, 2.0, 3.0));
+        g.add_child(Sphere::new());
+
+        assert!(!g.is_empty());
+        assert!(g.children()[0].downcast_ref::<Sphere>().is_some());
+        assert!(g.includes(g.children()[0].as_ref()));
+        assert_eq!(
+            *g.children()[0].properties().parent_transform(),
+            *g.transform()
+        );
+    }
+
+    // Scenario: Intersecting a ray with an empty group
+    //  Given g ← group()
+    //    And r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //  When xs ← local_intersect(g, r)
+    //  Then xs is empty
+    #[test]
+    fn intersecting_ray_with_empty_group() {
+        let g = Group::new();
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(g.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: Intersecting a ray with a nonempty group
+    //  Given g ← group()
+    //    And s1 ← sphere()
+    //    And s2 ← sphere()
+    //    And set_transform(s2, translation(0, 0, -3))
+    //    And s3 ← sphere()
+    //    And set_transform(s3, translation(5, 0, 0))
+    //    And add_child(g, s1)
+    //    And add_child(g, s2)
+    //    And add_child(g, s3)
+    //  When r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And xs ← local_intersect(g, r)
+    //  Then xs.count = 4
+    //    And xs[0].object = s2
+    //    And xs[1].object = s2
+    //    And xs[2].object = s1
+    //    And xs[3].object = s1
+    #[test]
+    fn intersecting_ray_with_nonempty_group() {
+        let g = Group::new()
+            .with_child(Sphere::new())
+            .with_child(Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, -3.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0)));
+        let s1 = g.children()[0].as_ref();
+        let s2 = g.children()[1].as_ref();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = g.local_intersect(&r);
+
+        assert_eq!(xs.len(), 4);
+        assert!(ptr::addr_eq(xs[0].object, s2));
+        assert!(ptr::addr_eq(xs[1].object, s2));
+        assert!(ptr::addr_eq(xs[2].object, s1));
+        assert!(ptr::addr_eq(xs[3].object, s1));
+    }
+
+    // Scenario: Intersecting a transformed group
+    //  Given g ← group()
+    //    And set_transform(g, scaling(2, 2, 2))
+    //    And s ← sphere()
+    //    And set_transform(s, translation(5, 0, 0))
+    //    And add_child(g, s)
+    //  When r ← ray(point(10, 0, -10), vector(0, 0, 1))
+    //    And xs ← intersect(g, r)
+    //  Then xs.count = 2
+    #[test]
+    fn intersecting_transformed_group() {
+        let g = Group::new()
+            .with_transform(Matrix4::scaling(2.0, 2.0, 2.0))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0)));
+        let r = Ray::new(Point::new(10.0, 0.0, -10.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert_eq!(g.intersect(&r).len(), 2);
+    }
+
+    // Scenario: Converting a point from world to object space
+    //  Given g1 ← group()
+    //    And set_transform(g1, rotation_y(π/2))
+    //    And g2 ← group()
+    //    And set_transform(g2, scaling(2, 2, 2))
+    //    And add_child(g1, g2)
+    //    And s ← sphere()
+    //    And set_transform(s, translation(5, 0, 0))
+    //    And add_child(g2, s)
+    //  When p ← world_to_object(s, point(-2, 0, -10))
+    //  Then p = point(0, 0, -1)
+    #[test]
+    fn converting_point_from_world_to_object_space() {
+        let g1 = nested_groups();
+        let s = innermost_sphere(&g1);
+
+        let p = s.world_to_object(Point::new(-2.0, 0.0, -10.0));
+
+        assert_eq!(p, Point::new(0.0, 0.0, -1.0));
+    }
+
+    // Scenario: Converting a normal from object to world space
+    //  Given g1 ← group()
+    //    And set_transform(g1, rotation_y(π/2))
+    //    And g2 ← group()
+    //    And set_transform(g2, scaling(1, 2, 3))
+    //    And add_child(g1, g2)
+    //    And s ← sphere()
+    //    And set_transform(s, translation(5, 0, 0))
+    //    And add_child(g2, s)
+    //  When n ← normal_to_world(s, vector(√3/3, √3/3, √3/3))
+    //  Then n = vector(0.2857, 0.4286, -0.8571)
+    #[test]
+    fn converting_normal_from_object_to_world_space() {
+        let mut g1 = Group::new().with_transform(Matrix4::rotation_y(FRAC_PI_2));
+        g1.add_child(
+            Group::new()
+                .with_transform(Matrix4::scaling(1.0, 2.0, 3.0))
+                .with_child(Sphere::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0))),
+        );
+        let s = innermost_sphere(&g1);
+        let third = Float::sqrt(3.0) / 3.0;
+
+        let n = s.normal_to_world(Vector::new(third, third, third));
+
+        assert_eq!(n, Vector::new(0.2857, 0.4286, -0.8571));
+    }
+
+    // Scenario: Finding the normal on a child object
+    //  Given g1 ← group()
+    //    And set_transform(g1, rotation_y(π/2))
+    //    And g2 ← group()
+    //    And set_transform(g2, scaling(1, 2, 3))
+    //    And add_child(g1, g2)
+    //    And s ← sphere()
+    //    And set_transform(s, translation(5, 0, 0))
+    //    And add_child(g2, s)
+    //  When n ← normal_at(s, point(1.7321, 1.1547, -5.5774))
+    //  Then n = vector(0.2857, 0.4286, -0.8571)
+    #[test]
+    fn finding_normal_on_child_object() {
+        let g1 = Group::new()
+            .with_transform(Matrix4::rotation_y(FRAC_PI_2))
+            .with_child(
+                Group::new()
+                    .with_transform(Matrix4::scaling(1.0, 2.0, 3.0))
+                    .with_child(Sphere::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0))),
+            );
+        let s = innermost_sphere(&g1);
+
+        let n = s.normal_at(Point::new(1.7321, 1.1547, -5.5774));
+
+        assert_eq!(n, Vector::new(0.2857, 0.4286, -0.8571));
+    }
+
+    #[test]
+    fn transforming_group_after_adding_children_updates_them() {
+        let mut g1 = nested_groups();
+        g1.set_transform(Matrix4::rotation_y(PI));
+        let s = innermost_sphere(&g1);
+
+        let p = s.world_to_object(Point::new(-10.0, 0.0, 0.0));
+
+        assert_eq!(p, Point::new(0.0, 0.0, 0.0));
+    }
+
+    // Scenario: A group has a bounding box that contains its children
+    //  Given s ← sphere()
+    //    And set_transform(s, translation(2, 5, -3) * scaling(2, 2, 2))
+    //    And c ← cylinder()
+    //    And c.minimum ← -2
+    //    And c.maximum ← 2
+    //    And set_transform(c, translation(-4, -1, 4) * scaling(0.5, 1, 0.5))
+    //    And shape ← group()
+    //    And add_child(shape, s)
+    //    And add_child(shape, c)
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-4.5, -3, -5)
+    //    And box.max = point(4, 7, 4.5)
+    #[test]
+    fn group_has_bounding_box_containing_its_children() {
+        let shape = Group::new()
+            .with_child(Sphere::new().with_transform(
+                Matrix4::translation(2.0, 5.0, -3.0) * Matrix4::scaling(2.0, 2.0, 2.0),
+            ))
+            .with_child(Cylinder::truncated(-2.0, 2.0, false).with_transform(
+                Matrix4::translation(-4.0, -1.0, 4.0) * Matrix4::scaling(0.5, 1.0, 0.5),
+            ));
+
+        let b = shape.bounds();
+
+        assert_eq!(b.min, Point::new(-4.5, -3.0, -5.0));
+        assert_eq!(b.max, Point::new(4.0, 7.0, 4.5));
+    }
+
+    // Scenario: Intersecting ray+group doesn't test children if box is missed
+    //  Given child ← test_shape()
+    //    And shape ← group()
+    //    And add_child(shape, child)
+    //    And r ← ray(point(0, 0, -5), vector(0, 1, 0))
+    //  When xs ← intersect(shape, r)
+    //  Then child.saved_ray is unset
+    #[test]
+    fn intersecting_group_does_not_test_children_if_box_is_missed() {
+        let shape = Group::new().with_child(TestShape::new());
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        shape.intersect(&r);
+
+        assert!(test_shape(shape.children()[0].as_ref())
+            .saved_ray()
+            .is_none());
+    }
+
+    // Scenario: Intersecting ray+group tests children if box is hit
+    //  Given child ← test_shape()
+    //    And shape ← group()
+    //    And add_child(shape, child)
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //  When xs ← intersect(shape, r)
+    //  Then child.saved_ray is set
+    #[test]
+    fn intersecting_group_tests_children_if_box_is_hit() {
+        let shape = Group::new().with_child(TestShape::new());
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        shape.intersect(&r);
+
+        assert!(test_shape(shape.children()[0].as_ref())
+            .saved_ray()
+            .is_some());
+    }
+
+    // Scenario: Partitioning a group's children
+    //  Given s1 ← sphere() with:
+    //      | transform | translation(-2, 0, 0) |
+    //    And s2 ← sphere() with:
+    //      | transform | translation(2, 0, 0) |
+    //    And s3 ← sphere()
+    //    And g ← group of [s1, s2, s3]
+    //  When (left, right) ← partition_children(g)
+    //  Then g is a group of [s3]
+    //    And left = [s1]
+    //    And right = [s2]
+    #[test]
+    fn partitioning_group_children() {
+        let mut g = Group::new()
+            .with_child(Sphere::new().with_transform(Matrix4::translation(-2.0, 0.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(2.0, 0.0, 0.0)))
+            .with_child(Sphere::new());
+
+        let (left, right) = g.partition_children();
+
+        assert_eq!(g.children().len(), 1);
+        assert_eq!(*g.children()[0].transform(), Matrix4::identity());
+        assert_eq!(left.len(), 1);
+        assert_eq!(*left[0].transform(), Matrix4::translation(-2.0, 0.0, 0.0));
+        assert_eq!(right.len(), 1);
+        assert_eq!(*right[0].transform(), Matrix4::translation(2.0, 0.0, 0.0));
+    }
+
+    // Scenario: Creating a sub-group from a list of children
+    //  Given s1 ← sphere()
+    //    And s2 ← sphere()
+    //    And g ← group()
+    //  When make_subgroup(g, [s1, s2])
+    //  Then g.count = 1
+    //    And g[0] is a group of [s1, s2]
+    #[test]
+    fn creating_subgroup_from_list_of_children() {
+        let mut g = Group::new();
+
+        g.make_subgroup(vec![Box::new(Sphere::new()), Box::new(Sphere::new())]);
+
+        assert_eq!(g.children().len(), 1);
+        assert_eq!(group(g.children()[0].as_ref()).children().len(), 2);
+    }
+
+    // Scenario: Subdividing a group partitions its children
+    //  Given s1 ← sphere() with:
+    //      | transform | translation(-2, -2, 0) |
+    //    And s2 ← sphere() with:
+    //      | transform | translation(-2, 2, 0) |
+    //    And s3 ← sphere() with:
+    //      | transform | scaling(4, 4, 4) |
+    //    And g ← group of [s1, s2, s3]
+    //  When divide(g, 1)
+    //  Then g[0] = s3
+    //    And subgroup ← g[1]
+    //    And subgroup is a group
+    //    And subgroup.count = 2
+    //    And subgroup[0] is a group of [s1]
+    //    And subgroup[1] is a group of [s2]
+    #[test]
+    fn subdividing_group_partitions_its_children() {
+        let mut g = Group::new()
+            .with_child(Sphere::new().with_transform(Matrix4::translation(-2.0, -2.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(-2.0, 2.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::scaling(4.0, 4.0, 4.0)));
+
+        g.divide(1);
+
+        assert_eq!(
+            *g.children()[0].transform(),
+            Matrix4::scaling(4.0, 4.0, 4.0)
+        );
+        let subgroup = group(g.children()[1].as_ref());
+        assert_eq!(subgroup.children().len(), 2);
+        let left = group(subgroup.children()[0].as_ref());
+        let right = group(subgroup.children()[1].as_ref());
+        assert_eq!(left.children().len(), 1);
+        assert_eq!(
+            *left.children()[0].transform(),
+            Matrix4::translation(-2.0, -2.0, 0.0)
+        );
+        assert_eq!(right.children().len(), 1);
+        assert_eq!(
+            *right.children()[0].transform(),
+            Matrix4::translation(-2.0, 2.0, 0.0)
+        );
+    }
+
+    // Scenario: Subdividing a group with too few children
+    //  Given s1 ← sphere() with:
+    //      | transform | translation(-2, 0, 0) |
+    //    And s2 ← sphere() with:
+    //      | transform | translation(2, 1, 0) |
+    //    And s3 ← sphere() with:
+    //      | transform | translation(2, -1, 0) |
+    //    And subgroup ← group of [s1, s2, s3]
+    //    And s4 ← sphere()
+    //    And g ← group of [subgroup, s4]
+    //  When divide(g, 3)
+    //  Then g[0] = subgroup
+    //    And g[1] = s4
+    //    And subgroup.count = 2
+    //    And subgroup[0] is a group of [s1]
+    //    And subgroup[1] is a group of [s2, s3]
+    #[test]
+    fn subdividing_group_with_too_few_children() {
+        let subgroup = Group::new()
+            .with_child(Sphere::new().with_transform(Matrix4::translation(-2.0, 0.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(2.0, 1.0, 0.0)))
+            .with_child(Sphere::new().with_transform(Matrix4::translation(2.0, -1.0, 0.0)));
+        let mut g = Group::new().with_child(subgroup).with_child(Sphere::new());
+
+        g.divide(3);
+
+        assert_eq!(g.children().len(), 2);
+        assert!(g.children()[1].downcast_ref::<Sphere>().is_some());
+        let subgroup = group(g.children()[0].as_ref());
+        assert_eq!(subgroup.children().len(), 2);
+        assert_eq!(group(subgroup.children()[0].as_ref()).children().len(), 1);
+        assert_eq!(group(subgroup.children()[1].as_ref()).children().len(), 2);
+    }
+
+    #[test]
+    fn subdividing_group_keeps_intersections() {
+        let mut g = Group::new().with_transform(Matrix4::translation(0.0, 0.0, 1.0));
+        for x in -3..=3 {
+            g.add_child(Sphere::new().with_transform(
+                Matrix4::translation(x as Float * 3.0, 0.0, 0.0) * Matrix4::scaling(0.5, 0.5, 0.5),
+            ));
+        }
+        let r = Ray::new(Point::new(6.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let before: Vec<Float> = g.intersect(&r).iter().map(|i| i.t).collect();
+
+        g.divide(2);
+
+        let after: Vec<Float> = g.intersect(&r).iter().map(|i| i.t).collect();
+        assert_eq!(before, vec![5.5, 6.5]);
+        assert_eq!(after, before);
+        assert!(g.children().len() < 7);
+    }
+
+    #[test]
+    fn subdividing_group_of_shapes_without_extent() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        let mut g = Group::new();
+        for _ in 0..3 {
+            g.add_child(Triangle::new(p, p, p));
+        }
+
+        g.divide(1);
+
+        assert_eq!(g.children().len(), 3);
+        assert!(g
+            .children()
+            .iter()
+            .all(|child| child.downcast_ref::<Triangle>().is_some()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn normal_of_group_itself_panics() {
+        Group::new().local_normal_at(Point::origin());
+    }
+
+    fn nested_groups() -> Group {
+        let mut g1 = Group::new().with_transform(Matrix4::rotation_y(FRAC_PI_2));
+        let mut g2 = Group::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        g2.add_child(Sphere::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0)));
+        g1.add_child(g2);
+        g1
+    }
+
+    fn group(shape: &dyn Shape) -> &Group {
+        shape.downcast_ref().unwrap()
+    }
+
+    fn test_shape(shape: &dyn Shape) -> &TestShape {
+        shape.downcast_ref().unwrap()
+    }
+
+    fn innermost_sphere(g1: &Group) -> &dyn Shape {
+        let g2 = g1.children()[0].downcast_ref::<Group>().unwrap();
+        g2.children()[0].as_ref()
+    }
+
+    #[test]
+    fn hiding_group_hides_children_added_later() {
+        let mut g = Group::new().with_child(Sphere::new());
+        g.set_visibility(Visibility {
+            camera: false,
+            ..Visibility::ALL
+        });
+        g.add_child(Sphere::new());
+
+        for child in g.children() {
+            assert_eq!(child.visibility(), Visibility::ALL);
+            assert!(!child.world_visibility().camera);
+        }
+    }
+
+    fn first_grandchild(g: &Group) -> &dyn Shape {
+        g.children()[0].downcast_ref::<Group>().unwrap().children()[0].as_ref()
+    }
+
+    #[test]
+    fn children_keep_their_own_visibility_inside_groups() {
+        let mut inner = Group::new().with_child(Sphere::new());
+        inner.set_visibility(Visibility {
+            shadows: false,
+            ..Visibility::ALL
+        });
+        let mut g = Group::new().with_child(inner);
+
+        g.set_visibility(Visibility {
+            reflections: false,
+            ..Visibility::ALL
+        });
+        let sphere = first_grandchild(&g);
+        assert_eq!(
+            sphere.world_visibility(),
+            Visibility {
+                shadows: false,
+                reflections: false,
+                ..Visibility::ALL
+            }
+        );
+
+        g.set_visibility(Visibility::ALL);
+        let sphere = first_grandchild(&g);
+        assert!(!sphere.world_visibility().shadows);
+        assert!(sphere.world_visibility().reflections);
+    }
+}
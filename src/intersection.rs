@@ -0,0 +1,673 @@
+use crate::prelude::*;
+use alloc::vec::Vec;
+use core::ops::Index;
+use core::ptr;
+use core::slice;
+
+/// A point where a ray crosses the surface of an object, `t` units along the ray.
+#[derive(Debug, Copy, Clone)]
+pub struct Intersection<'a> {
+    pub t: Float,
+    pub object: &'a dyn Shape,
+    /// Where on a triangle the hit is, relative to its second and third vertex.
+    pub u: Float,
+    pub v: Float,
+}
+
+impl<'a> Intersection<'a> {
+    pub fn new(t: Float, object: &'a dyn Shape) -> Self {
+        Self::with_uv(t, object, 0.0, 0.0)
+    }
+
+    pub fn with_uv(t: Float, object: &'a dyn Shape, u: Float, v: Float) -> Self {
+        Self { t, object, u, v }
+    }
+
+    /// Precomputes the geometry needed to shade this intersection of `ray`.
+    ///
+    /// `xs` holds every intersection of `ray`, so the refractive indices on both sides
+    /// of the surface can be found.
+    pub fn prepare_computations(&self, ray: &Ray, xs: &Intersections<'a>) -> Computations<'a> {
+        let point = ray.position(self.t);
+        let eyev = -ray.direction;
+        let mut normalv = self.object.normal_at_hit(point, self);
+
+        let inside = normalv.dot(&eyev) < 0.0;
+        if inside {
+            normalv = -normalv;
+        }
+
+        let (medium, n1, n2) = self.refractive_indices(xs);
+
+        Computations {
+            t: self.t,
+            object: self.object,
+            point,
+            over_point: point + normalv * EPSILON,
+            under_point: point - normalv * EPSILON,
+            eyev,
+            normalv,
+            reflectv: ray.direction.reflect(&normalv),
+            inside,
+            n1,
+            n2,
+            medium,
+        }
+    }
+
+    /// Object the ray travels through to reach this intersection, if any, and the
+    /// refractive indices of the materials the ray leaves and enters there.
+    fn refractive_indices(&self, xs: &Intersections<'a>) -> (Option<&'a dyn Shape>, Float, Float) {
+        fn outermost(containers: &[&dyn Shape]) -> Float {
+            containers
+                .last()
+                .map_or(1.0, |object| object.material().refractive_index)
+        }
+
+        // Walk the intersections in order, tracking which objects the ray is inside of.
+        let mut containers: Vec<&dyn Shape> = Vec::new();
+        let mut medium = None;
+        let mut n1 = 1.0;
+        for i in xs {
+            let is_hit = i == self;
+            if is_hit {
+                medium = containers.last().copied();
+                n1 = outermost(&containers);
+            }
+
+            match containers
+                .iter()
+                .position(|&object| ptr::addr_eq(object, i.object))
+            {
+                Some(index) => {
+                    containers.remove(index);
+                }
+                None => containers.push(i.object),
+            }
+
+            if is_hit {
+                return (medium, n1, outermost(&containers));
+            }
+        }
+
+        (medium, n1, 1.0)
+    }
+}
+
+/// Shading state of an intersection, see [`Intersection::prepare_computations`].
+#[derive(Debug, Copy, Clone)]
+pub struct Computations<'a> {
+    pub t: Float,
+    pub object: &'a dyn Shape,
+    pub point: Point,
+    /// `point` nudged along the normal, so rays cast from it do not hit the surface itself.
+    pub over_point: Point,
+    /// `point` nudged against the normal, where refracted rays start.
+    pub under_point: Point,
+    pub eyev: Vector,
+    /// Surface normal, flipped to face the eye when the hit is inside the object.
+    pub normalv: Vector,
+    /// Direction of the ray bouncing off the surface.
+    pub reflectv: Vector,
+    pub inside: bool,
+    /// Refractive index of the material the ray is leaving.
+    pub n1: Float,
+    /// Refractive index of the material the ray is entering.
+    pub n2: Float,
+    /// Object the ray went through on its way to the surface, whose material absorbs some
+    /// of the light.
+    pub medium: Option<&'a dyn Shape>,
+}
+
+impl Computations<'_> {
+    /// Fraction of light reflected rather than refracted at the surface, using Schlick's
+    /// approximation of the Fresnel equations.
+    pub fn schlick(&self) -> Float {
+        let mut cos = self.eyev.dot(&self.normalv);
+
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n * n * (1.0 - cos * cos);
+            // Total internal reflection.
+            if sin2_t > 1.0 {
+                return 1.0;
+            }
+            cos = (1.0 - sin2_t).sqrt();
+        }
+
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cos).powi(5)
+    }
+}
+
+/// Intersections are the same when they are at the same distance on the very same object.
+impl PartialEq for Intersection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        is_approx(self.t, other.t, None) && ptr::addr_eq(self.object, other.object)
+    }
+}
+
+/// Intersections of a ray with one or more objects, kept sorted by increasing `t`.
+#[derive(Debug, Clone, Default)]
+pub struct Intersections<'a> {
+    list: Vec<Intersection<'a>>,
+}
+
+impl<'a> Intersections<'a> {
+    pub fn new(mut list: Vec<Intersection<'a>>) -> Self {
+        sort(&mut list);
+        Self { list }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Intersection<'a>> {
+        self.list.iter()
+    }
+
+    /// Adds more intersections, e.g. those of another object, preserving the order.
+    pub fn extend<I: IntoIterator<Item = Intersection<'a>>>(&mut self, intersections: I) {
+        self.list.extend(intersections);
+        sort(&mut self.list);
+    }
+
+    /// The visible intersection: the one with the lowest non-negative `t`.
+    ///
+    /// Intersections behind the ray origin are never hits. Ties keep the order in
+    /// which the intersections were added.
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        self.list.iter().find(|i| i.t >= 0.0)
+    }
+}
+
+fn sort(list: &mut [Intersection]) {
+    list.sort_by(|a, b| a.t.total_cmp(&b.t));
+}
+
+impl<'a> Index<usize> for Intersections<'a> {
+    type Output = Intersection<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.list[index]
+    }
+}
+
+impl<'a> IntoIterator for Intersections<'a> {
+    type Item = Intersection<'a>;
+    type IntoIter = alloc::vec::IntoIter<Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Intersections<'a> {
+    type Item = &'b Intersection<'a>;
+    type IntoIter = slice::Iter<'b, Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+impl<'a> From<Vec<Intersection<'a>>> for Intersections<'a> {
+    fn from(list: Vec<Intersection<'a>>) -> Self {
+        Self::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_1_SQRT_2, SQRT_2};
+    use crate::prelude::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // Scenario: An intersection encapsulates t and object
+    //  Given s ← sphere()
+    //  When i ← intersection(3.5, s)
+    //  Then i.t = 3.5
+    //    And i.object = s
+    #[test]
+    fn intersection_encapsulates_t_and_object() {
+        let s = Sphere::new();
+        let i = Intersection::new(3.5, &s);
+
+        assert_eq!(i.t, 3.5);
+        assert!(core::ptr::addr_eq(i.object, &s));
+    }
+
+    // Scenario: An intersection can encapsulate `u` and `v`
+    //  Given s ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //  When i ← intersection_with_uv(3.5, s, 0.2, 0.4)
+    //  Then i.u = 0.2
+    //    And i.v = 0.4
+    #[test]
+    fn intersection_can_encapsulate_u_and_v() {
+        let s = Triangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        );
+        let i = Intersection::with_uv(3.5, &s, 0.2, 0.4);
+
+        assert_eq!(i.u, 0.2);
+        assert_eq!(i.v, 0.4);
+    }
+
+    // Scenario: Aggregating intersections
+    //  Given s ← sphere()
+    //    And i1 ← intersection(1, s)
+    //    And i2 ← intersection(2, s)
+    //  When xs ← intersections(i1, i2)
+    //  Then xs.count = 2
+    //    And xs[0].t = 1
+    //    And xs[1].t = 2
+    #[test]
+    fn aggregating_intersections() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(1.0, &s);
+        let i2 = Intersection::new(2.0, &s);
+        let xs = Intersections::new(vec![i1, i2]);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 1.0);
+        assert_eq!(xs[1].t, 2.0);
+    }
+
+    // Scenario: Intersect sets the object on the intersection
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0].object = s
+    //    And xs[1].object = s
+    #[test]
+    fn intersect_sets_object_on_intersection() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert!(core::ptr::addr_eq(xs[0].object, &s));
+        assert!(core::ptr::addr_eq(xs[1].object, &s));
+    }
+
+    // Scenario: The hit, when all intersections have positive t
+    //  Given s ← sphere()
+    //    And i1 ← intersection(1, s)
+    //    And i2 ← intersection(2, s)
+    //    And xs ← intersections(i2, i1)
+    //  When i ← hit(xs)
+    //  Then i = i1
+    #[test]
+    fn hit_when_all_intersections_have_positive_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(1.0, &s);
+        let i2 = Intersection::new(2.0, &s);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i1));
+    }
+
+    // Scenario: The hit, when some intersections have negative t
+    //  Given s ← sphere()
+    //    And i1 ← intersection(-1, s)
+    //    And i2 ← intersection(1, s)
+    //    And xs ← intersections(i2, i1)
+    //  When i ← hit(xs)
+    //  Then i = i2
+    #[test]
+    fn hit_when_some_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(-1.0, &s);
+        let i2 = Intersection::new(1.0, &s);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i2));
+    }
+
+    // Scenario: The hit, when all intersections have negative t
+    //  Given s ← sphere()
+    //    And i1 ← intersection(-2, s)
+    //    And i2 ← intersection(-1, s)
+    //    And xs ← intersections(i2, i1)
+    //  When i ← hit(xs)
+    //  Then i is nothing
+    #[test]
+    fn hit_when_all_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(-2.0, &s);
+        let i2 = Intersection::new(-1.0, &s);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), None);
+    }
+
+    // Scenario: The hit is always the lowest nonnegative intersection
+    //  Given s ← sphere()
+    //  And i1 ← intersection(5, s)
+    //  And i2 ← intersection(7, s)
+    //  And i3 ← intersection(-3, s)
+    //  And i4 ← intersection(2, s)
+    //  And xs ← intersections(i1, i2, i3, i4)
+    //  When i ← hit(xs)
+    //  Then i = i4
+    #[test]
+    fn hit_is_always_lowest_nonnegative_intersection() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(5.0, &s);
+        let i2 = Intersection::new(7.0, &s);
+        let i3 = Intersection::new(-3.0, &s);
+        let i4 = Intersection::new(2.0, &s);
+        let xs = Intersections::new(vec![i1, i2, i3, i4]);
+
+        assert_eq!(xs.hit(), Some(&i4));
+    }
+
+    #[test]
+    fn hit_at_zero_counts_as_nonnegative() {
+        let s = Sphere::new();
+        let xs = Intersections::new(vec![
+            Intersection::new(-1.0, &s),
+            Intersection::new(0.0, &s),
+        ]);
+
+        assert_eq!(xs.hit().map(|i| i.t), Some(0.0));
+    }
+
+    #[test]
+    fn hit_on_tie_is_first_intersection_added() {
+        let s1 = Sphere::new();
+        let s2 = Sphere::new();
+        let xs = Intersections::new(vec![
+            Intersection::new(1.0, &s2),
+            Intersection::new(1.0, &s1),
+        ]);
+
+        assert!(core::ptr::addr_eq(xs.hit().unwrap().object, &s2));
+    }
+
+    #[test]
+    fn extending_intersections_keeps_them_sorted() {
+        let s = Sphere::new();
+        let mut xs = Intersections::new(vec![Intersection::new(4.0, &s)]);
+        xs.extend(vec![
+            Intersection::new(-1.0, &s),
+            Intersection::new(2.0, &s),
+        ]);
+
+        let ts: Vec<Float> = xs.iter().map(|i| i.t).collect();
+        assert_eq!(ts, vec![-1.0, 2.0, 4.0]);
+    }
+
+    // Scenario: Precomputing the state of an intersection
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And shape ← sphere()
+    //    And i ← intersection(4, shape)
+    //  When comps ← prepare_computations(i, r)
+    //  Then comps.t = i.t
+    //    And comps.object = i.object
+    //    And comps.point = point(0, 0, -1)
+    //    And comps.eyev = vector(0, 0, -1)
+    //    And comps.normalv = vector(0, 0, -1)
+    #[test]
+    fn precomputing_state_of_intersection() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(4.0, &shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+
+        assert_eq!(comps.t, i.t);
+        assert!(core::ptr::addr_eq(comps.object, i.object));
+        assert_eq!(comps.point, Point::new(0.0, 0.0, -1.0));
+        assert_eq!(comps.eyev, Vector::new(0.0, 0.0, -1.0));
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    // Scenario: The hit, when an intersection occurs on the outside
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And shape ← sphere()
+    //    And i ← intersection(4, shape)
+    //  When comps ← prepare_computations(i, r)
+    //  Then comps.inside = false
+    #[test]
+    fn hit_when_intersection_occurs_on_outside() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(4.0, &shape);
+
+        assert!(
+            !i.prepare_computations(&r, &Intersections::new(vec![i]))
+                .inside
+        );
+    }
+
+    // Scenario: The hit, when an intersection occurs on the inside
+    //  Given r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //    And shape ← sphere()
+    //    And i ← intersection(1, shape)
+    //  When comps ← prepare_computations(i, r)
+    //  Then comps.point = point(0, 0, 1)
+    //    And comps.eyev = vector(0, 0, -1)
+    //    And comps.inside = true
+    //      # normal would have been (0, 0, 1), but is inverted!
+    //    And comps.normalv = vector(0, 0, -1)
+    #[test]
+    fn hit_when_intersection_occurs_on_inside() {
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(1.0, &shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+
+        assert_eq!(comps.point, Point::new(0.0, 0.0, 1.0));
+        assert_eq!(comps.eyev, Vector::new(0.0, 0.0, -1.0));
+        assert!(comps.inside);
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    // Scenario: The hit should offset the point
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And shape ← sphere() with:
+    //      | transform | translation(0, 0, 1) |
+    //    And i ← intersection(5, shape)
+    //  When comps ← prepare_computations(i, r)
+    //  Then comps.over_point.z < -EPSILON/2
+    //    And comps.point.z > comps.over_point.z
+    #[test]
+    fn hit_should_offset_the_point() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut shape = Sphere::new();
+        shape.set_transform(Matrix4::translation(0.0, 0.0, 1.0));
+        let i = Intersection::new(5.0, &shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+
+        assert!(comps.over_point.z < -EPSILON / 2.0);
+        assert!(comps.point.z > comps.over_point.z);
+    }
+
+    #[test]
+    fn empty_intersections_have_no_hit() {
+        let xs = Intersections::default();
+
+        assert!(xs.is_empty());
+        assert_eq!(xs.hit(), None);
+    }
+
+    // Scenario: Precomputing the reflection vector
+    //  Given shape ← plane()
+    //    And r ← ray(point(0, 1, -1), vector(0, -√2/2, √2/2))
+    //    And i ← intersection(√2, shape)
+    //  When comps ← prepare_computations(i, r)
+    //  Then comps.reflectv = vector(0, √2/2, √2/2)
+    #[test]
+    fn precomputing_reflection_vector() {
+        let shape = Plane::new();
+        let r = Ray::new(
+            Point::new(0.0, 1.0, -1.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let i = Intersection::new(SQRT_2, &shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+
+        assert_eq!(
+            comps.reflectv,
+            Vector::new(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2)
+        );
+    }
+
+    // Scenario Outline: Finding n1 and n2 at various intersections
+    //  Given A ← glass_sphere() with:
+    //      | transform                 | scaling(2, 2, 2) |
+    //      | material.refractive_index | 1.5              |
+    //    And B ← glass_sphere() with:
+    //      | transform                 | translation(0, 0, -0.25) |
+    //      | material.refractive_index | 2.0                      |
+    //    And C ← glass_sphere() with:
+    //      | transform                 | translation(0, 0, 0.25) |
+    //      | material.refractive_index | 2.5                     |
+    //    And r ← ray(point(0, 0, -4), vector(0, 0, 1))
+    //    And xs ← intersections(2:A, 2.75:B, 3.25:C, 4.75:B, 5.25:C, 6:A)
+    //  When comps ← prepare_computations(xs[<index>], r, xs)
+    //  Then comps.n1 = <n1>
+    //    And comps.n2 = <n2>
+    //
+    //  Examples:
+    //    | index | n1  | n2  |
+    //    | 0     | 1.0 | 1.5 |
+    //    | 1     | 1.5 | 2.0 |
+    //    | 2     | 2.0 | 2.5 |
+    //    | 3     | 2.5 | 2.5 |
+    //    | 4     | 2.5 | 1.5 |
+    //    | 5     | 1.5 | 1.0 |
+    #[test]
+    fn finding_n1_and_n2_at_various_intersections() {
+        let glass = |transform, refractive_index| {
+            let mut s = Sphere::glass().with_transform(transform);
+            s.material_mut().refractive_index = refractive_index;
+            s
+        };
+        let a = glass(Matrix4::scaling(2.0, 2.0, 2.0), 1.5);
+        let b = glass(Matrix4::translation(0.0, 0.0, -0.25), 2.0);
+        let c = glass(Matrix4::translation(0.0, 0.0, 0.25), 2.5);
+        let r = Ray::new(Point::new(0.0, 0.0, -4.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(2.0, &a),
+            Intersection::new(2.75, &b),
+            Intersection::new(3.25, &c),
+            Intersection::new(4.75, &b),
+            Intersection::new(5.25, &c),
+            Intersection::new(6.0, &a),
+        ]);
+        let expected = [
+            (1.0, 1.5),
+            (1.5, 2.0),
+            (2.0, 2.5),
+            (2.5, 2.5),
+            (2.5, 1.5),
+            (1.5, 1.0),
+        ];
+
+        for (index, &(n1, n2)) in expected.iter().enumerate() {
+            let comps = xs[index].prepare_computations(&r, &xs);
+
+            assert_eq!(comps.n1, n1);
+            assert_eq!(comps.n2, n2);
+        }
+    }
+
+    // Scenario: The under point is offset below the surface
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And shape ← glass_sphere() with:
+    //      | transform | translation(0, 0, 1) |
+    //    And i ← intersection(5, shape)
+    //    And xs ← intersections(i)
+    //  When comps ← prepare_computations(i, r, xs)
+    //  Then comps.under_point.z > EPSILON/2
+    //    And comps.point.z < comps.under_point.z
+    #[test]
+    fn under_point_is_offset_below_surface() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::glass().with_transform(Matrix4::translation(0.0, 0.0, 1.0));
+        let i = Intersection::new(5.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert!(comps.under_point.z > EPSILON / 2.0);
+        assert!(comps.point.z < comps.under_point.z);
+    }
+
+    // Scenario: The Schlick approximation under total internal reflection
+    //  Given shape ← glass_sphere()
+    //    And r ← ray(point(0, 0, √2/2), vector(0, 1, 0))
+    //    And xs ← intersections(-√2/2:shape, √2/2:shape)
+    //  When comps ← prepare_computations(xs[1], r, xs)
+    //    And reflectance ← schlick(comps)
+    //  Then reflectance = 1.0
+    #[test]
+    fn schlick_approximation_under_total_internal_reflection() {
+        let shape = Sphere::glass();
+        let r = Ray::new(
+            Point::new(0.0, 0.0, FRAC_1_SQRT_2),
+            Vector::new(0.0, 1.0, 0.0),
+        );
+        let xs = Intersections::new(vec![
+            Intersection::new(-FRAC_1_SQRT_2, &shape),
+            Intersection::new(FRAC_1_SQRT_2, &shape),
+        ]);
+
+        let comps = xs[1].prepare_computations(&r, &xs);
+
+        assert_eq!(comps.schlick(), 1.0);
+    }
+
+    // Scenario: The Schlick approximation with a perpendicular viewing angle
+    //  Given shape ← glass_sphere()
+    //    And r ← ray(point(0, 0, 0), vector(0, 1, 0))
+    //    And xs ← intersections(-1:shape, 1:shape)
+    //  When comps ← prepare_computations(xs[1], r, xs)
+    //    And reflectance ← schlick(comps)
+    //  Then reflectance = 0.04
+    #[test]
+    fn schlick_approximation_with_perpendicular_viewing_angle() {
+        let shape = Sphere::glass();
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(-1.0, &shape),
+            Intersection::new(1.0, &shape),
+        ]);
+
+        let comps = xs[1].prepare_computations(&r, &xs);
+
+        assert!(is_approx(comps.schlick(), 0.04, None));
+    }
+
+    // Scenario: The Schlick approximation with small angle and n2 > n1
+    //  Given shape ← glass_sphere()
+    //    And r ← ray(point(0, 0.99, -2), vector(0, 0, 1))
+    //    And xs ← intersections(1.8589:shape)
+    //  When comps ← prepare_computations(xs[0], r, xs)
+    //    And reflectance ← schlick(comps)
+    //  Then reflectance = 0.48873
+    #[test]
+    fn schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+        let shape = Sphere::glass();
+        let r = Ray::new(Point::new(0.0, 0.99, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![Intersection::new(1.8589, &shape)]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        assert!(is_approx(comps.schlick(), 0.48873, None));
+    }
+}
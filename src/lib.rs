@@ -1,7 +1,87 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// Tests run on the standard library anyway, and the test shapes lock through it.
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+pub mod animation;
+pub mod background;
+pub mod bounds;
+pub mod camera;
+pub mod canvas;
+pub mod color;
+pub mod cone;
+pub mod csg;
+pub mod cube;
+pub mod cylinder;
+pub mod encoder;
+pub mod error;
+pub mod fog;
+pub mod group;
+pub mod intersection;
+pub mod light;
+pub mod material;
 pub mod math;
+pub mod matrix;
+pub mod obj;
+pub mod pattern;
+pub mod perturb;
+pub mod plane;
+#[cfg(feature = "png")]
+pub mod png;
+pub mod point;
+pub mod ray;
+pub mod scene;
+pub mod shape;
+#[cfg(all(feature = "simd", target_arch = "x86_64", not(feature = "f64")))]
+mod simd;
+pub mod smooth_triangle;
+pub mod sphere;
+pub mod texture;
+pub mod transform;
+pub mod triangle;
 pub mod tuple;
+pub mod vector;
+pub mod world;
+mod yaml;
 
-mod prelude {
+pub mod prelude {
+    pub use crate::animation::*;
+    pub use crate::background::*;
+    pub use crate::bounds::*;
+    pub use crate::camera::*;
+    pub use crate::canvas::*;
+    pub use crate::color::*;
+    pub use crate::cone::*;
+    pub use crate::csg::*;
+    pub use crate::cube::*;
+    pub use crate::cylinder::*;
+    pub use crate::encoder::*;
+    pub use crate::error::*;
+    pub use crate::fog::*;
+    pub use crate::group::*;
+    pub use crate::intersection::*;
+    pub use crate::light::*;
+    pub use crate::material::*;
     pub use crate::math::*;
+    pub use crate::matrix::*;
+    pub use crate::obj::*;
+    pub use crate::pattern::*;
+    pub use crate::perturb::*;
+    pub use crate::plane::*;
+    #[cfg(feature = "png")]
+    pub use crate::png::*;
+    pub use crate::point::*;
+    pub use crate::ray::*;
+    pub use crate::scene::*;
+    pub use crate::shape::*;
+    pub use crate::smooth_triangle::*;
+    pub use crate::sphere::*;
+    pub use crate::texture::*;
+    pub use crate::transform::*;
+    pub use crate::triangle::*;
     pub use crate::tuple::*;
+    pub use crate::vector::*;
+    pub use crate::world::*;
 }
@@ -0,0 +1,551 @@
+use crate::prelude::*;
+use core::any::Any;
+use core::fmt;
+
+/// A source of light illuminating the objects of a world.
+pub trait Light: Any + Send + Sync + fmt::Debug {
+    /// Where the light comes from, which is also where shadow rays are cast to.
+    fn position(&self) -> Point;
+
+    /// Color and brightness of the light at its source.
+    fn intensity(&self) -> Color;
+
+    /// Color and brightness of the light reaching `point`, once dimmed by distance or
+    /// by the shape of its beam.
+    fn intensity_at(&self, _point: Point) -> Color {
+        self.intensity()
+    }
+}
+
+impl dyn Light {
+    pub fn downcast_ref<L: Light>(&self) -> Option<&L> {
+        (self as &dyn Any).downcast_ref()
+    }
+}
+
+/// How a light fades with the distance `d` to its source, dividing its intensity by
+/// `constant + linear * d + quadratic * d²`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Attenuation {
+    pub constant: Float,
+    pub linear: Float,
+    pub quadratic: Float,
+}
+
+impl Attenuation {
+    /// No falloff at all, the book's behavior.
+    pub const NONE: Self = Self::new(1.0, 0.0, 0.0);
+
+    pub const fn new(constant: Float, linear: Float, quadratic: Float) -> Self {
+        Self {
+            constant,
+            linear,
+            quadratic,
+        }
+    }
+
+    /// Fraction of the intensity left at `distance` from the source.
+    pub fn factor(&self, distance: Float) -> Float {
+        1.0 / (self.constant + distance * (self.linear + distance * self.quadratic))
+    }
+}
+
+impl Default for Attenuation {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// A light source with no size, shining equally in every direction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+}
+
+impl PointLight {
+    pub const fn new(position: Point, intensity: Color) -> Self {
+        Self {
+            position,
+            intensity,
+            attenuation: Attenuation::NONE,
+        }
+    }
+
+    pub fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+}
+
+impl Light for PointLight {
+    fn position(&self) -> Point {
+        self.position
+    }
+
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn intensity_at(&self, point: Point) -> Color {
+        self.intensity * self.attenuation.factor((point - self.position).magnitude())
+    }
+}
+
+/// A light source with no size, shining a cone of light along `direction`.
+///
+/// Points within `inner_angle` of the direction get the full intensity, points beyond
+/// `outer_angle` none, and the light fades smoothly in between. Both angles are
+/// measured from the axis of the cone, in radians.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpotLight {
+    pub position: Point,
+    /// Unit vector along the axis of the cone.
+    pub direction: Vector,
+    pub inner_angle: Float,
+    pub outer_angle: Float,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+}
+
+impl SpotLight {
+    /// # Panics
+    ///
+    /// Panics if `direction` is a zero vector, `inner_angle` is not positive or
+    /// `outer_angle` is smaller than it.
+    pub fn new(
+        position: Point,
+        direction: Vector,
+        inner_angle: Float,
+        outer_angle: Float,
+        intensity: Color,
+    ) -> Self {
+        Self::try_new(position, direction, inner_angle, outer_angle, intensity)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Spotlight like [`SpotLight::new`], failing instead of panicking on a bad
+    /// `direction` or cone.
+    pub fn try_new(
+        position: Point,
+        direction: Vector,
+        inner_angle: Float,
+        outer_angle: Float,
+        intensity: Color,
+    ) -> Result<Self> {
+        if inner_angle <= 0.0 {
+            return Err(RustracerError::InvalidArgument(
+                "the inner angle of a spotlight must be positive",
+            ));
+        }
+        if outer_angle < inner_angle {
+            return Err(RustracerError::InvalidArgument(
+                "the outer angle of a spotlight cannot be smaller than its inner angle",
+            ));
+        }
+        Ok(Self {
+            position,
+            direction: direction.try_normalize()?,
+            inner_angle,
+            outer_angle,
+            intensity,
+            attenuation: Attenuation::NONE,
+        })
+    }
+
+    pub fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    /// Fraction of the intensity shone toward `point` by the cone, ignoring distance.
+    pub fn cone_factor(&self, point: Point) -> Float {
+        let cos_angle = match (point - self.position).try_normalize() {
+            Ok(to_point) => to_point.dot(&self.direction),
+            // The source itself is fully lit.
+            Err(_) => return 1.0,
+        };
+        let cos_inner = self.inner_angle.cos();
+        let cos_outer = self.outer_angle.cos();
+        if cos_angle >= cos_inner {
+            1.0
+        } else if cos_angle <= cos_outer {
+            0.0
+        } else {
+            // Smoothstep, so the edge of the beam has no visible crease.
+            let t = (cos_angle - cos_outer) / (cos_inner - cos_outer);
+            t * t * (3.0 - 2.0 * t)
+        }
+    }
+}
+
+impl Light for SpotLight {
+    fn position(&self) -> Point {
+        self.position
+    }
+
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn intensity_at(&self, point: Point) -> Color {
+        let distance = (point - self.position).magnitude();
+        self.intensity * (self.cone_factor(point) * self.attenuation.factor(distance))
+    }
+}
+
+/// Color of a point on a surface lit by `light` as seen from `eyev`, using the Phong model.
+///
+/// The surface color comes from the material's pattern, if any, evaluated on `object`.
+/// Only the ambient term contributes when the point is `in_shadow`.
+///
+/// The ambient term stands for light bounced around the scene, so it takes the full
+/// [`Light::intensity`], not [`Light::intensity_at`]: neither attenuation nor the cone of
+/// a [`SpotLight`] dims it.
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &dyn Light,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    in_shadow: bool,
+) -> Color {
+    let color = match &material.pattern {
+        Some(pattern) => pattern.pattern_at_shape(object, point),
+        None => material.color,
+    };
+    let ambient = color * light.intensity() * material.ambient;
+    if in_shadow {
+        return ambient;
+    }
+
+    let intensity = light.intensity_at(point);
+    let effective_color = color * intensity;
+    let lightv = (light.position() - point).normalize();
+    // A negative cosine means the light is on the other side of the surface.
+    let light_dot_normal = lightv.dot(&normalv);
+    if light_dot_normal < 0.0 {
+        return ambient;
+    }
+
+    let diffuse = effective_color * material.diffuse * light_dot_normal;
+    // A negative cosine means the light reflects away from the eye.
+    let reflectv = (-lightv).reflect(&normalv);
+    let reflect_dot_eye = reflectv.dot(&eyev);
+    let specular = if reflect_dot_eye <= 0.0 {
+        Color::black()
+    } else {
+        intensity * material.specular * reflect_dot_eye.powf(material.shininess)
+    };
+
+    ambient + diffuse + specular
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_PI_4, FRAC_PI_6};
+    use crate::prelude::*;
+    use alloc::sync::Arc;
+
+    // Scenario: A point light has a position and intensity
+    //  Given intensity ← color(1, 1, 1)
+    //    And position ← point(0, 0, 0)
+    //  When light ← point_light(position, intensity)
+    //  Then light.position = position
+    //    And light.intensity = intensity
+    #[test]
+    fn point_light_has_position_and_intensity() {
+        let intensity = Color::white();
+        let position = Point::origin();
+        let light = PointLight::new(position, intensity);
+
+        assert_eq!(light.position, position);
+        assert_eq!(light.intensity, intensity);
+    }
+
+    // Background:
+    //  Given m ← material()
+    //    And position ← point(0, 0, 0)
+    fn background() -> (Material, Point) {
+        (Material::default(), Point::origin())
+    }
+
+    // Scenario: Lighting with the eye between the light and the surface
+    //  Given eyev ← vector(0, 0, -1)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv)
+    //  Then result = color(1.9, 1.9, 1.9)
+    #[test]
+    fn lighting_with_eye_between_light_and_surface() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(1.9, 1.9, 1.9));
+    }
+
+    // Scenario: Lighting with the eye between light and surface, eye offset 45°
+    //  Given eyev ← vector(0, √2/2, -√2/2)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv)
+    //  Then result = color(1.0, 1.0, 1.0)
+    #[test]
+    fn lighting_with_eye_offset_45_degrees() {
+        let (m, position) = background();
+        let k = Float::sqrt(2.0) / 2.0;
+        let eyev = Vector::new(0.0, k, -k);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(1.0, 1.0, 1.0));
+    }
+
+    // Scenario: Lighting with eye opposite surface, light offset 45°
+    //  Given eyev ← vector(0, 0, -1)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 10, -10), color(1, 1, 1))
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv)
+    //  Then result = color(0.7364, 0.7364, 0.7364)
+    #[test]
+    fn lighting_with_light_offset_45_degrees() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364));
+    }
+
+    // Scenario: Lighting with eye in the path of the reflection vector
+    //  Given eyev ← vector(0, -√2/2, -√2/2)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 10, -10), color(1, 1, 1))
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv)
+    //  Then result = color(1.6364, 1.6364, 1.6364)
+    #[test]
+    fn lighting_with_eye_in_path_of_reflection_vector() {
+        let (m, position) = background();
+        let k = Float::sqrt(2.0) / 2.0;
+        let eyev = Vector::new(0.0, -k, -k);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(1.6364, 1.6364, 1.6364));
+    }
+
+    // Scenario: Lighting with the light behind the surface
+    //  Given eyev ← vector(0, 0, -1)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 0, 10), color(1, 1, 1))
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv)
+    //  Then result = color(0.1, 0.1, 0.1)
+    #[test]
+    fn lighting_with_light_behind_surface() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, 10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+    }
+
+    // Scenario: Lighting with the surface in shadow
+    //  Given eyev ← vector(0, 0, -1)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    //    And in_shadow ← true
+    //  When result ← lighting(m, sphere(), light, position, eyev, normalv, in_shadow)
+    //  Then result = color(0.1, 0.1, 0.1)
+    #[test]
+    fn lighting_with_surface_in_shadow() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white());
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, true);
+
+        assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+    }
+
+    // Scenario: Lighting with a pattern applied
+    //  Given m.pattern ← stripe_pattern(color(1, 1, 1), color(0, 0, 0))
+    //    And m.ambient ← 1
+    //    And m.diffuse ← 0
+    //    And m.specular ← 0
+    //    And eyev ← vector(0, 0, -1)
+    //    And normalv ← vector(0, 0, -1)
+    //    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    //  When c1 ← lighting(m, sphere(), light, point(0.9, 0, 0), eyev, normalv, false)
+    //    And c2 ← lighting(m, sphere(), light, point(1.1, 0, 0), eyev, normalv, false)
+    //  Then c1 = color(1, 1, 1)
+    //    And c2 = color(0, 0, 0)
+    #[test]
+    fn lighting_with_pattern_applied() {
+        let m = Material {
+            pattern: Some(Arc::new(StripePattern::new(Color::white(), Color::black()))),
+            ambient: 1.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            ..Material::default()
+        };
+        let object = Sphere::new();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white());
+
+        let c1 = lighting(
+            &m,
+            &object,
+            &light,
+            Point::new(0.9, 0.0, 0.0),
+            eyev,
+            normalv,
+            false,
+        );
+        let c2 = lighting(
+            &m,
+            &object,
+            &light,
+            Point::new(1.1, 0.0, 0.0),
+            eyev,
+            normalv,
+            false,
+        );
+
+        assert_eq!(c1, Color::white());
+        assert_eq!(c2, Color::black());
+    }
+
+    #[test]
+    fn attenuation_divides_intensity_by_polynomial_of_distance() {
+        let attenuation = Attenuation::new(1.0, 0.5, 0.25);
+
+        assert_eq!(Attenuation::NONE.factor(100.0), 1.0);
+        assert_eq!(attenuation.factor(0.0), 1.0);
+        assert_eq!(attenuation.factor(2.0), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn point_light_fades_with_distance() {
+        let light = PointLight::new(Point::origin(), Color::white())
+            .with_attenuation(Attenuation::new(0.0, 0.0, 1.0));
+
+        assert_eq!(light.intensity(), Color::white());
+        assert_eq!(
+            light.intensity_at(Point::new(0.0, 2.0, 0.0)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+
+    #[test]
+    fn trying_spotlight_with_bad_cone_fails() {
+        let spotlight = |direction, inner, outer| {
+            SpotLight::try_new(Point::origin(), direction, inner, outer, Color::white())
+        };
+        let down = Vector::new(0.0, -1.0, 0.0);
+
+        assert!(spotlight(down, FRAC_PI_4, FRAC_PI_6).is_err());
+        assert!(spotlight(down, 0.0, FRAC_PI_6).is_err());
+        assert!(matches!(
+            spotlight(Vector::new(0.0, 0.0, 0.0), FRAC_PI_6, FRAC_PI_4),
+            Err(RustracerError::NormalizeZeroVector)
+        ));
+        assert!(spotlight(down, FRAC_PI_6, FRAC_PI_6).is_ok());
+    }
+
+    #[test]
+    fn spotlight_shines_within_its_cone() {
+        let light = SpotLight::new(
+            Point::new(0.0, 10.0, 0.0),
+            Vector::new(0.0, -2.0, 0.0),
+            FRAC_PI_6,
+            FRAC_PI_4,
+            Color::white(),
+        );
+
+        assert_eq!(light.direction, Vector::new(0.0, -1.0, 0.0));
+        assert_eq!(light.intensity_at(Point::origin()), Color::white());
+        assert_eq!(
+            light.intensity_at(Point::new(5.0, 0.0, 0.0)),
+            Color::white()
+        );
+        assert_eq!(
+            light.intensity_at(Point::new(15.0, 0.0, 0.0)),
+            Color::black()
+        );
+        assert_eq!(
+            light.intensity_at(Point::new(0.0, 20.0, 0.0)),
+            Color::black()
+        );
+    }
+
+    #[test]
+    fn spotlight_fades_between_inner_and_outer_angles() {
+        let light = SpotLight::new(
+            Point::origin(),
+            Vector::new(0.0, 0.0, 1.0),
+            FRAC_PI_6,
+            FRAC_PI_4,
+            Color::white(),
+        );
+        let inner = FRAC_PI_6.tan();
+        let outer = FRAC_PI_4.tan();
+        let factor = |x: Float| light.cone_factor(Point::new(x, 0.0, 1.0));
+
+        assert_eq!(factor(inner), 1.0);
+        assert_eq!(factor(outer), 0.0);
+        let (a, b) = (factor(0.6), factor(0.8));
+        assert!(0.0 < b && b < a && a < 1.0);
+    }
+
+    #[test]
+    fn lighting_outside_spotlight_cone_leaves_ambient() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = SpotLight::new(
+            Point::new(0.0, 0.0, -10.0),
+            Vector::new(0.0, 1.0, 0.0),
+            FRAC_PI_6,
+            FRAC_PI_4,
+            Color::white(),
+        );
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+    }
+
+    #[test]
+    fn lighting_with_attenuated_light() {
+        let (m, position) = background();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white())
+            .with_attenuation(Attenuation::new(0.0, 0.5, 0.0));
+
+        let result = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, false);
+
+        // Diffuse and specular are divided by 5, ambient is unchanged.
+        assert_eq!(
+            result,
+            Color::new(0.1 + 1.8 / 5.0, 0.1 + 1.8 / 5.0, 0.1 + 1.8 / 5.0)
+        );
+    }
+}
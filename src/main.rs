@@ -0,0 +1,294 @@
+use rustracer::prelude::{Float, RustracerError, Scene};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage: rustracer [OPTIONS] <SCENE>
+
+Renders a YAML scene file to a PPM or PNG image, chosen by the output extension.
+
+Options:
+  -o, --output <PATH>          Image to write [default: the scene name, as PNG]
+  -r, --resolution <WxH>       Overrides the size of the scene's camera
+  -s, --samples <N>            Rays per pixel along each axis, N x N in total [default: 1]
+  -c, --clamp <MAX>            Dims samples brighter than MAX, against stray white pixels
+  -j, --threads <N>            Render threads, 0 for one per core [default: 0]
+  -q, --quiet                  Hides the progress indicator
+  -h, --help                   Prints this message";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    scene: PathBuf,
+    output: PathBuf,
+    resolution: Option<(usize, usize)>,
+    samples: usize,
+    clamp: Option<Float>,
+    threads: usize,
+    quiet: bool,
+}
+
+impl Options {
+    /// Reads the options from the command-line arguments, without the program name.
+    ///
+    /// Returns `Ok(None)` when help was asked for.
+    fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Self>, String> {
+        let mut args = args.into_iter();
+        let mut scene = None;
+        let mut output = None;
+        let mut resolution = None;
+        let mut samples = 1;
+        let mut clamp = None;
+        let mut threads = 0;
+        let mut quiet = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("`{}` needs a value", name))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-o" | "--output" => output = Some(PathBuf::from(value(&arg)?)),
+                "-r" | "--resolution" => resolution = Some(parse_resolution(&value(&arg)?)?),
+                "-s" | "--samples" => samples = parse_samples(&arg, &value(&arg)?)?,
+                "-c" | "--clamp" => clamp = Some(parse_brightness(&arg, &value(&arg)?)?),
+                "-j" | "--threads" => threads = parse_count(&arg, &value(&arg)?)?,
+                "-q" | "--quiet" => quiet = true,
+                flag if flag.starts_with('-') => return Err(format!("unknown option `{}`", flag)),
+                _ if scene.is_some() => return Err(format!("unexpected argument `{}`", arg)),
+                _ => scene = Some(PathBuf::from(arg)),
+            }
+        }
+
+        let scene = scene.ok_or_else(|| "no scene file given".to_string())?;
+        let output = output.unwrap_or_else(|| scene.with_extension(default_extension()));
+        output_format(&output)?;
+
+        Ok(Some(Self {
+            scene,
+            output,
+            resolution,
+            samples,
+            clamp,
+            threads,
+            quiet,
+        }))
+    }
+}
+
+fn parse_count(name: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("`{}` expects a whole number, not `{}`", name, value))
+}
+
+fn parse_samples(name: &str, value: &str) -> Result<usize, String> {
+    match parse_count(name, value)? {
+        0 => Err(format!("`{}` must be at least 1", name)),
+        samples => Ok(samples),
+    }
+}
+
+fn parse_brightness(name: &str, value: &str) -> Result<Float, String> {
+    match value.parse() {
+        Ok(max) if max > 0.0 => Ok(max),
+        _ => Err(format!(
+            "`{}` expects a positive number, not `{}`",
+            name, value
+        )),
+    }
+}
+
+fn parse_resolution(value: &str) -> Result<(usize, usize), String> {
+    let size =
+        value
+            .split_once('x')
+            .and_then(|(width, height)| match (width.parse(), height.parse()) {
+                (Ok(width), Ok(height)) if width > 0 && height > 0 => Some((width, height)),
+                _ => None,
+            });
+    size.ok_or_else(|| format!("resolution must look like `640x480`, not `{}`", value))
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Format {
+    Ppm,
+    #[cfg(feature = "png")]
+    Png,
+}
+
+fn default_extension() -> &'static str {
+    if cfg!(feature = "png") {
+        "png"
+    } else {
+        "ppm"
+    }
+}
+
+fn output_format(path: &Path) -> Result<Format, String> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    match extension.to_ascii_lowercase().as_str() {
+        "ppm" => Ok(Format::Ppm),
+        #[cfg(feature = "png")]
+        "png" => Ok(Format::Png),
+        _ => Err(format!(
+            "cannot write `{}`: the output must be a .ppm or .{} file",
+            path.display(),
+            default_extension()
+        )),
+    }
+}
+
+fn render(options: &Options) -> Result<(), RustracerError> {
+    let Scene { world, mut camera } = Scene::load(&options.scene)?;
+    if let Some((width, height)) = options.resolution {
+        camera.set_size(width, height);
+    }
+    camera.set_samples_per_pixel(options.samples);
+    if let Some(max) = options.clamp {
+        camera.set_max_sample_brightness(max);
+    }
+
+    let start = Instant::now();
+    let mut rows_done = 0;
+    let mut last_percent = None;
+    let image = camera.render_parallel_progressive(&world, options.threads, |_, _| {
+        rows_done += 1;
+        let percent = rows_done * 100 / camera.vsize();
+        if !options.quiet && last_percent != Some(percent) {
+            last_percent = Some(percent);
+            eprint!(
+                "\rRendering {}x{}: {:3}%",
+                camera.hsize(),
+                camera.vsize(),
+                percent
+            );
+            // Progress is best-effort; a closed stderr should not stop the render.
+            let _ = io::stderr().flush();
+        }
+    });
+    if !options.quiet {
+        eprintln!(" in {:.1}s", start.elapsed().as_secs_f32());
+    }
+
+    // The format was checked along with the options.
+    match output_format(&options.output).unwrap_or(Format::Ppm) {
+        Format::Ppm => fs::write(&options.output, image.to_ppm())?,
+        #[cfg(feature = "png")]
+        Format::Png => image.save_png(&options.output)?,
+    }
+    Ok(())
+}
+
+fn main() {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(reason) => {
+            eprintln!("rustracer: {}\n\n{}", reason, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = render(&options) {
+        eprintln!("rustracer: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parsing_default_options() {
+        assert_eq!(
+            parse(&["scenes/cover.yml"]),
+            Ok(Some(Options {
+                scene: PathBuf::from("scenes/cover.yml"),
+                output: PathBuf::from("scenes/cover.png"),
+                resolution: None,
+                samples: 1,
+                clamp: None,
+                threads: 0,
+                quiet: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parsing_all_options() {
+        assert_eq!(
+            parse(&[
+                "-o",
+                "out.ppm",
+                "--resolution",
+                "640x480",
+                "-s",
+                "3",
+                "--clamp",
+                "4.5",
+                "--threads",
+                "4",
+                "-q",
+                "cover.yml"
+            ]),
+            Ok(Some(Options {
+                scene: PathBuf::from("cover.yml"),
+                output: PathBuf::from("out.ppm"),
+                resolution: Some((640, 480)),
+                samples: 3,
+                clamp: Some(4.5),
+                threads: 4,
+                quiet: true,
+            }))
+        );
+        assert_eq!(parse(&["cover.yml", "--help"]), Ok(None));
+    }
+
+    #[test]
+    fn rejecting_invalid_options() {
+        assert_eq!(parse(&[]), Err("no scene file given".to_string()));
+        assert_eq!(
+            parse(&["a.yml", "b.yml"]),
+            Err("unexpected argument `b.yml`".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "--fast"]),
+            Err("unknown option `--fast`".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "-s"]),
+            Err("`-s` needs a value".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "-s", "0"]),
+            Err("`-s` must be at least 1".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "--samples", "0"]),
+            Err("`--samples` must be at least 1".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "-c", "0"]),
+            Err("`-c` expects a positive number, not `0`".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "-r", "640"]),
+            Err("resolution must look like `640x480`, not `640`".to_string())
+        );
+        assert_eq!(
+            parse(&["a.yml", "-o", "a.jpg"]),
+            Err("cannot write `a.jpg`: the output must be a .ppm or .png file".to_string())
+        );
+    }
+}
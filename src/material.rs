@@ -0,0 +1,144 @@
+use crate::prelude::*;
+use alloc::sync::Arc;
+
+/// Surface attributes used by the Phong reflection model.
+#[derive(Debug, Clone)]
+pub struct Material {
+    pub color: Color,
+    /// Varies the surface color across the object, replacing `color` when set.
+    pub pattern: Option<Arc<dyn Pattern>>,
+    /// Tilts the shading normals to fake bumps on the surface, when set.
+    pub normal_perturb: Option<Arc<dyn NormalPerturb>>,
+    pub ambient: Float,
+    pub diffuse: Float,
+    pub specular: Float,
+    pub shininess: Float,
+    /// Fraction of light mirrored by the surface, from 0 (matte) to 1 (perfect mirror).
+    pub reflective: Float,
+    /// Fraction of light let through the surface, from 0 (opaque) to 1 (fully clear).
+    pub transparency: Float,
+    /// How much light bends when entering the material, 1 being vacuum.
+    pub refractive_index: Float,
+    /// Absorption coefficient of each color per unit of distance traveled inside the object,
+    /// so that thick glass gets darker than thin glass: light going `d` through it keeps
+    /// `e^(-absorption * d)` of each color. Black absorbs nothing.
+    pub absorption: Color,
+}
+
+impl Material {
+    pub const fn new() -> Self {
+        Self {
+            color: Color::white(),
+            pattern: None,
+            normal_perturb: None,
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.0,
+            reflective: 0.0,
+            transparency: 0.0,
+            refractive_index: 1.0,
+            absorption: Color::black(),
+        }
+    }
+
+    /// Fraction of each color still left after traveling `distance` inside the object,
+    /// following the Beer–Lambert law.
+    pub fn transmittance(&self, distance: Float) -> Color {
+        Color::new(
+            (-self.absorption.r * distance).exp(),
+            (-self.absorption.g * distance).exp(),
+            (-self.absorption.b * distance).exp(),
+        )
+    }
+}
+
+/// Materials only share a pattern or perturbation when they point to the very same one.
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        let same_perturb = match (&self.normal_perturb, &other.normal_perturb) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_pattern
+            && same_perturb
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+            && self.absorption == other.absorption
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: The default material
+    //  Given m ← material()
+    //  Then m.color = color(1, 1, 1)
+    //    And m.ambient = 0.1
+    //    And m.diffuse = 0.9
+    //    And m.specular = 0.9
+    //    And m.shininess = 200.0
+    #[test]
+    fn default_material() {
+        let m = Material::default();
+
+        assert_eq!(m.color, Color::white());
+        assert_eq!(m.ambient, 0.1);
+        assert_eq!(m.diffuse, 0.9);
+        assert_eq!(m.specular, 0.9);
+        assert_eq!(m.shininess, 200.0);
+    }
+
+    // Scenario: Reflectivity for the default material
+    //  Given m ← material()
+    //  Then m.reflective = 0.0
+    #[test]
+    fn reflectivity_for_default_material() {
+        let m = Material::default();
+
+        assert_eq!(m.reflective, 0.0);
+    }
+
+    // Scenario: Transparency and Refractive Index for the default material
+    //  Given m ← material()
+    //  Then m.transparency = 0.0
+    //    And m.refractive_index = 1.0
+    #[test]
+    fn transparency_and_refractive_index_for_default_material() {
+        let m = Material::default();
+
+        assert_eq!(m.transparency, 0.0);
+        assert_eq!(m.refractive_index, 1.0);
+    }
+
+    #[test]
+    fn absorption_dims_light_with_distance() {
+        let mut m = Material::default();
+        assert_eq!(m.transmittance(10.0), Color::white());
+
+        m.absorption = Color::new(0.5, 0.0, 2.0);
+        assert_eq!(m.transmittance(0.0), Color::white());
+        assert_eq!(m.transmittance(1.0), Color::new(0.60653, 1.0, 0.13534));
+        assert_eq!(m.transmittance(2.0), Color::new(0.36788, 1.0, 0.01832));
+    }
+}
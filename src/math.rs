@@ -1,14 +1,276 @@
-pub const EPSILON: f32 = 0.0001;
+/// The scalar used for every coordinate, color and distance: `f32`, or `f64` with the
+/// `f64` feature.
+#[cfg(not(feature = "f64"))]
+pub type Float = f32;
+/// The scalar used for every coordinate, color and distance: `f32`, or `f64` with the
+/// `f64` feature.
+#[cfg(feature = "f64")]
+pub type Float = f64;
 
-pub fn is_approx(a: f32, b: f32, esp: Option<f32>) -> bool {
-    (a - b).abs() <= esp.unwrap_or(EPSILON)
+/// Mathematical constants, such as [`consts::PI`], at the precision of [`Float`].
+#[cfg(not(feature = "f64"))]
+pub use core::f32::consts;
+/// Mathematical constants, such as [`consts::PI`], at the precision of [`Float`].
+#[cfg(feature = "f64")]
+pub use core::f64::consts;
+
+pub const EPSILON: Float = 0.0001;
+
+/// Whether `a` and `b` differ by at most `esp`, or [`EPSILON`] if it is `None`.
+///
+/// Infinities are only equal to themselves.
+pub fn is_approx(a: Float, b: Float, esp: Option<Float>) -> bool {
+    a == b || (a - b).abs() <= esp.unwrap_or(EPSILON)
+}
+
+/// Floating point functions that `core` does not provide without `std`.
+///
+/// With the `std` feature enabled the inherent float methods are used instead,
+/// so the same method-call syntax compiles in both configurations. The software
+/// versions are only about as accurate as `f32`, even with the `f64` feature.
+#[cfg(not(feature = "std"))]
+pub trait FloatMath {
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn floor(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn acos(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatMath for Float {
+    fn sqrt(self) -> Self {
+        soft::sqrt(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        soft::powi(self, n)
+    }
+
+    fn sin(self) -> Self {
+        soft::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        soft::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        soft::sin(self) / soft::cos(self)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        soft::powf(self, n)
+    }
+
+    fn exp(self) -> Self {
+        soft::exp(self)
+    }
+
+    fn floor(self) -> Self {
+        soft::floor(self)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        soft::atan2(self, x)
+    }
+
+    fn acos(self) -> Self {
+        soft::atan2(soft::sqrt(1.0 - self * self), self)
+    }
+}
+
+#[cfg(any(test, not(feature = "std")))]
+mod soft {
+    use super::Float;
+    use crate::math::consts::{FRAC_PI_2, FRAC_PI_6, LN_2, PI};
+
+    /// Bits of the exponent and the mantissa of a [`Float`].
+    #[cfg(not(feature = "f64"))]
+    const EXPONENT_BITS: u32 = 8;
+    #[cfg(not(feature = "f64"))]
+    const MANTISSA_BITS: u32 = 23;
+    #[cfg(feature = "f64")]
+    const EXPONENT_BITS: u32 = 11;
+    #[cfg(feature = "f64")]
+    const MANTISSA_BITS: u32 = 52;
+
+    const EXPONENT_BIAS: i32 = (1 << (EXPONENT_BITS - 1)) - 1;
+
+    pub fn sqrt(x: Float) -> Float {
+        if x.is_nan() || x < 0.0 {
+            return Float::NAN;
+        }
+        if x == 0.0 || x == Float::INFINITY {
+            return x;
+        }
+
+        // Initial guess from halving the exponent, refined with Newton's method; each step
+        // doubles the number of correct digits.
+        let one = Float::to_bits(1.0);
+        let mut y = Float::from_bits((x.to_bits() >> 1) + (one >> 1));
+        for _ in 0..MANTISSA_BITS.ilog2() {
+            y = 0.5 * (y + x / y);
+        }
+        y
+    }
+
+    pub fn powi(x: Float, n: i32) -> Float {
+        let mut base = x;
+        let mut exp = n.unsigned_abs();
+        let mut result = 1.0;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+
+        if n < 0 {
+            1.0 / result
+        } else {
+            result
+        }
+    }
+
+    pub fn sin(x: Float) -> Float {
+        if !x.is_finite() {
+            return Float::NAN;
+        }
+
+        // Reduce to [-π, π], then fold onto [-π/2, π/2] where the series converges quickly.
+        let turns = ((x / (2.0 * PI)) + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+        let mut r = x - turns as Float * 2.0 * PI;
+        if r > FRAC_PI_2 {
+            r = PI - r;
+        } else if r < -FRAC_PI_2 {
+            r = -PI - r;
+        }
+
+        let r2 = r * r;
+        r * (1.0
+            - r2 / 6.0
+                * (1.0 - r2 / 20.0 * (1.0 - r2 / 42.0 * (1.0 - r2 / 72.0 * (1.0 - r2 / 110.0)))))
+    }
+
+    pub fn cos(x: Float) -> Float {
+        sin(x + FRAC_PI_2)
+    }
+
+    /// `x` raised to `n`, for the non-negative bases that shading needs.
+    pub fn powf(x: Float, n: Float) -> Float {
+        if n == 0.0 {
+            return 1.0;
+        }
+        if x <= 0.0 {
+            return if x == 0.0 && n > 0.0 { 0.0 } else { Float::NAN };
+        }
+        exp(n * ln(x))
+    }
+
+    pub fn floor(x: Float) -> Float {
+        // Every float this large is already a whole number.
+        if !x.is_finite() || x.abs() >= (1u64 << MANTISSA_BITS) as Float {
+            return x;
+        }
+
+        let truncated = x as i64 as Float;
+        if truncated > x {
+            truncated - 1.0
+        } else {
+            truncated
+        }
+    }
+
+    /// Angle of the point (`x`, `y`) from the x axis, in [-π, π].
+    pub fn atan2(y: Float, x: Float) -> Float {
+        if x.is_nan() || y.is_nan() {
+            return Float::NAN;
+        }
+        if x == 0.0 {
+            return if y > 0.0 {
+                FRAC_PI_2
+            } else if y < 0.0 {
+                -FRAC_PI_2
+            } else {
+                0.0
+            };
+        }
+
+        let angle = atan(y / x);
+        if x > 0.0 {
+            angle
+        } else if y < 0.0 {
+            angle - PI
+        } else {
+            angle + PI
+        }
+    }
+
+    fn atan(x: Float) -> Float {
+        if x < 0.0 {
+            return -atan(-x);
+        }
+        if x > 1.0 {
+            return FRAC_PI_2 - atan(1.0 / x);
+        }
+        // Shift by π/6 below tan(π/12), where the series converges quickly.
+        let sqrt_3 = sqrt(3.0);
+        if x > 2.0 - sqrt_3 {
+            return FRAC_PI_6 + atan((x * sqrt_3 - 1.0) / (sqrt_3 + x));
+        }
+
+        let x2 = x * x;
+        x * (1.0 - x2 * (1.0 / 3.0 - x2 * (1.0 / 5.0 - x2 * (1.0 / 7.0 - x2 / 9.0))))
+    }
+
+    fn ln(x: Float) -> Float {
+        // x = m * 2^e with m in [1, 2), then ln(m) = 2 atanh((m - 1) / (m + 1)).
+        let bits = x.to_bits();
+        let mantissa_mask = (1 << MANTISSA_BITS) - 1;
+        let e = (bits >> MANTISSA_BITS) as i32 - EXPONENT_BIAS;
+        let m = Float::from_bits((bits & mantissa_mask) | Float::to_bits(1.0));
+        let s = (m - 1.0) / (m + 1.0);
+        let s2 = s * s;
+        let series =
+            s * (2.0 + s2 * (2.0 / 3.0 + s2 * (2.0 / 5.0 + s2 * (2.0 / 7.0 + s2 * (2.0 / 9.0)))));
+        series + e as Float * LN_2
+    }
+
+    pub fn exp(x: Float) -> Float {
+        if x > (EXPONENT_BIAS + 1) as Float * LN_2 {
+            return Float::INFINITY;
+        }
+        if x < -((EXPONENT_BIAS + MANTISSA_BITS as i32) as Float) * LN_2 {
+            return 0.0;
+        }
+
+        // e^x = 2^k * e^r with |r| <= ln(2) / 2.
+        let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i32;
+        let r = x - k as Float * LN_2;
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for i in 1..10 {
+            term *= r / i as Float;
+            sum += term;
+        }
+        sum * powi(2.0, k)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::soft;
     use crate::prelude::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_approx_inside_epsilon() {
         let a = 1.0;
         let b = 1.00001;
@@ -17,10 +279,93 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_approx_outside_epsilon() {
         let a = 1.0;
         let b = 1.01;
 
         assert_eq!(is_approx(a, b, None), false);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn is_approx_with_infinities() {
+        assert!(is_approx(Float::INFINITY, Float::INFINITY, None));
+        assert!(is_approx(Float::NEG_INFINITY, Float::NEG_INFINITY, None));
+        assert!(!is_approx(Float::INFINITY, Float::NEG_INFINITY, None));
+        assert!(!is_approx(Float::INFINITY, Float::MAX, None));
+    }
+
+    #[test]
+    fn soft_sqrt_matches_std() {
+        for &x in &[0.0 as Float, 1e-6, 0.25, 1.0, 2.0, 14.0, 12345.678, 1e20] {
+            assert!(is_approx(soft::sqrt(x), x.sqrt(), Some(x.sqrt() * 1e-6)));
+        }
+        assert!(soft::sqrt(-1.0).is_nan());
+    }
+
+    #[test]
+    fn soft_sin_and_cos_match_std() {
+        for i in -40..=40 {
+            let x = i as Float * 0.37;
+            assert!(is_approx(soft::sin(x), x.sin(), Some(1e-5)));
+            assert!(is_approx(soft::cos(x), x.cos(), Some(1e-5)));
+        }
+    }
+
+    #[test]
+    fn soft_powf_matches_std() {
+        for &(x, n) in &[
+            (0.5 as Float, 200.0 as Float),
+            (0.99, 10.0),
+            (2.0, 0.5),
+            (10.0, 3.0),
+            (0.0, 2.0),
+            (3.0, 0.0),
+        ] {
+            let expected = x.powf(n);
+            assert!(is_approx(
+                soft::powf(x, n),
+                expected,
+                Some(expected.abs() * 1e-5 + 1e-30)
+            ));
+        }
+    }
+
+    #[test]
+    fn soft_exp_matches_std() {
+        for &x in &[-20.0 as Float, -2.5, -1.0, -0.1, 0.0, 0.3, 1.0, 4.2, 30.0] {
+            let expected = x.exp();
+            assert!(is_approx(soft::exp(x), expected, Some(expected * 1e-5)));
+        }
+    }
+
+    #[test]
+    fn soft_powi_matches_std() {
+        for &(x, n) in &[
+            (2.0 as Float, 0),
+            (2.0, 10),
+            (-1.5, 3),
+            (0.5, -2),
+            (3.0, 200),
+        ] {
+            assert_eq!(soft::powi(x, n), x.powi(n));
+        }
+    }
+
+    #[test]
+    fn soft_floor_matches_std() {
+        for &x in &[0.0 as Float, 0.5, 1.0, 1.9999, -0.1, -1.0, -2.5, 1e9, -1e9] {
+            assert_eq!(soft::floor(x), x.floor());
+        }
+    }
+
+    #[test]
+    fn soft_atan2_matches_std() {
+        for i in -12..=12 {
+            for j in -12..=12 {
+                let (y, x) = (i as Float * 0.7, j as Float * 1.3);
+                assert!(is_approx(soft::atan2(y, x), y.atan2(x), Some(1e-5)));
+            }
+        }
+    }
+}
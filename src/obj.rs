@@ -0,0 +1,565 @@
+use crate::prelude::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// A triangle of a mesh, as indices into the vertices and normals of its [`ObjFile`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Face {
+    pub vertices: [usize; 3],
+    /// Present when every corner of the face was given a vertex normal.
+    pub normals: Option<[usize; 3]>,
+}
+
+/// Faces listed under a `g` statement.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ObjGroup {
+    pub name: String,
+    pub faces: Vec<Face>,
+}
+
+/// The geometry described by a Wavefront OBJ file.
+///
+/// Only vertices (`v`), vertex normals (`vn`), faces (`f`) and groups (`g`) are understood.
+/// Polygons are split into triangles fanning out from their first vertex. Every other line,
+/// including malformed ones, is skipped and counted in [`ObjFile::ignored_lines`].
+///
+/// OBJ indices start at 1, but the indices stored in [`Face`] start at 0.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ObjFile {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    default_group: Vec<Face>,
+    groups: Vec<ObjGroup>,
+    /// Index in `groups` of the group new faces go into, if any.
+    current_group: Option<usize>,
+    ignored_lines: usize,
+}
+
+impl ObjFile {
+    pub fn parse(input: &str) -> Self {
+        let mut obj = Self::default();
+
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+            let arguments: Vec<&str> = words.collect();
+            let recognized = match keyword {
+                "v" => obj.parse_vertex(&arguments),
+                "vn" => obj.parse_normal(&arguments),
+                "f" => obj.parse_face(&arguments),
+                "g" => obj.parse_group(&arguments),
+                _ => false,
+            };
+
+            if !recognized {
+                obj.ignored_lines += 1;
+            }
+        }
+
+        obj
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn normals(&self) -> &[Vector] {
+        &self.normals
+    }
+
+    /// Faces that appear before any group statement.
+    pub fn default_group(&self) -> &[Face] {
+        &self.default_group
+    }
+
+    /// Named groups, in the order they first appear.
+    pub fn groups(&self) -> &[ObjGroup] {
+        &self.groups
+    }
+
+    pub fn group(&self, name: &str) -> Option<&ObjGroup> {
+        self.groups.iter().find(|group| group.name == name)
+    }
+
+    /// Number of non-blank, non-comment lines that were not understood.
+    pub fn ignored_lines(&self) -> usize {
+        self.ignored_lines
+    }
+
+    /// Builds a group holding the faces of the default group, and a child group for each
+    /// named group.
+    ///
+    /// Faces with vertex normals become [`SmoothTriangle`]s, the others [`Triangle`]s.
+    /// Large meshes render much faster once [`Shape::divide`] has been called on the result.
+    pub fn to_group(&self) -> Group {
+        self.to_group_with_material(&Material::default())
+    }
+
+    /// Builds a group like [`ObjFile::to_group`], giving every triangle `material`.
+    pub fn to_group_with_material(&self, material: &Material) -> Group {
+        let mut group = Group::new();
+        self.add_faces(&mut group, &self.default_group, material);
+        for obj_group in &self.groups {
+            let mut child = Group::new();
+            self.add_faces(&mut child, &obj_group.faces, material);
+            group.add_child(child);
+        }
+        group
+    }
+
+    fn add_faces(&self, group: &mut Group, faces: &[Face], material: &Material) {
+        for face in faces {
+            let [p1, p2, p3] = face.vertices.map(|index| self.vertices[index]);
+            match face.normals {
+                Some(normals) => {
+                    let [n1, n2, n3] = normals.map(|index| self.normals[index]);
+                    group.add_child(
+                        SmoothTriangle::new(p1, p2, p3, n1, n2, n3).with_material(material.clone()),
+                    );
+                }
+                None => group.add_child(Triangle::new(p1, p2, p3).with_material(material.clone())),
+            }
+        }
+    }
+
+    fn parse_vertex(&mut self, arguments: &[&str]) -> bool {
+        match parse_triple(arguments) {
+            Some([x, y, z]) => {
+                self.vertices.push(Point::new(x, y, z));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_normal(&mut self, arguments: &[&str]) -> bool {
+        match parse_triple(arguments) {
+            Some([x, y, z]) => {
+                self.normals.push(Vector::new(x, y, z));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_face(&mut self, arguments: &[&str]) -> bool {
+        if arguments.len() < 3 {
+            return false;
+        }
+
+        let mut vertices = Vec::with_capacity(arguments.len());
+        let mut normals = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            match self.parse_face_vertex(argument) {
+                Some((vertex, normal)) => {
+                    vertices.push(vertex);
+                    normals.push(normal);
+                }
+                None => return false,
+            }
+        }
+
+        let faces = self.current_group();
+        for i in 1..vertices.len() - 1 {
+            let corners = [0, i, i + 1];
+            let normals = match (normals[0], normals[i], normals[i + 1]) {
+                (Some(a), Some(b), Some(c)) => Some([a, b, c]),
+                _ => None,
+            };
+            faces.push(Face {
+                vertices: corners.map(|corner| vertices[corner]),
+                normals,
+            });
+        }
+
+        true
+    }
+
+    /// Vertex and optional normal indices of a `v`, `v/vt`, `v//vn` or `v/vt/vn` reference.
+    fn parse_face_vertex(&self, argument: &str) -> Option<(usize, Option<usize>)> {
+        let mut indices = argument.split('/');
+        let vertex = parse_index(indices.next()?, self.vertices.len())?;
+        // Texture coordinates are not supported.
+        let _ = indices.next();
+        let normal = match indices.next() {
+            Some(index) => Some(parse_index(index, self.normals.len())?),
+            None => None,
+        };
+
+        Some((vertex, normal))
+    }
+
+    fn parse_group(&mut self, arguments: &[&str]) -> bool {
+        let name = match arguments.first() {
+            Some(name) => *name,
+            None => return false,
+        };
+
+        // Naming a group again reopens it rather than starting a new one.
+        let index = match self.groups.iter().position(|group| group.name == name) {
+            Some(index) => index,
+            None => {
+                self.groups.push(ObjGroup {
+                    name: name.to_string(),
+                    faces: Vec::new(),
+                });
+                self.groups.len() - 1
+            }
+        };
+        self.current_group = Some(index);
+
+        true
+    }
+
+    fn current_group(&mut self) -> &mut Vec<Face> {
+        match self.current_group {
+            Some(index) => &mut self.groups[index].faces,
+            None => &mut self.default_group,
+        }
+    }
+}
+
+fn parse_triple(arguments: &[&str]) -> Option<[Float; 3]> {
+    match arguments {
+        [x, y, z] => Some([x.parse().ok()?, y.parse().ok()?, z.parse().ok()?]),
+        _ => None,
+    }
+}
+
+/// Converts a 1-based OBJ index into a 0-based one, checking it refers to an existing element.
+fn parse_index(index: &str, count: usize) -> Option<usize> {
+    let index: usize = index.parse().ok()?;
+    if index == 0 || index > count {
+        return None;
+    }
+    Some(index - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: Ignoring unrecognized lines
+    //  Given gibberish ← a file containing:
+    //    """
+    //    There was a young lady named Bright
+    //    who traveled much faster than light.
+    //    She set out one day
+    //    in a relative way,
+    //    and came back the previous night.
+    //    """
+    //  When parser ← parse_obj_file(gibberish)
+    //  Then parser should have ignored 5 lines
+    #[test]
+    fn ignoring_unrecognized_lines() {
+        let gibberish = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+
+        let parser = ObjFile::parse(gibberish);
+
+        assert_eq!(parser.ignored_lines(), 5);
+    }
+
+    // Scenario: Vertex records
+    //  Given file ← a file containing:
+    //    """
+    //    v -1 1 0
+    //    v -1.0000 0.5000 0.0000
+    //    v 1 0 0
+    //    v 1 1 0
+    //    """
+    //  When parser ← parse_obj_file(file)
+    //  Then parser.vertices[1] = point(-1, 1, 0)
+    //    And parser.vertices[2] = point(-1, 0.5, 0)
+    //    And parser.vertices[3] = point(1, 0, 0)
+    //    And parser.vertices[4] = point(1, 1, 0)
+    #[test]
+    fn vertex_records() {
+        let file = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0";
+
+        let parser = ObjFile::parse(file);
+
+        assert_eq!(
+            parser.vertices(),
+            &[
+                Point::new(-1.0, 1.0, 0.0),
+                Point::new(-1.0, 0.5, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(1.0, 1.0, 0.0),
+            ]
+        );
+    }
+
+    // Scenario: Parsing triangle faces
+    //  Given file ← a file containing:
+    //    """
+    //    v -1 1 0
+    //    v -1 0 0
+    //    v 1 0 0
+    //    v 1 1 0
+    //
+    //    f 1 2 3
+    //    f 1 3 4
+    //    """
+    //  When parser ← parse_obj_file(file)
+    //    And g ← parser.default_group
+    //    And t1 ← first child of g
+    //    And t2 ← second child of g
+    //  Then t1.p1 = parser.vertices[1]
+    //    And t1.p2 = parser.vertices[2]
+    //    And t1.p3 = parser.vertices[3]
+    //    And t2.p1 = parser.vertices[1]
+    //    And t2.p2 = parser.vertices[3]
+    //    And t2.p3 = parser.vertices[4]
+    #[test]
+    fn parsing_triangle_faces() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+
+        let parser = ObjFile::parse(file);
+        let g = parser.default_group();
+
+        assert_eq!(g.len(), 2);
+        assert_eq!(g[0].vertices, [0, 1, 2]);
+        assert_eq!(g[1].vertices, [0, 2, 3]);
+        assert_eq!(parser.ignored_lines(), 0);
+    }
+
+    // Scenario: Triangulating polygons
+    //  Given file ← a file containing:
+    //    """
+    //    v -1 1 0
+    //    v -1 0 0
+    //    v 1 0 0
+    //    v 1 1 0
+    //    v 0 2 0
+    //
+    //    f 1 2 3 4 5
+    //    """
+    //  When parser ← parse_obj_file(file)
+    //    And g ← parser.default_group
+    //    And t1 ← first child of g
+    //    And t2 ← second child of g
+    //    And t3 ← third child of g
+    //  Then t1.p1 = parser.vertices[1]
+    //    And t1.p2 = parser.vertices[2]
+    //    And t1.p3 = parser.vertices[3]
+    //    And t2.p1 = parser.vertices[1]
+    //    And t2.p2 = parser.vertices[3]
+    //    And t2.p3 = parser.vertices[4]
+    //    And t3.p1 = parser.vertices[1]
+    //    And t3.p2 = parser.vertices[4]
+    //    And t3.p3 = parser.vertices[5]
+    #[test]
+    fn triangulating_polygons() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5";
+
+        let parser = ObjFile::parse(file);
+        let g = parser.default_group();
+
+        assert_eq!(g.len(), 3);
+        assert_eq!(g[0].vertices, [0, 1, 2]);
+        assert_eq!(g[1].vertices, [0, 2, 3]);
+        assert_eq!(g[2].vertices, [0, 3, 4]);
+    }
+
+    // Scenario: Triangles in groups
+    //  Given file ← the file "triangles.obj"
+    //  When parser ← parse_obj_file(file)
+    //    And g1 ← "FirstGroup" from parser
+    //    And g2 ← "SecondGroup" from parser
+    //    And t1 ← first child of g1
+    //    And t2 ← first child of g2
+    //  Then t1.p1 = parser.vertices[1]
+    //    And t1.p2 = parser.vertices[2]
+    //    And t1.p3 = parser.vertices[3]
+    //    And t2.p1 = parser.vertices[1]
+    //    And t2.p2 = parser.vertices[3]
+    //    And t2.p3 = parser.vertices[4]
+    #[test]
+    fn triangles_in_groups() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let parser = ObjFile::parse(file);
+        let g1 = parser.group("FirstGroup").unwrap();
+        let g2 = parser.group("SecondGroup").unwrap();
+
+        assert!(parser.default_group().is_empty());
+        assert_eq!(g1.faces.len(), 1);
+        assert_eq!(g1.faces[0].vertices, [0, 1, 2]);
+        assert_eq!(g2.faces.len(), 1);
+        assert_eq!(g2.faces[0].vertices, [0, 2, 3]);
+    }
+
+    // Scenario: Vertex normal records
+    //  Given file ← a file containing:
+    //    """
+    //    vn 0 0 1
+    //    vn 0.707 0 -0.707
+    //    vn 1 2 3
+    //    """
+    //  When parser ← parse_obj_file(file)
+    //  Then parser.normals[1] = vector(0, 0, 1)
+    //    And parser.normals[2] = vector(0.707, 0, -0.707)
+    //    And parser.normals[3] = vector(1, 2, 3)
+    #[test]
+    fn vertex_normal_records() {
+        let file = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3";
+
+        let parser = ObjFile::parse(file);
+
+        assert_eq!(
+            parser.normals(),
+            &[
+                Vector::new(0.0, 0.0, 1.0),
+                Vector::new(0.707, 0.0, -0.707),
+                Vector::new(1.0, 2.0, 3.0),
+            ]
+        );
+    }
+
+    // Scenario: Faces with normals
+    //  Given file ← a file containing:
+    //    """
+    //    v 0 1 0
+    //    v -1 0 0
+    //    v 1 0 0
+    //
+    //    vn -1 0 0
+    //    vn 1 0 0
+    //    vn 0 1 0
+    //
+    //    f 1//3 2//1 3//2
+    //    f 1/0/3 2/102/1 3/14/2
+    //    """
+    //  When parser ← parse_obj_file(file)
+    //    And g ← parser.default_group
+    //    And t1 ← first child of g
+    //    And t2 ← second child of g
+    //  Then t1.p1 = parser.vertices[1]
+    //    And t1.p2 = parser.vertices[2]
+    //    And t1.p3 = parser.vertices[3]
+    //    And t1.n1 = parser.normals[3]
+    //    And t1.n2 = parser.normals[1]
+    //    And t1.n3 = parser.normals[2]
+    //    And t2 = t1
+    #[test]
+    fn faces_with_normals() {
+        let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/0/3 2/102/1 3/14/2";
+
+        let parser = ObjFile::parse(file);
+        let g = parser.default_group();
+
+        assert_eq!(g.len(), 2);
+        assert_eq!(g[0].vertices, [0, 1, 2]);
+        assert_eq!(g[0].normals, Some([2, 0, 1]));
+        assert_eq!(g[1], g[0]);
+
+        let group = parser.to_group();
+        let t1 = group.children()[0]
+            .downcast_ref::<SmoothTriangle>()
+            .unwrap();
+        assert_eq!(t1.p1(), parser.vertices()[0]);
+        assert_eq!(t1.n1(), parser.normals()[2]);
+        assert_eq!(t1.n2(), parser.normals()[0]);
+        assert_eq!(t1.n3(), parser.normals()[1]);
+    }
+
+    // Scenario: Converting an OBJ file to a group
+    //  Given file ← the file "triangles.obj"
+    //    And parser ← parse_obj_file(file)
+    //  When g ← obj_to_group(parser)
+    //  Then g includes "FirstGroup" from parser
+    //    And g includes "SecondGroup" from parser
+    #[test]
+    fn converting_obj_file_to_group() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let parser = ObjFile::parse(file);
+        let g = parser.to_group();
+
+        assert_eq!(g.children().len(), 2);
+        let g1 = g.children()[0].downcast_ref::<Group>().unwrap();
+        let g2 = g.children()[1].downcast_ref::<Group>().unwrap();
+        let t1 = g1.children()[0].downcast_ref::<Triangle>().unwrap();
+        let t2 = g2.children()[0].downcast_ref::<Triangle>().unwrap();
+        assert_eq!(t1.p2(), parser.vertices()[1]);
+        assert_eq!(t2.p2(), parser.vertices()[2]);
+    }
+
+    #[test]
+    fn malformed_and_unsupported_lines_are_counted_as_ignored() {
+        let file = "# a comment, which is not counted
+v 1 2
+v 0 0 0
+v 1 0 0
+v 0 1 0
+vt 0.5 0.5
+f 1 2
+f 1 2 9
+usemtl shiny
+g";
+
+        let parser = ObjFile::parse(file);
+
+        assert_eq!(parser.vertices().len(), 3);
+        assert!(parser.default_group().is_empty());
+        assert_eq!(parser.ignored_lines(), 6);
+    }
+}
@@ -0,0 +1,548 @@
+use crate::prelude::*;
+use core::fmt;
+
+/// Transformation of a pattern relative to the shape it is applied to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternProperties {
+    transform: Matrix4,
+    inverse: Matrix4,
+}
+
+impl PatternProperties {
+    pub fn new() -> Self {
+        Self {
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    /// Inverse of the transformation, which brings object-space points into pattern space.
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `transform` is not invertible.
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.inverse = transform
+            .inverse()
+            .expect("pattern transformations must be invertible");
+        self.transform = transform;
+    }
+}
+
+impl Default for PatternProperties {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A color that varies over the surface of a shape.
+///
+/// Implementations only map points in their own pattern space to colors through
+/// [`Pattern::local_pattern_at`]; [`Pattern::pattern_at_shape`] takes care of bringing
+/// world-space points through the shape and pattern transformations.
+pub trait Pattern: Send + Sync + fmt::Debug {
+    fn properties(&self) -> &PatternProperties;
+
+    fn properties_mut(&mut self) -> &mut PatternProperties;
+
+    /// Color at a point given in pattern space.
+    fn local_pattern_at(&self, point: Point) -> Color;
+
+    fn transform(&self) -> &Matrix4 {
+        self.properties().transform()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `transform` is not invertible.
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.properties_mut().set_transform(transform);
+    }
+
+    fn with_transform(mut self, transform: Matrix4) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+
+    /// Color at a world-space point on `object`.
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        let object_point = object.world_to_object(world_point);
+        let pattern_point = *self.properties().inverse() * object_point;
+        self.local_pattern_at(pattern_point)
+    }
+}
+
+/// Alternating bands of two colors along the x axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StripePattern {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl StripePattern {
+    pub fn new(a: Color, b: Color) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for StripePattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        if point.x.floor() as i64 % 2 == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// A linear blend from one color to another, repeating every unit along the x axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientPattern {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl GradientPattern {
+    pub fn new(a: Color, b: Color) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for GradientPattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        self.a + (self.b - self.a) * (point.x - point.x.floor())
+    }
+}
+
+/// Concentric rings of two colors around the y axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingPattern {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl RingPattern {
+    pub fn new(a: Color, b: Color) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for RingPattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let distance = (point.x * point.x + point.z * point.z).sqrt();
+        if distance.floor() as i64 % 2 == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// Unit cubes of two alternating colors, like a three-dimensional checkerboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckersPattern {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl CheckersPattern {
+    pub fn new(a: Color, b: Color) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for CheckersPattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let sum = point.x.floor() + point.y.floor() + point.z.floor();
+        if sum as i64 % 2 == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// A pattern that returns the pattern-space point it was asked about as a color.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct TestPattern {
+    properties: PatternProperties,
+}
+
+#[cfg(test)]
+impl TestPattern {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+impl Pattern for TestPattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        Color::new(point.x, point.y, point.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestPattern;
+    use crate::prelude::*;
+
+    // Background:
+    //  Given black ← color(0, 0, 0)
+    //    And white ← color(1, 1, 1)
+    fn black_and_white() -> (Color, Color) {
+        (Color::black(), Color::white())
+    }
+
+    // Scenario: Creating a stripe pattern
+    //  Given pattern ← stripe_pattern(white, black)
+    //  Then pattern.a = white
+    //    And pattern.b = black
+    #[test]
+    fn creating_stripe_pattern() {
+        let (black, white) = black_and_white();
+        let pattern = StripePattern::new(white, black);
+
+        assert_eq!(pattern.a, white);
+        assert_eq!(pattern.b, black);
+    }
+
+    // Scenario: A stripe pattern is constant in y
+    //  Given pattern ← stripe_pattern(white, black)
+    //  Then stripe_at(pattern, point(0, 0, 0)) = white
+    //    And stripe_at(pattern, point(0, 1, 0)) = white
+    //    And stripe_at(pattern, point(0, 2, 0)) = white
+    #[test]
+    fn stripe_pattern_is_constant_in_y() {
+        let (black, white) = black_and_white();
+        let pattern = StripePattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 1.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 2.0, 0.0)), white);
+    }
+
+    // Scenario: A stripe pattern is constant in z
+    //  Given pattern ← stripe_pattern(white, black)
+    //  Then stripe_at(pattern, point(0, 0, 0)) = white
+    //    And stripe_at(pattern, point(0, 0, 1)) = white
+    //    And stripe_at(pattern, point(0, 0, 2)) = white
+    #[test]
+    fn stripe_pattern_is_constant_in_z() {
+        let (black, white) = black_and_white();
+        let pattern = StripePattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 1.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 2.0)), white);
+    }
+
+    // Scenario: A stripe pattern alternates in x
+    //  Given pattern ← stripe_pattern(white, black)
+    //  Then stripe_at(pattern, point(0, 0, 0)) = white
+    //    And stripe_at(pattern, point(0.9, 0, 0)) = white
+    //    And stripe_at(pattern, point(1, 0, 0)) = black
+    //    And stripe_at(pattern, point(-0.1, 0, 0)) = black
+    //    And stripe_at(pattern, point(-1, 0, 0)) = black
+    //    And stripe_at(pattern, point(-1.1, 0, 0)) = white
+    #[test]
+    fn stripe_pattern_alternates_in_x() {
+        let (black, white) = black_and_white();
+        let pattern = StripePattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.9, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.0, 0.0, 0.0)), black);
+        assert_eq!(pattern.local_pattern_at(Point::new(-0.1, 0.0, 0.0)), black);
+        assert_eq!(pattern.local_pattern_at(Point::new(-1.0, 0.0, 0.0)), black);
+        assert_eq!(pattern.local_pattern_at(Point::new(-1.1, 0.0, 0.0)), white);
+    }
+
+    // Scenario: Stripes with an object transformation
+    //  Given object ← sphere()
+    //    And set_transform(object, scaling(2, 2, 2))
+    //    And pattern ← stripe_pattern(white, black)
+    //  When c ← stripe_at_object(pattern, object, point(1.5, 0, 0))
+    //  Then c = white
+    #[test]
+    fn stripes_with_object_transformation() {
+        let (black, white) = black_and_white();
+        let object = Sphere::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let pattern = StripePattern::new(white, black);
+
+        let c = pattern.pattern_at_shape(&object, Point::new(1.5, 0.0, 0.0));
+
+        assert_eq!(c, white);
+    }
+
+    // Scenario: Stripes with a pattern transformation
+    //  Given object ← sphere()
+    //    And pattern ← stripe_pattern(white, black)
+    //    And set_pattern_transform(pattern, scaling(2, 2, 2))
+    //  When c ← stripe_at_object(pattern, object, point(1.5, 0, 0))
+    //  Then c = white
+    #[test]
+    fn stripes_with_pattern_transformation() {
+        let (black, white) = black_and_white();
+        let object = Sphere::new();
+        let pattern =
+            StripePattern::new(white, black).with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+
+        let c = pattern.pattern_at_shape(&object, Point::new(1.5, 0.0, 0.0));
+
+        assert_eq!(c, white);
+    }
+
+    // Scenario: Stripes with both an object and a pattern transformation
+    //  Given object ← sphere()
+    //    And set_transform(object, scaling(2, 2, 2))
+    //    And pattern ← stripe_pattern(white, black)
+    //    And set_pattern_transform(pattern, translation(0.5, 0, 0))
+    //  When c ← stripe_at_object(pattern, object, point(2.5, 0, 0))
+    //  Then c = white
+    #[test]
+    fn stripes_with_object_and_pattern_transformation() {
+        let (black, white) = black_and_white();
+        let object = Sphere::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let pattern =
+            StripePattern::new(white, black).with_transform(Matrix4::translation(0.5, 0.0, 0.0));
+
+        let c = pattern.pattern_at_shape(&object, Point::new(2.5, 0.0, 0.0));
+
+        assert_eq!(c, white);
+    }
+
+    // Scenario: The default pattern transformation
+    //  Given pattern ← test_pattern()
+    //  Then pattern.transform = identity_matrix
+    #[test]
+    fn default_pattern_transformation() {
+        let pattern = TestPattern::new();
+
+        assert_eq!(*pattern.transform(), Matrix4::identity());
+    }
+
+    // Scenario: Assigning a transformation
+    //  Given pattern ← test_pattern()
+    //  When set_pattern_transform(pattern, translation(1, 2, 3))
+    //  Then pattern.transform = translation(1, 2, 3)
+    #[test]
+    fn assigning_transformation() {
+        let mut pattern = TestPattern::new();
+        pattern.set_transform(Matrix4::translation(1.0, 2.0, 3.0));
+
+        assert_eq!(*pattern.transform(), Matrix4::translation(1.0, 2.0, 3.0));
+    }
+
+    // Scenario: A pattern with an object transformation
+    //  Given shape ← sphere()
+    //    And set_transform(shape, scaling(2, 2, 2))
+    //    And pattern ← test_pattern()
+    //  When c ← pattern_at_shape(pattern, shape, point(2, 3, 4))
+    //  Then c = color(1, 1.5, 2)
+    #[test]
+    fn pattern_with_object_transformation() {
+        let shape = Sphere::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let pattern = TestPattern::new();
+
+        let c = pattern.pattern_at_shape(&shape, Point::new(2.0, 3.0, 4.0));
+
+        assert_eq!(c, Color::new(1.0, 1.5, 2.0));
+    }
+
+    // Scenario: A pattern with a pattern transformation
+    //  Given shape ← sphere()
+    //    And pattern ← test_pattern()
+    //    And set_pattern_transform(pattern, scaling(2, 2, 2))
+    //  When c ← pattern_at_shape(pattern, shape, point(2, 3, 4))
+    //  Then c = color(1, 1.5, 2)
+    #[test]
+    fn pattern_with_pattern_transformation() {
+        let shape = Sphere::new();
+        let pattern = TestPattern::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+
+        let c = pattern.pattern_at_shape(&shape, Point::new(2.0, 3.0, 4.0));
+
+        assert_eq!(c, Color::new(1.0, 1.5, 2.0));
+    }
+
+    // Scenario: A pattern with both an object and a pattern transformation
+    //  Given shape ← sphere()
+    //    And set_transform(shape, scaling(2, 2, 2))
+    //    And pattern ← test_pattern()
+    //    And set_pattern_transform(pattern, translation(0.5, 1, 1.5))
+    //  When c ← pattern_at_shape(pattern, shape, point(2.5, 3, 3.5))
+    //  Then c = color(0.75, 0.5, 0.25)
+    #[test]
+    fn pattern_with_object_and_pattern_transformation() {
+        let shape = Sphere::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let pattern = TestPattern::new().with_transform(Matrix4::translation(0.5, 1.0, 1.5));
+
+        let c = pattern.pattern_at_shape(&shape, Point::new(2.5, 3.0, 3.5));
+
+        assert_eq!(c, Color::new(0.75, 0.5, 0.25));
+    }
+
+    // Scenario: A gradient linearly interpolates between colors
+    //  Given pattern ← gradient_pattern(white, black)
+    //  Then pattern_at(pattern, point(0, 0, 0)) = white
+    //    And pattern_at(pattern, point(0.25, 0, 0)) = color(0.75, 0.75, 0.75)
+    //    And pattern_at(pattern, point(0.5, 0, 0)) = color(0.5, 0.5, 0.5)
+    //    And pattern_at(pattern, point(0.75, 0, 0)) = color(0.25, 0.25, 0.25)
+    #[test]
+    fn gradient_linearly_interpolates_between_colors() {
+        let (black, white) = black_and_white();
+        let pattern = GradientPattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.25, 0.0, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.5, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.75, 0.0, 0.0)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+
+    // Scenario: A ring should extend in both x and z
+    //  Given pattern ← ring_pattern(white, black)
+    //  Then pattern_at(pattern, point(0, 0, 0)) = white
+    //    And pattern_at(pattern, point(1, 0, 0)) = black
+    //    And pattern_at(pattern, point(0, 0, 1)) = black
+    //    # 0.708 = just slightly more than √2/2
+    //    And pattern_at(pattern, point(0.708, 0, 0.708)) = black
+    #[test]
+    fn ring_extends_in_both_x_and_z() {
+        let (black, white) = black_and_white();
+        let pattern = RingPattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.0, 0.0, 0.0)), black);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 1.0)), black);
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.708, 0.0, 0.708)),
+            black
+        );
+    }
+
+    // Scenario: Checkers should repeat in x
+    //  Given pattern ← checkers_pattern(white, black)
+    //  Then pattern_at(pattern, point(0, 0, 0)) = white
+    //    And pattern_at(pattern, point(0.99, 0, 0)) = white
+    //    And pattern_at(pattern, point(1.01, 0, 0)) = black
+    #[test]
+    fn checkers_repeat_in_x() {
+        let (black, white) = black_and_white();
+        let pattern = CheckersPattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.99, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.01, 0.0, 0.0)), black);
+    }
+
+    // Scenario: Checkers should repeat in y
+    //  Given pattern ← checkers_pattern(white, black)
+    //  Then pattern_at(pattern, point(0, 0, 0)) = white
+    //    And pattern_at(pattern, point(0, 0.99, 0)) = white
+    //    And pattern_at(pattern, point(0, 1.01, 0)) = black
+    #[test]
+    fn checkers_repeat_in_y() {
+        let (black, white) = black_and_white();
+        let pattern = CheckersPattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.99, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 1.01, 0.0)), black);
+    }
+
+    // Scenario: Checkers should repeat in z
+    //  Given pattern ← checkers_pattern(white, black)
+    //  Then pattern_at(pattern, point(0, 0, 0)) = white
+    //    And pattern_at(pattern, point(0, 0, 0.99)) = white
+    //    And pattern_at(pattern, point(0, 0, 1.01)) = black
+    #[test]
+    fn checkers_repeat_in_z() {
+        let (black, white) = black_and_white();
+        let pattern = CheckersPattern::new(white, black);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.99)), white);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 1.01)), black);
+    }
+}
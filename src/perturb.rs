@@ -0,0 +1,225 @@
+use crate::prelude::*;
+use core::fmt;
+
+/// Fakes small bumps on a surface by tilting its normals, without changing its geometry.
+///
+/// Set on [`Material::normal_perturb`], it is applied by [`Shape::normal_at_hit`] to the
+/// normals used for shading.
+pub trait NormalPerturb: Send + Sync + fmt::Debug {
+    /// Tilted unit normal of the surface at `point`, given its true unit `normal`, both in
+    /// object space.
+    fn perturb(&self, point: Point, normal: Vector) -> Vector;
+}
+
+/// Tilts `normal` as if the surface were pushed out along it by a height field whose
+/// gradient at the point is `gradient`.
+///
+/// Only the part of the gradient tangent to the surface matters: moving along the normal
+/// does not change which way the surface faces.
+pub fn bump_normal(normal: Vector, gradient: Vector) -> Vector {
+    let tangent_gradient = gradient - normal * gradient.dot(&normal);
+    (normal - tangent_gradient).normalize()
+}
+
+/// Sine waves along each axis, like ripples on water.
+///
+/// The surface is raised by `amplitude * (sin(f x) + sin(f y) + sin(f z))`, with `f` the
+/// `frequency` in radians per unit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ripples {
+    pub amplitude: Float,
+    pub frequency: Float,
+}
+
+impl Ripples {
+    pub fn new(amplitude: Float, frequency: Float) -> Self {
+        Self {
+            amplitude,
+            frequency,
+        }
+    }
+}
+
+impl NormalPerturb for Ripples {
+    fn perturb(&self, point: Point, normal: Vector) -> Vector {
+        let f = self.frequency;
+        let k = self.amplitude * f;
+        let gradient = Vector::new(
+            k * (f * point.x).cos(),
+            k * (f * point.y).cos(),
+            k * (f * point.z).cos(),
+        );
+        bump_normal(normal, gradient)
+    }
+}
+
+/// Irregular bumps from Perlin noise, `scale` units across and raised by up to about
+/// `amplitude`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoiseBump {
+    pub amplitude: Float,
+    pub scale: Float,
+}
+
+impl NoiseBump {
+    pub fn new(amplitude: Float, scale: Float) -> Self {
+        Self { amplitude, scale }
+    }
+
+    fn height(&self, x: Float, y: Float, z: Float) -> Float {
+        self.amplitude * perlin_noise(x / self.scale, y / self.scale, z / self.scale)
+    }
+}
+
+impl NormalPerturb for NoiseBump {
+    fn perturb(&self, point: Point, normal: Vector) -> Vector {
+        // Central differences, small compared to the size of the bumps.
+        let d = self.scale * 0.001;
+        let Point { x, y, z, .. } = point;
+        let gradient = Vector::new(
+            self.height(x + d, y, z) - self.height(x - d, y, z),
+            self.height(x, y + d, z) - self.height(x, y - d, z),
+            self.height(x, y, z + d) - self.height(x, y, z - d),
+        ) / (2.0 * d);
+        bump_normal(normal, gradient)
+    }
+}
+
+/// Ken Perlin's improved gradient noise: smooth, roughly in [-1, 1], and 0 on every
+/// point with whole coordinates.
+pub fn perlin_noise(x: Float, y: Float, z: Float) -> Float {
+    let (xf, yf, zf) = (x.floor(), y.floor(), z.floor());
+    let (xi, yi, zi) = (xf as i32, yf as i32, zf as i32);
+    let (x, y, z) = (x - xf, y - yf, z - zf);
+    let (u, v, w) = (fade(x), fade(y), fade(z));
+
+    let corner = |dx: i32, dy: i32, dz: i32| {
+        gradient(
+            hash(xi + dx, yi + dy, zi + dz),
+            x - dx as Float,
+            y - dy as Float,
+            z - dz as Float,
+        )
+    };
+    lerp(
+        w,
+        lerp(
+            v,
+            lerp(u, corner(0, 0, 0), corner(1, 0, 0)),
+            lerp(u, corner(0, 1, 0), corner(1, 1, 0)),
+        ),
+        lerp(
+            v,
+            lerp(u, corner(0, 0, 1), corner(1, 0, 1)),
+            lerp(u, corner(0, 1, 1), corner(1, 1, 1)),
+        ),
+    )
+}
+
+fn fade(t: Float) -> Float {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: Float, a: Float, b: Float) -> Float {
+    a + t * (b - a)
+}
+
+/// Pseudo-random bits for a lattice point, standing in for Perlin's permutation table.
+fn hash(x: i32, y: i32, z: i32) -> u32 {
+    let mut h = (x as u32).wrapping_mul(0x8da6_b343)
+        ^ (y as u32).wrapping_mul(0xd816_3841)
+        ^ (z as u32).wrapping_mul(0xcb1a_b31f);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0x5bd1_e995);
+    h ^ (h >> 15)
+}
+
+/// Dot product of the offset (x, y, z) with one of the 12 edge directions of a cube.
+fn gradient(hash: u32, x: Float, y: Float, z: Float) -> Float {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = match h {
+        0..=3 => y,
+        12 | 14 => x,
+        _ => z,
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::sync::Arc;
+
+    #[test]
+    fn bumping_ignores_gradient_along_normal() {
+        let normal = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(bump_normal(normal, Vector::new(0.0, 3.0, 0.0)), normal);
+        assert_eq!(
+            bump_normal(normal, Vector::new(1.0, 3.0, 0.0)),
+            Vector::new(-1.0, 1.0, 0.0).normalize()
+        );
+    }
+
+    #[test]
+    fn ripples_tilt_normals_along_waves() {
+        let ripples = Ripples::new(0.1, 2.0);
+        let normal = Vector::new(0.0, 1.0, 0.0);
+
+        // On a plane, the slope in x is amplitude * frequency * cos(frequency * x).
+        let n = ripples.perturb(Point::new(0.0, 0.0, crate::math::consts::FRAC_PI_4), normal);
+        assert_eq!(n, Vector::new(-0.2, 1.0, 0.0).normalize());
+        assert!(is_approx(n.magnitude(), 1.0, None));
+    }
+
+    #[test]
+    fn perlin_noise_is_smooth_and_zero_on_lattice() {
+        assert_eq!(perlin_noise(0.0, 0.0, 0.0), 0.0);
+        assert_eq!(perlin_noise(3.0, -2.0, 7.0), 0.0);
+
+        let mut previous = perlin_noise(0.5, 0.25, 0.75);
+        let mut largest: Float = 0.0;
+        for i in 1..200 {
+            let value = perlin_noise(0.5 + i as Float * 0.01, 0.25, 0.75);
+            assert!((value - previous).abs() < 0.05);
+            assert!(value.abs() <= 1.5);
+            largest = largest.max(value.abs());
+            previous = value;
+        }
+        assert!(largest > 0.1);
+    }
+
+    #[test]
+    fn noise_bump_perturbs_normals_by_varying_amounts() {
+        let bump = NoiseBump::new(0.3, 0.5);
+        let normal = Vector::new(0.0, 0.0, -1.0);
+
+        let a = bump.perturb(Point::new(0.3, 0.1, 0.0), normal);
+        let b = bump.perturb(Point::new(0.7, 0.4, 0.0), normal);
+
+        assert_ne!(a, normal);
+        assert_ne!(a, b);
+        assert!(a.dot(&normal) > 0.0);
+        assert!(is_approx(a.magnitude(), 1.0, None));
+    }
+
+    #[test]
+    fn perturbation_applies_to_normals_at_hits() {
+        let ripples = Arc::new(Ripples::new(0.1, 2.0));
+        let s = Plane::new()
+            .with_transform(Matrix4::translation(0.0, 1.0, 0.0))
+            .with_material(Material {
+                normal_perturb: Some(ripples.clone()),
+                ..Material::default()
+            });
+        let p = Point::new(0.0, 1.0, crate::math::consts::FRAC_PI_4);
+        let i = Intersection::new(1.0, &s);
+
+        assert_eq!(s.normal_at(p), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            s.normal_at_hit(p, &i),
+            ripples.perturb(Point::new(0.0, 0.0, p.z), Vector::new(0.0, 1.0, 0.0))
+        );
+    }
+}
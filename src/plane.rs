@@ -0,0 +1,143 @@
+use crate::prelude::*;
+use alloc::vec;
+
+/// An infinite plane spanning the x and z axes of its object space.
+#[derive(Debug, Clone, Default)]
+pub struct Plane {
+    properties: ShapeProperties,
+}
+
+impl Plane {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Shape for Plane {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        // A ray parallel to the plane never meets it, and a coplanar one is invisible.
+        if ray.direction.y.abs() < EPSILON {
+            return Intersections::default();
+        }
+
+        let t = -ray.origin.y / ray.direction.y;
+        Intersections::new(vec![Intersection::new(t, self)])
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        Vector::new(0.0, 1.0, 0.0)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(Float::NEG_INFINITY, 0.0, Float::NEG_INFINITY),
+            Point::new(Float::INFINITY, 0.0, Float::INFINITY),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: The normal of a plane is constant everywhere
+    //  Given p ← plane()
+    //  When n1 ← local_normal_at(p, point(0, 0, 0))
+    //    And n2 ← local_normal_at(p, point(10, 0, -10))
+    //    And n3 ← local_normal_at(p, point(-5, 0, 150))
+    //  Then n1 = vector(0, 1, 0)
+    //    And n2 = vector(0, 1, 0)
+    //    And n3 = vector(0, 1, 0)
+    #[test]
+    fn normal_of_plane_is_constant_everywhere() {
+        let p = Plane::new();
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(p.local_normal_at(Point::origin()), up);
+        assert_eq!(p.local_normal_at(Point::new(10.0, 0.0, -10.0)), up);
+        assert_eq!(p.local_normal_at(Point::new(-5.0, 0.0, 150.0)), up);
+    }
+
+    // Scenario: Intersect with a ray parallel to the plane
+    //  Given p ← plane()
+    //    And r ← ray(point(0, 10, 0), vector(0, 0, 1))
+    //  When xs ← local_intersect(p, r)
+    //  Then xs is empty
+    #[test]
+    fn intersect_with_ray_parallel_to_plane() {
+        let p = Plane::new();
+        let r = Ray::new(Point::new(0.0, 10.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(p.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: Intersect with a coplanar ray
+    //  Given p ← plane()
+    //    And r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //  When xs ← local_intersect(p, r)
+    //  Then xs is empty
+    #[test]
+    fn intersect_with_coplanar_ray() {
+        let p = Plane::new();
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(p.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray intersecting a plane from above
+    //  Given p ← plane()
+    //    And r ← ray(point(0, 1, 0), vector(0, -1, 0))
+    //  When xs ← local_intersect(p, r)
+    //  Then xs.count = 1
+    //    And xs[0].t = 1
+    //    And xs[0].object = p
+    #[test]
+    fn ray_intersecting_plane_from_above() {
+        let p = Plane::new();
+        let r = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+        let xs = p.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 1.0);
+        assert!(core::ptr::addr_eq(xs[0].object, &p));
+    }
+
+    // Scenario: A ray intersecting a plane from below
+    //  Given p ← plane()
+    //    And r ← ray(point(0, -1, 0), vector(0, 1, 0))
+    //  When xs ← local_intersect(p, r)
+    //  Then xs.count = 1
+    //    And xs[0].t = 1
+    //    And xs[0].object = p
+    #[test]
+    fn ray_intersecting_plane_from_below() {
+        let p = Plane::new();
+        let r = Ray::new(Point::new(0.0, -1.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        let xs = p.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 1.0);
+        assert!(core::ptr::addr_eq(xs[0].object, &p));
+    }
+
+    // Scenario: A plane has a bounding box
+    //  Given shape ← plane()
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-infinity, 0, -infinity)
+    //    And box.max = point(infinity, 0, infinity)
+    #[test]
+    fn plane_has_bounding_box() {
+        let b = Plane::new().bounds();
+
+        assert_eq!(b.min, Point::new(Float::NEG_INFINITY, 0.0, Float::NEG_INFINITY));
+        assert_eq!(b.max, Point::new(Float::INFINITY, 0.0, Float::INFINITY));
+    }
+}
@@ -0,0 +1,879 @@
+use crate::prelude::*;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+const BYTES_PER_PIXEL: usize = 3;
+
+/// 8-bit RGB PNG, compressed with a fixed-Huffman deflate stream.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PngEncoder;
+
+impl ImageEncoder for PngEncoder {
+    fn encode(&self, canvas: &Canvas) -> Vec<u8> {
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(canvas.width() as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(canvas.height() as u32).to_be_bytes());
+        // Bit depth 8, truecolor, deflate, adaptive filtering, no interlace.
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &ihdr);
+        write_chunk(&mut png, b"IDAT", &zlib_compress(&filter_scanlines(canvas)));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+/// 8-bit and 16-bit PNG in any color type, without interlacing. Transparency is ignored.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PngDecoder;
+
+impl ImageDecoder for PngDecoder {
+    fn decode(&self, bytes: &[u8]) -> Result<Canvas> {
+        if !bytes.starts_with(&SIGNATURE) {
+            return Err(invalid_png("missing signature"));
+        }
+
+        let mut header = None;
+        let mut palette: &[u8] = &[];
+        let mut compressed = Vec::new();
+        let mut rest = &bytes[SIGNATURE.len()..];
+        loop {
+            if rest.len() < 12 {
+                return Err(invalid_png("truncated chunk"));
+            }
+            let length = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            if rest.len() < 12 + length {
+                return Err(invalid_png("truncated chunk"));
+            }
+            let (kind, data) = (&rest[4..8], &rest[8..8 + length]);
+            let crc = &rest[8 + length..12 + length];
+            if crc32(&rest[4..8 + length]).to_be_bytes() != crc {
+                return Err(invalid_png("corrupted chunk"));
+            }
+            rest = &rest[12 + length..];
+
+            match kind {
+                b"IHDR" => header = Some(Header::parse(data)?),
+                b"PLTE" => palette = data,
+                b"IDAT" => compressed.extend_from_slice(data),
+                b"IEND" => break,
+                // Ancillary chunks, like gamma or text, do not change the pixels.
+                _ => {}
+            }
+        }
+
+        let header = header.ok_or_else(|| invalid_png("missing header"))?;
+        let scanlines = zlib_decompress(&compressed)?;
+        let samples = unfilter_scanlines(&header, &scanlines)?;
+        header.to_canvas(&samples, palette)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Canvas {
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.save(path, &PngEncoder)
+    }
+
+    pub fn load_png<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::load(path, &PngDecoder)
+    }
+}
+
+fn invalid_png(reason: &str) -> RustracerError {
+    RustracerError::InvalidImage(format!("PNG: {}", reason))
+}
+
+struct Header {
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    color_type: u8,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() != 13 {
+            return Err(invalid_png("malformed header"));
+        }
+        let header = Self {
+            width: u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize,
+            height: u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize,
+            bit_depth: data[8],
+            color_type: data[9],
+        };
+        if !is_decodable_size(header.width, header.height) {
+            return Err(invalid_png("the image is empty or too large"));
+        }
+        if data[12] != 0 {
+            return Err(invalid_png("interlaced images are not supported"));
+        }
+        let supported = match header.color_type {
+            3 => header.bit_depth == 8,
+            0 | 2 | 4 | 6 => header.bit_depth == 8 || header.bit_depth == 16,
+            _ => false,
+        };
+        if !supported {
+            return Err(invalid_png("unsupported bit depth or color type"));
+        }
+        Ok(header)
+    }
+
+    fn channels(&self) -> usize {
+        match self.color_type {
+            0 | 3 => 1,
+            4 => 2,
+            2 => 3,
+            _ => 4,
+        }
+    }
+
+    /// Bytes per pixel, which is also how far back filters look.
+    fn pixel_size(&self) -> usize {
+        self.channels() * self.bit_depth as usize / 8
+    }
+
+    fn to_canvas(&self, samples: &[u8], palette: &[u8]) -> Result<Canvas> {
+        let mut canvas = Canvas::new(self.width, self.height);
+        let sample_size = self.bit_depth as usize / 8;
+        let max = ((1u32 << self.bit_depth) - 1) as Float;
+        let sample = |pixel: &[u8], channel: usize| {
+            let offset = channel * sample_size;
+            let value = if sample_size == 2 {
+                u16::from_be_bytes([pixel[offset], pixel[offset + 1]]) as Float
+            } else {
+                pixel[offset] as Float
+            };
+            value / max
+        };
+
+        for (i, pixel) in samples.chunks_exact(self.pixel_size()).enumerate() {
+            let color = match self.color_type {
+                0 | 4 => {
+                    let gray = sample(pixel, 0);
+                    Color::new(gray, gray, gray)
+                }
+                3 => {
+                    let index = pixel[0] as usize * 3;
+                    let rgb = palette
+                        .get(index..index + 3)
+                        .ok_or_else(|| invalid_png("palette index out of range"))?;
+                    Color::new(
+                        rgb[0] as Float / 255.0,
+                        rgb[1] as Float / 255.0,
+                        rgb[2] as Float / 255.0,
+                    )
+                }
+                _ => Color::new(sample(pixel, 0), sample(pixel, 1), sample(pixel, 2)),
+            };
+            canvas.write_pixel(i % self.width, i / self.width, color);
+        }
+        Ok(canvas)
+    }
+}
+
+/// Undoes the per-scanline filters, leaving the raw samples of every row.
+fn unfilter_scanlines(header: &Header, data: &[u8]) -> Result<Vec<u8>> {
+    let bpp = header.pixel_size();
+    let stride = header.width * bpp;
+    if data.len() != header.height * (stride + 1) {
+        return Err(invalid_png("wrong amount of image data"));
+    }
+
+    let mut samples = vec![0u8; header.height * stride];
+    for (y, line) in data.chunks_exact(stride + 1).enumerate() {
+        let (filter, line) = (line[0], &line[1..]);
+        let (previous, current) = samples.split_at_mut(y * stride);
+        let above = if y == 0 {
+            None
+        } else {
+            Some(&previous[(y - 1) * stride..])
+        };
+        let current = &mut current[..stride];
+
+        for x in 0..stride {
+            let a = if x >= bpp { current[x - bpp] } else { 0 };
+            let b = above.map_or(0, |above| above[x]);
+            let c = match above {
+                Some(above) if x >= bpp => above[x - bpp],
+                _ => 0,
+            };
+            let predictor = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => paeth(a, b, c),
+                _ => return Err(invalid_png("unknown filter type")),
+            };
+            current[x] = line[x].wrapping_add(predictor);
+        }
+    }
+    Ok(samples)
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Prefixes every row with the filter type that gives the smallest sum of residuals.
+fn filter_scanlines(canvas: &Canvas) -> Vec<u8> {
+    let stride = canvas.width() * BYTES_PER_PIXEL;
+    let raw: Vec<u8> = canvas
+        .pixels()
+        .iter()
+        .flat_map(|c| [to_byte(c.r), to_byte(c.g), to_byte(c.b)])
+        .collect();
+
+    let mut filtered = Vec::with_capacity((stride + 1) * canvas.height());
+    let zeros = vec![0; stride];
+    let mut candidate = vec![0; stride];
+    let mut best = vec![0; stride];
+
+    for y in 0..canvas.height() {
+        let row = &raw[y * stride..(y + 1) * stride];
+        let prior = if y == 0 {
+            &zeros[..]
+        } else {
+            &raw[(y - 1) * stride..y * stride]
+        };
+
+        let mut best_filter = 0;
+        let mut best_cost = u64::MAX;
+        for filter in 0..5 {
+            for i in 0..stride {
+                let left = if i >= BYTES_PER_PIXEL {
+                    row[i - BYTES_PER_PIXEL]
+                } else {
+                    0
+                };
+                let up = prior[i];
+                let up_left = if i >= BYTES_PER_PIXEL {
+                    prior[i - BYTES_PER_PIXEL]
+                } else {
+                    0
+                };
+                let predictor = match filter {
+                    0 => 0,
+                    1 => left,
+                    2 => up,
+                    3 => ((left as u16 + up as u16) / 2) as u8,
+                    _ => paeth(left, up, up_left),
+                };
+                candidate[i] = row[i].wrapping_sub(predictor);
+            }
+
+            let cost = candidate
+                .iter()
+                .map(|&b| (b as i8).unsigned_abs() as u64)
+                .sum();
+            if cost < best_cost {
+                best_cost = cost;
+                best_filter = filter;
+                best.copy_from_slice(&candidate);
+            }
+        }
+
+        filtered.push(best_filter);
+        filtered.extend_from_slice(&best);
+    }
+
+    filtered
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+const WINDOW_SIZE: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: u32 = 15;
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    /// Writes `count` bits of `value`, least significant bit first.
+    fn write_bits(&mut self, value: u32, count: u32) {
+        self.buffer |= value << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes are packed starting from their most significant bit.
+    fn write_code(&mut self, code: u32, length: u32) {
+        self.write_bits(code.reverse_bits() >> (32 - length), length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+fn write_literal_or_length(out: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => out.write_code(0x30 + symbol, 8),
+        144..=255 => out.write_code(0x190 + symbol - 144, 9),
+        256..=279 => out.write_code(symbol - 256, 7),
+        _ => out.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(out: &mut BitWriter, length: usize, distance: usize) {
+    let code = LENGTH_BASE
+        .iter()
+        .rposition(|&b| b as usize <= length)
+        .unwrap();
+    write_literal_or_length(out, 257 + code as u32);
+    out.write_bits(
+        (length - LENGTH_BASE[code] as usize) as u32,
+        LENGTH_EXTRA[code] as u32,
+    );
+
+    let code = DISTANCE_BASE
+        .iter()
+        .rposition(|&b| b as usize <= distance)
+        .unwrap();
+    out.write_code(code as u32, 5);
+    out.write_bits(
+        (distance - DISTANCE_BASE[code] as usize) as u32,
+        DISTANCE_EXTRA[code] as u32,
+    );
+}
+
+fn hash(data: &[u8]) -> usize {
+    let key = (data[0] as u32) << 16 | (data[1] as u32) << 8 | data[2] as u32;
+    (key.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+}
+
+fn insert_hash(data: &[u8], pos: usize, head: &mut [usize], prev: &mut [usize]) {
+    if pos + MIN_MATCH <= data.len() {
+        let h = hash(&data[pos..]);
+        prev[pos % WINDOW_SIZE] = head[h];
+        head[h] = pos;
+    }
+}
+
+/// Single fixed-Huffman deflate block with greedy LZ77 matching.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter::new();
+    out.write_bits(1, 1); // final block
+    out.write_bits(1, 2); // fixed Huffman codes
+
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; WINDOW_SIZE];
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut best_length = 0;
+        let mut best_distance = 0;
+
+        if pos + MIN_MATCH <= data.len() {
+            let max_length = MAX_MATCH.min(data.len() - pos);
+            let mut candidate = head[hash(&data[pos..])];
+            let mut chain = 0;
+            while candidate != usize::MAX && pos - candidate <= WINDOW_SIZE && chain < MAX_CHAIN {
+                let length = data[candidate..]
+                    .iter()
+                    .zip(&data[pos..pos + max_length])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if length > best_length {
+                    best_length = length;
+                    best_distance = pos - candidate;
+                    if length == max_length {
+                        break;
+                    }
+                }
+                let next = prev[candidate % WINDOW_SIZE];
+                if next == usize::MAX || next >= candidate {
+                    break;
+                }
+                candidate = next;
+                chain += 1;
+            }
+        }
+
+        if best_length >= MIN_MATCH {
+            write_match(&mut out, best_length, best_distance);
+            for p in pos..pos + best_length {
+                insert_hash(data, p, &mut head, &mut prev);
+            }
+            pos += best_length;
+        } else {
+            write_literal_or_length(&mut out, data[pos] as u32);
+            insert_hash(data, pos, &mut head, &mut prev);
+            pos += 1;
+        }
+    }
+
+    write_literal_or_length(&mut out, 256);
+    out.finish()
+}
+
+pub(crate) fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    // 32K window, default compression level, no preset dictionary.
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(deflate(data));
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    /// Position of the next bit, counting from the start of `data`.
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Reads `count` bits, least significant bit first.
+    fn read_bits(&mut self, count: u32) -> Result<u32> {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = self
+                .data
+                .get(self.position / 8)
+                .ok_or_else(|| invalid_png("truncated deflate stream"))?;
+            value |= ((byte >> (self.position % 8)) as u32 & 1) << i;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Skips to the next byte boundary and returns the bytes from there.
+    fn align_to_byte(&mut self) -> &'a [u8] {
+        self.position = self.position.div_ceil(8) * 8;
+        &self.data[(self.position / 8).min(self.data.len())..]
+    }
+}
+
+/// Canonical Huffman code, decoded one bit at a time.
+struct Huffman {
+    /// Number of codes of each length.
+    counts: [u16; 16],
+    /// Symbols ordered by code.
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+
+        let mut offsets = [0u16; 16];
+        for length in 1..16 {
+            offsets[length] = offsets[length - 1] + counts[length - 1];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        Self { counts, symbols }
+    }
+
+    fn decode(&self, input: &mut BitReader) -> Result<u16> {
+        // Codes of each length follow the last code of the previous length, shifted.
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for length in 1..16 {
+            code |= input.read_bits(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(invalid_png("invalid Huffman code"))
+    }
+}
+
+/// Order in which the lengths of the code length code are stored.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    (Huffman::new(&lengths), Huffman::new(&[5; 30]))
+}
+
+fn dynamic_codes(input: &mut BitReader) -> Result<(Huffman, Huffman)> {
+    let literal_count = input.read_bits(5)? as usize + 257;
+    let distance_count = input.read_bits(5)? as usize + 1;
+    let code_length_count = input.read_bits(4)? as usize + 4;
+
+    let mut code_lengths = [0u8; 19];
+    for &i in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[i] = input.read_bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths);
+
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let (length, repeat) = match code_length_code.decode(input)? {
+            symbol @ 0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or_else(|| invalid_png("repeated code length with no previous one"))?;
+                (previous, 3 + input.read_bits(2)?)
+            }
+            17 => (0, 3 + input.read_bits(3)?),
+            _ => (0, 11 + input.read_bits(7)?),
+        };
+        lengths.extend((0..repeat).map(|_| length));
+    }
+    if lengths.len() != literal_count + distance_count {
+        return Err(invalid_png("too many code lengths"));
+    }
+
+    let (literals, distances) = lengths.split_at(literal_count);
+    Ok((Huffman::new(literals), Huffman::new(distances)))
+}
+
+fn inflate_block(
+    input: &mut BitReader,
+    out: &mut Vec<u8>,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<()> {
+    loop {
+        let symbol = literals.decode(input)? as usize;
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let code = symbol - 257;
+                if code >= LENGTH_BASE.len() {
+                    return Err(invalid_png("invalid length code"));
+                }
+                let length = LENGTH_BASE[code] as usize
+                    + input.read_bits(LENGTH_EXTRA[code] as u32)? as usize;
+
+                let code = distances.decode(input)? as usize;
+                if code >= DISTANCE_BASE.len() {
+                    return Err(invalid_png("invalid distance code"));
+                }
+                let distance = DISTANCE_BASE[code] as usize
+                    + input.read_bits(DISTANCE_EXTRA[code] as u32)? as usize;
+                if distance > out.len() {
+                    return Err(invalid_png("distance past the start of the stream"));
+                }
+
+                // Copies byte by byte, since a match may overlap what it produces.
+                let start = out.len() - distance;
+                for i in 0..length {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+}
+
+/// Decompresses a deflate stream made of stored, fixed-Huffman and dynamic-Huffman blocks.
+fn inflate(data: &[u8]) -> Result<Vec<u8>> {
+    let mut input = BitReader::new(data);
+    let mut out = Vec::new();
+    loop {
+        let last = input.read_bits(1)? == 1;
+        match input.read_bits(2)? {
+            0 => {
+                let bytes = input.align_to_byte();
+                if bytes.len() < 4 {
+                    return Err(invalid_png("truncated stored block"));
+                }
+                let length = u16::from_le_bytes([bytes[0], bytes[1]]) as usize;
+                if !u16::from_le_bytes([bytes[2], bytes[3]]) as usize != length {
+                    return Err(invalid_png("corrupted stored block"));
+                }
+                let stored = bytes
+                    .get(4..4 + length)
+                    .ok_or_else(|| invalid_png("truncated stored block"))?;
+                out.extend_from_slice(stored);
+                input.position += (4 + length) * 8;
+            }
+            1 => {
+                let (literals, distances) = fixed_codes();
+                inflate_block(&mut input, &mut out, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut input)?;
+                inflate_block(&mut input, &mut out, &literals, &distances)?;
+            }
+            _ => return Err(invalid_png("invalid block type")),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+pub(crate) fn zlib_decompress(stream: &[u8]) -> Result<Vec<u8>> {
+    if stream.len() < 6
+        || stream[0] & 0x0f != 8
+        || !u16::from_be_bytes([stream[0], stream[1]]).is_multiple_of(31)
+    {
+        return Err(invalid_png("invalid zlib header"));
+    }
+    if stream[1] & 0x20 != 0 {
+        return Err(invalid_png("preset dictionaries are not supported"));
+    }
+
+    let (deflated, checksum) = stream[2..].split_at(stream.len() - 6);
+    let data = inflate(deflated)?;
+    if adler32(&data).to_be_bytes() != checksum {
+        return Err(invalid_png("corrupted image data"));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_of_known_inputs() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    }
+
+    #[test]
+    fn adler32_of_known_input() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn png_starts_with_signature_and_header() {
+        let c = Canvas::new(5, 3);
+        let png = PngEncoder.encode(&c);
+
+        assert_eq!(png[..8], SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(png[16..20], 5u32.to_be_bytes());
+        assert_eq!(png[20..24], 3u32.to_be_bytes());
+        assert_eq!(png[24..29], [8, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn png_ends_with_iend_chunk() {
+        let png = PngEncoder.encode(&Canvas::new(2, 2));
+
+        assert_eq!(
+            png[png.len() - 12..],
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+    }
+
+    #[test]
+    fn uniform_canvas_compresses_well() {
+        let mut c = Canvas::new(100, 100);
+        c.fill(Color::new(0.2, 0.4, 0.6));
+
+        assert!(PngEncoder.encode(&c).len() < 1000);
+    }
+
+    #[test]
+    fn inflating_stored_and_fixed_huffman_blocks() {
+        let data = b"the quick brown fox jumps over the lazy dog, the quick brown fox";
+        let stored = [
+            0x78, 0x01, 0x01, 0x05, 0x00, 0xfa, 0xff, b'h', b'e', b'l', b'l', b'o', 0x06, 0x2c,
+            0x02, 0x15,
+        ];
+
+        assert_eq!(zlib_decompress(&stored).unwrap(), b"hello");
+        assert_eq!(zlib_decompress(&zlib_compress(data)).unwrap(), data);
+    }
+
+    #[test]
+    fn inflating_dynamic_huffman_block() {
+        // zlib's compression of 290 pseudo-random `a` and `b` bytes.
+        let stream = [
+            0x78, 0xda, 0x7d, 0x8e, 0x01, 0x0a, 0x00, 0x30, 0x08, 0x02, 0xdf, 0xda, 0xfd, 0xff,
+            0x11, 0x83, 0x1a, 0x9b, 0x2b, 0x99, 0x50, 0x58, 0x89, 0x19, 0x71, 0x40, 0x16, 0x49,
+            0xd9, 0x44, 0xc1, 0xb3, 0xa1, 0x54, 0x43, 0x24, 0xdd, 0x1c, 0x3e, 0x96, 0x5c, 0xdf,
+            0xae, 0xc6, 0x0c, 0x28, 0x33, 0x7f, 0xc5, 0x5a, 0x19, 0x36, 0x87, 0x4d, 0x48, 0xb9,
+            0x2c, 0x8f, 0xd2, 0x6e, 0x0a,
+        ];
+        let mut x: u32 = 1;
+        let expected: Vec<u8> = (0..290)
+            .map(|_| {
+                x = x.wrapping_mul(1_103_515_245).wrapping_add(12345) & 0x7fff_ffff;
+                b"aaaaaaab"[(x >> 16) as usize % 8]
+            })
+            .collect();
+
+        assert_eq!(zlib_decompress(&stream).unwrap(), expected);
+    }
+
+    #[test]
+    fn inflating_corrupted_stream_fails() {
+        let mut stream = zlib_compress(b"some image data");
+        let last = stream.len() - 1;
+        stream[last] ^= 1;
+
+        assert!(zlib_decompress(&stream).is_err());
+        assert!(zlib_decompress(&stream[..4]).is_err());
+    }
+
+    #[test]
+    fn decoding_encoded_png_round_trips() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Color::new(1.0, 0.2, 0.0));
+        c.write_pixel(4, 1, Color::new(0.0, 0.6, 1.0));
+        c.write_pixel(2, 2, Color::white());
+
+        let decoded = PngDecoder.decode(&PngEncoder.encode(&c)).unwrap();
+
+        assert_eq!(decoded.width(), 5);
+        assert_eq!(decoded.height(), 3);
+        assert_eq!(decoded.pixels(), c.pixels());
+    }
+
+    #[test]
+    fn decoding_rgba_png_with_filters() {
+        // 2x2 RGBA, the second row using the "up" filter, in a stored deflate block.
+        let scanlines = [
+            0x78, 0x01, 0x01, 0x12, 0x00, 0xed, 0xff, 0x00, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00,
+            0xff, 0x80, 0x02, 0x00, 0xff, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x39, 0xef, 0x05,
+            0x7e,
+        ];
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &[0, 0, 0, 2, 0, 0, 0, 2, 8, 6, 0, 0, 0]);
+        write_chunk(&mut png, b"IDAT", &scanlines);
+        write_chunk(&mut png, b"IEND", &[]);
+
+        let canvas = PngDecoder.decode(&png).unwrap();
+
+        assert_eq!(canvas.pixel_at(0, 0), Color::new(1.0, 0.0, 0.0));
+        assert_eq!(canvas.pixel_at(1, 0), Color::new(0.0, 0.0, 1.0));
+        assert_eq!(canvas.pixel_at(0, 1), Color::new(1.0, 1.0, 0.0));
+        assert_eq!(canvas.pixel_at(1, 1), Color::new(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn decoding_invalid_png_fails() {
+        let mut png = PngEncoder.encode(&Canvas::new(2, 2));
+        png[20] ^= 0xff;
+
+        assert!(matches!(
+            PngDecoder.decode(&png),
+            Err(RustracerError::InvalidImage(_))
+        ));
+        assert!(PngDecoder.decode(b"P3 1 1 255 0 0 0").is_err());
+    }
+
+    #[test]
+    fn decoding_empty_or_huge_png_fails() {
+        for (width, height) in [(0u32, 0u32), (4_000_000_000, 4_000_000_000)] {
+            let mut png = SIGNATURE.to_vec();
+            let mut ihdr = Vec::new();
+            ihdr.extend_from_slice(&width.to_be_bytes());
+            ihdr.extend_from_slice(&height.to_be_bytes());
+            ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+            write_chunk(&mut png, b"IHDR", &ihdr);
+            write_chunk(&mut png, b"IEND", &[]);
+
+            assert!(matches!(
+                PngDecoder.decode(&png),
+                Err(RustracerError::InvalidImage(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+use core::ops::{Add, Sub};
+
+/// A position in space, equivalent to a tuple with `w = 1`.
+#[derive(Debug, Copy, Clone)]
+pub struct Point {
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+}
+
+impl Point {
+    pub const fn new(x: Float, y: Float, z: Float) -> Self {
+        Self { x, y, z }
+    }
+
+    pub const fn origin() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        is_approx(self.x, other.x, None)
+            && is_approx(self.y, other.y, None)
+            && is_approx(self.z, other.z, None)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl From<Point> for Tuple {
+    fn from(p: Point) -> Self {
+        Tuple::from_point(p.x, p.y, p.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: point() creates tuples with w=1
+    //  Given p ← point(4, -4, 3)
+    //   Then p = tuple(4, -4, 3, 1)
+    #[test]
+    fn point_converts_to_tuple_with_w_1() {
+        let p = Point::new(4.0, -4.0, 3.0);
+        let expected = Tuple::new(4.0, -4.0, 3.0, 1.0);
+
+        assert_eq!(Tuple::from(p), expected);
+    }
+
+    // Scenario: Subtracting two points
+    //  Given p1 ← point(3, 2, 1)
+    //    And p2 ← point(5, 6, 7)
+    //   Then p1 - p2 = vector(-2, -4, -6)
+    #[test]
+    fn subtracting_two_points_gives_vector() {
+        let p1 = Point::new(3.0, 2.0, 1.0);
+        let p2 = Point::new(5.0, 6.0, 7.0);
+        let expected = Vector::new(-2.0, -4.0, -6.0);
+
+        assert_eq!(p1 - p2, expected);
+    }
+
+    // Scenario: Subtracting a vector from a point
+    //  Given p ← point(3, 2, 1)
+    //    And v ← vector(5, 6, 7)
+    //   Then p - v = point(-2, -4, -6)
+    #[test]
+    fn subtracting_vector_from_point_gives_point() {
+        let p = Point::new(3.0, 2.0, 1.0);
+        let v = Vector::new(5.0, 6.0, 7.0);
+        let expected = Point::new(-2.0, -4.0, -6.0);
+
+        assert_eq!(p - v, expected);
+    }
+
+    #[test]
+    fn adding_vector_to_point_gives_point() {
+        let p = Point::new(3.0, -2.0, 5.0);
+        let v = Vector::new(-2.0, 3.0, 1.0);
+        let expected = Point::new(1.0, 1.0, 6.0);
+
+        assert_eq!(p + v, expected);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub const fn new(origin: Point, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Point reached after travelling `t` times the direction from the origin.
+    pub fn position(&self, t: Float) -> Point {
+        self.origin + self.direction * t
+    }
+
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        Self::new(*m * self.origin, *m * self.direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: Creating and querying a ray
+    //  Given origin ← point(1, 2, 3)
+    //    And direction ← vector(4, 5, 6)
+    //  When r ← ray(origin, direction)
+    //  Then r.origin = origin
+    //    And r.direction = direction
+    #[test]
+    fn creating_and_querying_ray() {
+        let origin = Point::new(1.0, 2.0, 3.0);
+        let direction = Vector::new(4.0, 5.0, 6.0);
+        let r = Ray::new(origin, direction);
+
+        assert_eq!(r.origin, origin);
+        assert_eq!(r.direction, direction);
+    }
+
+    // Scenario: Computing a point from a distance
+    //  Given r ← ray(point(2, 3, 4), vector(1, 0, 0))
+    //  Then position(r, 0) = point(2, 3, 4)
+    //    And position(r, 1) = point(3, 3, 4)
+    //    And position(r, -1) = point(1, 3, 4)
+    //    And position(r, 2.5) = point(4.5, 3, 4)
+    #[test]
+    fn computing_point_from_distance() {
+        let r = Ray::new(Point::new(2.0, 3.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+
+        assert_eq!(r.position(0.0), Point::new(2.0, 3.0, 4.0));
+        assert_eq!(r.position(1.0), Point::new(3.0, 3.0, 4.0));
+        assert_eq!(r.position(-1.0), Point::new(1.0, 3.0, 4.0));
+        assert_eq!(r.position(2.5), Point::new(4.5, 3.0, 4.0));
+    }
+
+    // Scenario: Translating a ray
+    //  Given r ← ray(point(1, 2, 3), vector(0, 1, 0))
+    //    And m ← translation(3, 4, 5)
+    //  When r2 ← transform(r, m)
+    //  Then r2.origin = point(4, 6, 8)
+    //    And r2.direction = vector(0, 1, 0)
+    #[test]
+    fn translating_ray() {
+        let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let m = Matrix4::translation(3.0, 4.0, 5.0);
+        let r2 = r.transform(&m);
+
+        assert_eq!(r2.origin, Point::new(4.0, 6.0, 8.0));
+        assert_eq!(r2.direction, Vector::new(0.0, 1.0, 0.0));
+    }
+
+    // Scenario: Scaling a ray
+    //  Given r ← ray(point(1, 2, 3), vector(0, 1, 0))
+    //    And m ← scaling(2, 3, 4)
+    //  When r2 ← transform(r, m)
+    //  Then r2.origin = point(2, 6, 12)
+    //    And r2.direction = vector(0, 3, 0)
+    #[test]
+    fn scaling_ray() {
+        let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let m = Matrix4::scaling(2.0, 3.0, 4.0);
+        let r2 = r.transform(&m);
+
+        assert_eq!(r2.origin, Point::new(2.0, 6.0, 12.0));
+        assert_eq!(r2.direction, Vector::new(0.0, 3.0, 0.0));
+    }
+}
@@ -0,0 +1,1585 @@
+use crate::prelude::*;
+use crate::yaml::{self, Value};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A world and the camera to render it with, as described by a scene file.
+///
+/// Scene files use the YAML format of the Ray Tracer Challenge bonus chapters: a list of
+/// items, each either adding something to the scene or defining a reusable value.
+///
+/// ```yaml
+/// - add: camera
+///   width: 100
+///   height: 50
+///   field-of-view: 1.047
+///   from: [0, 1.5, -5]
+///   to: [0, 1, 0]
+///   up: [0, 1, 0]
+///
+/// - add: light
+///   at: [-10, 10, -10]
+///   intensity: [1, 1, 1]
+///
+/// - define: red
+///   value:
+///     color: [1, 0.2, 0.2]
+///     specular: 0.3
+///
+/// - add: sphere
+///   material: red
+///   transform:
+///     - [scale, 0.5, 0.5, 0.5]
+///     - [translate, 0, 1, 0]
+/// ```
+///
+/// The camera may also take an `aperture` and a `focal-distance` to blur what is out of
+/// focus.
+///
+/// A `background` is either a single `color` or a sky fading from its `horizon` color to
+/// its `zenith` color, with an optional `ground` color below the horizon.
+///
+/// A `fog` of some `color` hides distant objects, either thickening evenly from `start`
+/// (0 by default) to `end` or exponentially with a `density`.
+///
+/// Lights are either `light`, a point light, or `spotlight`, which also takes a point it
+/// is aimed `to` and the `inner-angle` and `outer-angle` of its cone, with the inner
+/// angle positive and no larger than the outer one. Both accept an `attenuation` of
+/// `[constant, linear, quadratic]`.
+///
+/// Shapes are `sphere`, `plane`, `cube`, `cylinder` and `cone` (with optional `min`, `max`
+/// and `closed`), `triangle` (`p1`, `p2`, `p3`), `group` (`children`), `csg`
+/// (`operation`, `left`, `right`) and `obj` (`file`, relative to the scene file). All take
+/// an optional `material` and `transform`; the material of a group, CSG or OBJ file is
+/// used by the shapes inside it that have none of their own. Large groups and OBJ files
+/// are divided into a hierarchy of bounding boxes so that rays skip most of their shapes.
+///
+/// A shape's `visibility` hides it from some rays with `camera`, `shadows`, `reflections`
+/// and `refractions` flags, all true by default, e.g. for a light blocker seen only by
+/// shadow rays. On a group, CSG or OBJ file it applies to every shape inside.
+///
+/// Transforms are lists of `[translate, x, y, z]`, `[scale, x, y, z]`, `[rotate-x, r]`,
+/// `[rotate-y, r]`, `[rotate-z, r]` and `[shear, xy, xz, yx, yz, zx, zy]`, applied in
+/// order. Materials accept `color`, `ambient`, `diffuse`, `specular`, `shininess`,
+/// `reflective`, `transparency`, `refractive-index`, `absorption` (a color) and a
+/// `pattern` with a `type` among `stripes`, `gradient`, `rings` and `checkers`, two
+/// `colors` and a `transform`.
+///
+/// A `normal-perturb` fakes bumps on the surface, either `ripples` with an `amplitude`
+/// and a `frequency` or `noise` with an `amplitude` and a `scale`.
+///
+/// A pattern of `type: map` wraps a `uv_pattern` around the shape with a `spherical`,
+/// `planar`, `cylindrical` or `cube` `mapping`. UV patterns are `checkers` (with a
+/// `width`, a `height` and two `colors`), `align_check` (with `main`, `ul`, `ur`, `bl`
+/// and `br` colors) or an `image` read from a PPM or PNG `file`. A `cube` mapping may
+/// instead take a UV pattern for each of its `left`, `front`, `right`, `back`, `up` and
+/// `down` faces.
+///
+/// Defined values are used by name wherever a material, a transform, a transform step
+/// or a shape is expected. A definition can `extend` another one to add to its keys.
+#[derive(Debug)]
+pub struct Scene {
+    pub world: World,
+    pub camera: Camera,
+}
+
+impl Scene {
+    /// Reads a scene, looking for OBJ files relative to the working directory.
+    pub fn parse(input: &str) -> Result<Self> {
+        Builder::new().build(&yaml::parse(input)?)
+    }
+
+    /// Reads the scene file at `path`, looking for OBJ files relative to its directory.
+    #[cfg(feature = "std")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut builder = Builder::new();
+        builder.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        builder.build(&yaml::parse(&fs::read_to_string(path)?)?)
+    }
+}
+
+/// Groups with at least this many children, including those read from OBJ files, are
+/// divided into a hierarchy of bounding boxes once the scene is built.
+const DIVIDE_THRESHOLD: usize = 8;
+
+fn invalid(reason: String) -> RustracerError {
+    RustracerError::InvalidScene(reason)
+}
+
+struct Builder {
+    defines: Vec<(String, Value)>,
+    camera: Option<Camera>,
+    world: World,
+    /// Names of the definitions being expanded, innermost last.
+    expanding: RefCell<Vec<String>>,
+    #[cfg(feature = "std")]
+    base_dir: PathBuf,
+}
+
+impl Builder {
+    fn new() -> Self {
+        Self {
+            defines: Vec::new(),
+            camera: None,
+            world: World::new(),
+            expanding: RefCell::new(Vec::new()),
+            #[cfg(feature = "std")]
+            base_dir: PathBuf::new(),
+        }
+    }
+
+    fn build(mut self, root: &Value) -> Result<Scene> {
+        let items = match root {
+            Value::Sequence(items) => items,
+            _ => return Err(invalid("a scene must be a list of items".to_string())),
+        };
+
+        for item in items {
+            if let Some(name) = item.get("define") {
+                self.define(name, item)?;
+            } else if let Some(kind) = item.get("add") {
+                match as_str(kind, "add")? {
+                    "camera" => self.add_camera(item)?,
+                    "light" => self.add_light(item)?,
+                    "spotlight" => self.add_spotlight(item)?,
+                    "fog" => self.add_fog(item)?,
+                    "background" => self.add_background(item)?,
+                    _ => {
+                        let object = self.shape(item, &Material::default())?;
+                        self.world.objects.push(object);
+                    }
+                }
+            } else {
+                return Err(invalid(format!(
+                    "items must either `add` or `define` something, not {}",
+                    item.kind()
+                )));
+            }
+        }
+
+        for object in &mut self.world.objects {
+            object.divide(DIVIDE_THRESHOLD);
+        }
+        let camera = self
+            .camera
+            .ok_or_else(|| invalid("the scene has no camera".to_string()))?;
+        Ok(Scene {
+            world: self.world,
+            camera,
+        })
+    }
+
+    fn define(&mut self, name: &Value, item: &Value) -> Result<()> {
+        check_keys(item, &["define", "extend", "value"], "definitions")?;
+        let name = as_str(name, "define")?.to_string();
+        let value = item
+            .get("value")
+            .ok_or_else(|| invalid(format!("definition `{}` has no value", name)))?;
+
+        let value = match item.get("extend") {
+            Some(base) => {
+                let base = as_str(base, "extend")?;
+                match (self.lookup(base)?, value) {
+                    (Value::Mapping(base), Value::Mapping(extra)) => {
+                        let mut entries = base.clone();
+                        for (key, value) in extra {
+                            entries.retain(|(k, _)| k != key);
+                            entries.push((key.clone(), value.clone()));
+                        }
+                        Value::Mapping(entries)
+                    }
+                    _ => {
+                        return Err(invalid(format!(
+                            "`{}` can only extend a mapping with a mapping",
+                            name
+                        )))
+                    }
+                }
+            }
+            None => value.clone(),
+        };
+
+        self.defines.retain(|(n, _)| *n != name);
+        self.defines.push((name, value));
+        Ok(())
+    }
+
+    fn lookup(&self, name: &str) -> Result<&Value> {
+        self.defines
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| invalid(format!("`{}` is not defined", name)))
+    }
+
+    /// The value itself, or the definition it names.
+    fn resolve<'a>(&'a self, value: &'a Value) -> Result<&'a Value> {
+        match value {
+            Value::String(name) => self.lookup(name),
+            _ => Ok(value),
+        }
+    }
+
+    /// Calls `expand` with the definition named `name`, failing if that definition is
+    /// already being expanded, i.e. if it refers to itself.
+    fn expand<T>(&self, name: &str, expand: impl FnOnce(&Value) -> Result<T>) -> Result<T> {
+        if self.expanding.borrow().iter().any(|n| n == name) {
+            return Err(invalid(format!("recursive definition of `{}`", name)));
+        }
+        let value = self.lookup(name)?;
+        self.expanding.borrow_mut().push(name.to_string());
+        let result = expand(value);
+        self.expanding.borrow_mut().pop();
+        result
+    }
+
+    fn add_camera(&mut self, item: &Value) -> Result<()> {
+        check_keys(
+            item,
+            &[
+                "add",
+                "width",
+                "height",
+                "field-of-view",
+                "from",
+                "to",
+                "up",
+                "aperture",
+                "focal-distance",
+            ],
+            "cameras",
+        )?;
+        let mut camera = Camera::new(
+            as_size(required(item, "width")?, "width")?,
+            as_size(required(item, "height")?, "height")?,
+            as_float(required(item, "field-of-view")?, "field-of-view")?,
+        );
+        let [fx, fy, fz] = as_triple(required(item, "from")?, "from")?;
+        let [tx, ty, tz] = as_triple(required(item, "to")?, "to")?;
+        let [ux, uy, uz] = as_triple(required(item, "up")?, "up")?;
+        let (from, to, up) = (
+            Point::new(fx, fy, fz),
+            Point::new(tx, ty, tz),
+            Vector::new(ux, uy, uz),
+        );
+        if from == to {
+            return Err(invalid(
+                "the camera must look `to` somewhere other than `from`".to_string(),
+            ));
+        }
+        if (to - from).cross(&up).magnitude() == 0.0 {
+            return Err(invalid(
+                "the camera's `up` must not point along its view".to_string(),
+            ));
+        }
+        camera
+            .try_set_transform(Matrix4::view_transform(from, to, up))
+            .map_err(|_| invalid("the camera's view must be invertible".to_string()))?;
+
+        if let Some(aperture) = item.get("aperture") {
+            let aperture = as_float(aperture, "aperture")?;
+            let focal_distance = as_float(required(item, "focal-distance")?, "focal-distance")?;
+            if aperture < 0.0 || focal_distance <= 0.0 {
+                return Err(invalid(
+                    "the aperture and focal distance must be positive".to_string(),
+                ));
+            }
+            camera.set_depth_of_field(aperture, focal_distance);
+        }
+
+        self.camera = Some(camera);
+        Ok(())
+    }
+
+    fn add_light(&mut self, item: &Value) -> Result<()> {
+        check_keys(item, &["add", "at", "intensity", "attenuation"], "lights")?;
+        let [x, y, z] = as_triple(required(item, "at")?, "at")?;
+        let intensity = as_color(required(item, "intensity")?, "intensity")?;
+
+        self.world.add_light(
+            PointLight::new(Point::new(x, y, z), intensity).with_attenuation(attenuation(item)?),
+        );
+        Ok(())
+    }
+
+    fn add_spotlight(&mut self, item: &Value) -> Result<()> {
+        check_keys(
+            item,
+            &[
+                "add",
+                "at",
+                "to",
+                "inner-angle",
+                "outer-angle",
+                "intensity",
+                "attenuation",
+            ],
+            "spotlights",
+        )?;
+        let [x, y, z] = as_triple(required(item, "at")?, "at")?;
+        let [tx, ty, tz] = as_triple(required(item, "to")?, "to")?;
+        let (at, to) = (Point::new(x, y, z), Point::new(tx, ty, tz));
+        let direction = (to - at)
+            .try_normalize()
+            .map_err(|_| invalid("a spotlight cannot point at itself".to_string()))?;
+        let inner_angle = as_float(required(item, "inner-angle")?, "inner-angle")?;
+        let outer_angle = as_float(required(item, "outer-angle")?, "outer-angle")?;
+        if inner_angle <= 0.0 || inner_angle > outer_angle {
+            return Err(invalid(
+                "a spotlight's `inner-angle` must be positive and at most its `outer-angle`"
+                    .to_string(),
+            ));
+        }
+
+        self.world.add_light(
+            SpotLight::new(
+                at,
+                direction,
+                inner_angle,
+                outer_angle,
+                as_color(required(item, "intensity")?, "intensity")?,
+            )
+            .with_attenuation(attenuation(item)?),
+        );
+        Ok(())
+    }
+
+    fn add_background(&mut self, item: &Value) -> Result<()> {
+        check_keys(
+            item,
+            &["add", "color", "horizon", "zenith", "ground"],
+            "backgrounds",
+        )?;
+        let background = match item.get("color") {
+            Some(color) if item.get("horizon").is_none() && item.get("zenith").is_none() => {
+                Background::Solid(as_color(color, "color")?)
+            }
+            Some(_) => {
+                return Err(invalid(
+                    "a background takes either a `color` or a `horizon` and a `zenith`".to_string(),
+                ))
+            }
+            None => Background::Gradient {
+                horizon: as_color(required(item, "horizon")?, "horizon")?,
+                zenith: as_color(required(item, "zenith")?, "zenith")?,
+                ground: match item.get("ground") {
+                    Some(ground) => Some(as_color(ground, "ground")?),
+                    None => None,
+                },
+            },
+        };
+
+        self.world.background = background;
+        Ok(())
+    }
+
+    fn add_fog(&mut self, item: &Value) -> Result<()> {
+        check_keys(item, &["add", "color", "density", "start", "end"], "fog")?;
+        let color = as_color(required(item, "color")?, "color")?;
+        let fog = match item.get("density") {
+            Some(density) => {
+                let density = as_float(density, "density")?;
+                if item.get("start").is_some() || item.get("end").is_some() {
+                    return Err(invalid(
+                        "fog takes either a `density` or a `start` and an `end`".to_string(),
+                    ));
+                }
+                if density < 0.0 {
+                    return Err(invalid("the density of fog cannot be negative".to_string()));
+                }
+                Fog::exponential(color, density)
+            }
+            None => {
+                let start = optional_float(item, "start", 0.0)?;
+                let end = as_float(required(item, "end")?, "end")?;
+                if start < 0.0 || end <= start {
+                    return Err(invalid(
+                        "fog must start in front of the eye and end past its start".to_string(),
+                    ));
+                }
+                Fog::linear(color, start, end)
+            }
+        };
+
+        self.world.fog = Some(fog);
+        Ok(())
+    }
+
+    /// Builds the shape described by `item`, giving it `inherited` unless it has a
+    /// material of its own.
+    fn shape(&self, item: &Value, inherited: &Material) -> Result<Box<dyn Shape>> {
+        if let Value::String(name) = item {
+            return self.expand(name, |item| self.shape(item, inherited));
+        }
+        let kind = as_str(required(item, "add")?, "add")?;
+        let material = match item.get("material") {
+            Some(material) => self.material(material)?,
+            None => inherited.clone(),
+        };
+        let transform = match item.get("transform") {
+            Some(transform) => self.transform(transform)?,
+            None => Matrix4::identity(),
+        };
+
+        let mut shape: Box<dyn Shape> = match kind {
+            "sphere" | "plane" | "cube" => {
+                check_keys(item, &["add", "material", "transform", "visibility"], kind)?;
+                match kind {
+                    "sphere" => Box::new(Sphere::new()),
+                    "plane" => Box::new(Plane::new()),
+                    _ => Box::new(Cube::new()),
+                }
+            }
+            "cylinder" | "cone" => {
+                check_keys(
+                    item,
+                    &[
+                        "add",
+                        "material",
+                        "transform",
+                        "visibility",
+                        "min",
+                        "max",
+                        "closed",
+                    ],
+                    kind,
+                )?;
+                let minimum = optional_float(item, "min", Float::NEG_INFINITY)?;
+                let maximum = optional_float(item, "max", Float::INFINITY)?;
+                let closed = optional_bool(item, "closed", false)?;
+                if kind == "cylinder" {
+                    Box::new(Cylinder::truncated(minimum, maximum, closed))
+                } else {
+                    Box::new(Cone::truncated(minimum, maximum, closed))
+                }
+            }
+            "triangle" => {
+                check_keys(
+                    item,
+                    &[
+                        "add",
+                        "material",
+                        "transform",
+                        "visibility",
+                        "p1",
+                        "p2",
+                        "p3",
+                    ],
+                    kind,
+                )?;
+                let point =
+                    |key| as_triple(required(item, key)?, key).map(|[x, y, z]| Point::new(x, y, z));
+                Box::new(Triangle::new(point("p1")?, point("p2")?, point("p3")?))
+            }
+            "group" => {
+                check_keys(
+                    item,
+                    &["add", "material", "transform", "visibility", "children"],
+                    kind,
+                )?;
+                let mut group = Group::new();
+                match item.get("children") {
+                    Some(Value::Sequence(children)) => {
+                        for child in children {
+                            group.add_boxed_child(self.shape(child, &material)?);
+                        }
+                    }
+                    Some(other) => return Err(expected("children", "a list", other)),
+                    None => {}
+                }
+                Box::new(group)
+            }
+            "csg" => {
+                check_keys(
+                    item,
+                    &[
+                        "add",
+                        "material",
+                        "transform",
+                        "visibility",
+                        "operation",
+                        "left",
+                        "right",
+                    ],
+                    kind,
+                )?;
+                let operation = match as_str(required(item, "operation")?, "operation")? {
+                    "union" => CsgOperation::Union,
+                    "intersection" => CsgOperation::Intersection,
+                    "difference" => CsgOperation::Difference,
+                    other => return Err(invalid(format!("unknown CSG operation `{}`", other))),
+                };
+                Box::new(Csg::from_boxed(
+                    operation,
+                    self.shape(required(item, "left")?, &material)?,
+                    self.shape(required(item, "right")?, &material)?,
+                ))
+            }
+            "obj" => {
+                check_keys(
+                    item,
+                    &["add", "material", "transform", "visibility", "file"],
+                    kind,
+                )?;
+                let file = as_str(required(item, "file")?, "file")?;
+                Box::new(self.load_obj(file)?.to_group_with_material(&material))
+            }
+            // A shape defined earlier, with this item's keys taking precedence.
+            name => {
+                return self.expand(name, |definition| {
+                    let mut entries = match definition {
+                        Value::Mapping(entries) if entries.iter().any(|(k, _)| k == "add") => {
+                            entries.clone()
+                        }
+                        _ => return Err(invalid(format!("unknown shape `{}`", name))),
+                    };
+                    if let Value::Mapping(extra) = item {
+                        for (key, value) in extra.iter().filter(|(k, _)| k != "add") {
+                            entries.retain(|(k, _)| k != key);
+                            entries.push((key.clone(), value.clone()));
+                        }
+                    }
+                    self.shape(&Value::Mapping(entries), inherited)
+                });
+            }
+        };
+
+        if !matches!(kind, "group" | "csg" | "obj") {
+            shape.set_material(material);
+        }
+        if let Some(visibility) = visibility(item)? {
+            shape.set_visibility(visibility);
+        }
+        shape
+            .try_set_transform(transform)
+            .map_err(|_| invalid("transforms must be invertible".to_string()))?;
+        Ok(shape)
+    }
+
+    #[cfg(feature = "std")]
+    fn load_obj(&self, file: &str) -> Result<ObjFile> {
+        ObjFile::load(self.base_dir.join(file))
+    }
+
+    #[cfg(feature = "std")]
+    fn load_image(&self, file: &str) -> Result<Canvas> {
+        let path = self.base_dir.join(file);
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Canvas::load_ppm(path),
+            #[cfg(feature = "png")]
+            Some("png") => Canvas::load_png(path),
+            _ => Err(invalid(format!(
+                "cannot read `{}`: unknown image format",
+                file
+            ))),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn load_image(&self, file: &str) -> Result<Canvas> {
+        Err(invalid(format!(
+            "cannot read `{}`: images need the `std` feature",
+            file
+        )))
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn load_obj(&self, file: &str) -> Result<ObjFile> {
+        Err(invalid(format!(
+            "cannot read `{}`: OBJ files need the `std` feature",
+            file
+        )))
+    }
+
+    fn material(&self, value: &Value) -> Result<Material> {
+        let value = self.resolve(value)?;
+        check_keys(
+            value,
+            &[
+                "color",
+                "ambient",
+                "diffuse",
+                "specular",
+                "shininess",
+                "reflective",
+                "transparency",
+                "refractive-index",
+                "absorption",
+                "pattern",
+                "normal-perturb",
+            ],
+            "materials",
+        )?;
+
+        let defaults = Material::default();
+        Ok(Material {
+            color: match value.get("color") {
+                Some(color) => as_color(color, "color")?,
+                None => defaults.color,
+            },
+            pattern: match value.get("pattern") {
+                Some(pattern) => Some(self.pattern(pattern)?),
+                None => None,
+            },
+            normal_perturb: match value.get("normal-perturb") {
+                Some(perturb) => Some(self.normal_perturb(perturb)?),
+                None => None,
+            },
+            ambient: optional_float(value, "ambient", defaults.ambient)?,
+            diffuse: optional_float(value, "diffuse", defaults.diffuse)?,
+            specular: optional_float(value, "specular", defaults.specular)?,
+            shininess: optional_float(value, "shininess", defaults.shininess)?,
+            reflective: optional_float(value, "reflective", defaults.reflective)?,
+            transparency: optional_float(value, "transparency", defaults.transparency)?,
+            refractive_index: optional_float(value, "refractive-index", defaults.refractive_index)?,
+            absorption: match value.get("absorption") {
+                Some(absorption) => as_color(absorption, "absorption")?,
+                None => defaults.absorption,
+            },
+        })
+    }
+
+    fn pattern(&self, value: &Value) -> Result<Arc<dyn Pattern>> {
+        let value = self.resolve(value)?;
+        let kind = as_str(required(value, "type")?, "type")?;
+        let transform = match value.get("transform") {
+            Some(transform) => self.transform(transform)?,
+            None => Matrix4::identity(),
+        };
+        if kind == "map" {
+            return self.texture_map(value, transform);
+        }
+
+        check_keys(value, &["type", "colors", "transform"], "patterns")?;
+        let (a, b) = two_colors(value)?;
+        Ok(match kind {
+            "stripes" => Arc::new(StripePattern::new(a, b).with_transform(transform)),
+            "gradient" => Arc::new(GradientPattern::new(a, b).with_transform(transform)),
+            "rings" => Arc::new(RingPattern::new(a, b).with_transform(transform)),
+            "checkers" => Arc::new(CheckersPattern::new(a, b).with_transform(transform)),
+            other => return Err(invalid(format!("unknown pattern `{}`", other))),
+        })
+    }
+
+    fn normal_perturb(&self, value: &Value) -> Result<Arc<dyn NormalPerturb>> {
+        let value = self.resolve(value)?;
+        let amplitude = |value| as_float(required(value, "amplitude")?, "amplitude");
+        Ok(match as_str(required(value, "type")?, "type")? {
+            "ripples" => {
+                check_keys(value, &["type", "amplitude", "frequency"], "ripples")?;
+                let frequency = as_float(required(value, "frequency")?, "frequency")?;
+                Arc::new(Ripples::new(amplitude(value)?, frequency))
+            }
+            "noise" => {
+                check_keys(value, &["type", "amplitude", "scale"], "noise")?;
+                let scale = as_float(required(value, "scale")?, "scale")?;
+                if scale <= 0.0 {
+                    return Err(invalid("the scale of noise must be positive".to_string()));
+                }
+                Arc::new(NoiseBump::new(amplitude(value)?, scale))
+            }
+            other => return Err(invalid(format!("unknown normal perturbation `{}`", other))),
+        })
+    }
+
+    fn texture_map(&self, value: &Value, transform: Matrix4) -> Result<Arc<dyn Pattern>> {
+        let mapping = as_str(required(value, "mapping")?, "mapping")?;
+        // Without a single pattern, a cube gets one per face.
+        if mapping == "cube" && value.get("uv_pattern").is_none() {
+            check_keys(
+                value,
+                &[
+                    "type",
+                    "mapping",
+                    "transform",
+                    "left",
+                    "front",
+                    "right",
+                    "back",
+                    "up",
+                    "down",
+                ],
+                "cube maps",
+            )?;
+            let face = |key| self.uv_pattern(required(value, key)?);
+            let faces = [
+                face("left")?,
+                face("front")?,
+                face("right")?,
+                face("back")?,
+                face("up")?,
+                face("down")?,
+            ];
+            return Ok(Arc::new(CubeMap::new(faces).with_transform(transform)));
+        }
+
+        check_keys(
+            value,
+            &["type", "mapping", "transform", "uv_pattern"],
+            "texture maps",
+        )?;
+        let mapping = match mapping {
+            "spherical" => UvMapping::Spherical,
+            "planar" => UvMapping::Planar,
+            "cylindrical" => UvMapping::Cylindrical,
+            "cube" => UvMapping::Cubic,
+            other => return Err(invalid(format!("unknown mapping `{}`", other))),
+        };
+        let uv_pattern = self.uv_pattern(required(value, "uv_pattern")?)?;
+        Ok(Arc::new(
+            TextureMap::from_boxed(uv_pattern, mapping).with_transform(transform),
+        ))
+    }
+
+    fn uv_pattern(&self, value: &Value) -> Result<Box<dyn UvPattern>> {
+        let value = self.resolve(value)?;
+        Ok(match as_str(required(value, "type")?, "type")? {
+            "checkers" => {
+                check_keys(value, &["type", "width", "height", "colors"], "checkers")?;
+                let (a, b) = two_colors(value)?;
+                Box::new(UvCheckers::new(
+                    as_float(required(value, "width")?, "width")?,
+                    as_float(required(value, "height")?, "height")?,
+                    a,
+                    b,
+                ))
+            }
+            "align_check" => {
+                check_keys(value, &["type", "colors"], "align checks")?;
+                let colors = required(value, "colors")?;
+                check_keys(colors, &["main", "ul", "ur", "bl", "br"], "align checks")?;
+                let color = |key| as_color(required(colors, key)?, key);
+                Box::new(UvAlignCheck::new(
+                    color("main")?,
+                    color("ul")?,
+                    color("ur")?,
+                    color("bl")?,
+                    color("br")?,
+                ))
+            }
+            "image" => {
+                check_keys(value, &["type", "file"], "images")?;
+                let file = as_str(required(value, "file")?, "file")?;
+                Box::new(UvImage::new(self.load_image(file)?))
+            }
+            other => return Err(invalid(format!("unknown UV pattern `{}`", other))),
+        })
+    }
+
+    fn transform(&self, value: &Value) -> Result<Matrix4> {
+        let mut transform = Transform::identity();
+        self.add_transform_steps(&mut transform, value)?;
+        transform
+            .matrix()
+            .inverse()
+            .map_err(|_| invalid("transforms must be invertible".to_string()))?;
+        Ok(transform.into())
+    }
+
+    fn add_transform_steps(&self, transform: &mut Transform, value: &Value) -> Result<()> {
+        let steps = match value {
+            Value::String(name) => {
+                return self.expand(name, |value| self.add_transform_steps(transform, value))
+            }
+            Value::Sequence(steps) => steps,
+            other => return Err(expected("transform", "a list of steps", other)),
+        };
+
+        for step in steps {
+            let step = match step {
+                // A defined transform, whose steps are spliced in.
+                Value::String(_) => {
+                    self.add_transform_steps(transform, step)?;
+                    continue;
+                }
+                Value::Sequence(step) => step,
+                other => return Err(expected("transform", "a list of steps", other)),
+            };
+
+            let (operation, arguments) = match step.split_first() {
+                Some((operation, arguments)) => (as_str(operation, "transform")?, arguments),
+                None => return Err(invalid("empty transform step".to_string())),
+            };
+            let arguments = arguments
+                .iter()
+                .map(|argument| as_float(argument, operation))
+                .collect::<Result<Vec<Float>>>()?;
+
+            let matrix = match (operation, arguments.as_slice()) {
+                ("translate", &[x, y, z]) => Matrix4::translation(x, y, z),
+                ("scale", &[x, y, z]) => Matrix4::scaling(x, y, z),
+                ("rotate-x", &[r]) => Matrix4::rotation_x(r),
+                ("rotate-y", &[r]) => Matrix4::rotation_y(r),
+                ("rotate-z", &[r]) => Matrix4::rotation_z(r),
+                ("shear", &[xy, xz, yx, yz, zx, zy]) => Matrix4::shearing(xy, xz, yx, yz, zx, zy),
+                ("translate" | "scale" | "rotate-x" | "rotate-y" | "rotate-z" | "shear", _) => {
+                    return Err(invalid(format!(
+                        "wrong number of arguments for `{}`",
+                        operation
+                    )))
+                }
+                _ => return Err(invalid(format!("unknown transform `{}`", operation))),
+            };
+            *transform = transform.then(matrix);
+        }
+
+        Ok(())
+    }
+}
+
+/// The optional `[constant, linear, quadratic]` falloff of a light.
+fn attenuation(item: &Value) -> Result<Attenuation> {
+    match item.get("attenuation") {
+        Some(value) => as_triple(value, "attenuation").map(|[c, l, q]| Attenuation::new(c, l, q)),
+        None => Ok(Attenuation::NONE),
+    }
+}
+
+/// The optional `visibility` of a shape, whose `camera`, `shadows`, `reflections` and
+/// `refractions` flags are all true by default.
+fn visibility(item: &Value) -> Result<Option<Visibility>> {
+    let value = match item.get("visibility") {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    check_keys(
+        value,
+        &["camera", "shadows", "reflections", "refractions"],
+        "visibility",
+    )?;
+    Ok(Some(Visibility {
+        camera: optional_bool(value, "camera", true)?,
+        shadows: optional_bool(value, "shadows", true)?,
+        reflections: optional_bool(value, "reflections", true)?,
+        refractions: optional_bool(value, "refractions", true)?,
+    }))
+}
+
+fn two_colors(value: &Value) -> Result<(Color, Color)> {
+    match required(value, "colors")? {
+        Value::Sequence(colors) if colors.len() == 2 => Ok((
+            as_color(&colors[0], "colors")?,
+            as_color(&colors[1], "colors")?,
+        )),
+        other => Err(expected("colors", "a list of two colors", other)),
+    }
+}
+
+fn check_keys(value: &Value, allowed: &[&str], what: &str) -> Result<()> {
+    match value {
+        Value::Mapping(entries) => {
+            match entries.iter().find(|(k, _)| !allowed.contains(&k.as_str())) {
+                Some((key, _)) => Err(invalid(format!("unknown key `{}` for {}", key, what))),
+                None => Ok(()),
+            }
+        }
+        other => Err(invalid(format!(
+            "expected a mapping for {}, not {}",
+            what,
+            other.kind()
+        ))),
+    }
+}
+
+fn required<'a>(value: &'a Value, key: &str) -> Result<&'a Value> {
+    value
+        .get(key)
+        .ok_or_else(|| invalid(format!("missing `{}`", key)))
+}
+
+fn expected(key: &str, what: &str, value: &Value) -> RustracerError {
+    invalid(format!(
+        "expected {} for `{}`, not {}",
+        what,
+        key,
+        value.kind()
+    ))
+}
+
+fn as_str<'a>(value: &'a Value, key: &str) -> Result<&'a str> {
+    match value {
+        Value::String(s) => Ok(s),
+        other => Err(expected(key, "a name", other)),
+    }
+}
+
+fn as_float(value: &Value, key: &str) -> Result<Float> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        other => Err(expected(key, "a number", other)),
+    }
+}
+
+fn optional_float(value: &Value, key: &str, default: Float) -> Result<Float> {
+    value.get(key).map_or(Ok(default), |v| as_float(v, key))
+}
+
+fn optional_bool(value: &Value, key: &str, default: bool) -> Result<bool> {
+    match value.get(key) {
+        Some(Value::Bool(b)) => Ok(*b),
+        Some(other) => Err(expected(key, "a boolean", other)),
+        None => Ok(default),
+    }
+}
+
+fn as_size(value: &Value, key: &str) -> Result<usize> {
+    match value {
+        Value::Number(n) if *n >= 1.0 && n.floor() == *n => Ok(*n as usize),
+        other => Err(expected(key, "a positive whole number", other)),
+    }
+}
+
+fn as_triple(value: &Value, key: &str) -> Result<[Float; 3]> {
+    match value {
+        Value::Sequence(items) if items.len() == 3 => Ok([
+            as_float(&items[0], key)?,
+            as_float(&items[1], key)?,
+            as_float(&items[2], key)?,
+        ]),
+        other => Err(expected(key, "a list of three numbers", other)),
+    }
+}
+
+fn as_color(value: &Value, key: &str) -> Result<Color> {
+    as_triple(value, key).map(|[r, g, b]| Color::new(r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::FRAC_PI_2;
+    use crate::prelude::*;
+    use alloc::format;
+    use alloc::string::String;
+
+    const CAMERA: &str = "
+- add: camera
+  width: 100
+  height: 50
+  field-of-view: 0.785
+  from: [0, 0, -5]
+  to: [0, 0, 0]
+  up: [0, 1, 0]
+";
+
+    fn scene(items: &str) -> Result<Scene> {
+        Scene::parse(&format!("{}{}", CAMERA, items))
+    }
+
+    fn invalid_reason(items: &str) -> String {
+        match scene(items) {
+            Err(RustracerError::InvalidScene(reason)) => reason,
+            other => panic!("expected an invalid scene, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reading_camera_and_light() {
+        let s = scene(
+            "
+- add: light
+  at: [50, 100, -50]
+  intensity: [1, 1, 1]
+- add: spotlight
+  at: [0, 5, 0]
+  to: [0, 0, 0]
+  inner-angle: 0.3
+  outer-angle: 0.5
+  intensity: [1, 1, 0.8]
+  attenuation: [1, 0.1, 0.01]",
+        )
+        .unwrap();
+
+        assert_eq!(s.camera.hsize(), 100);
+        assert_eq!(s.camera.vsize(), 50);
+        assert_eq!(s.camera.field_of_view(), 0.785);
+        assert_eq!(s.camera.aperture(), 0.0);
+        assert_eq!(
+            *s.camera.transform(),
+            Matrix4::view_transform(
+                Point::new(0.0, 0.0, -5.0),
+                Point::origin(),
+                Vector::new(0.0, 1.0, 0.0)
+            )
+        );
+        assert_eq!(s.world.lights.len(), 2);
+        assert_eq!(
+            s.world.lights[0].downcast_ref::<PointLight>(),
+            Some(&PointLight::new(
+                Point::new(50.0, 100.0, -50.0),
+                Color::white()
+            ))
+        );
+        assert_eq!(
+            s.world.lights[1].downcast_ref::<SpotLight>(),
+            Some(
+                &SpotLight::new(
+                    Point::new(0.0, 5.0, 0.0),
+                    Vector::new(0.0, -1.0, 0.0),
+                    0.3,
+                    0.5,
+                    Color::new(1.0, 1.0, 0.8)
+                )
+                .with_attenuation(Attenuation::new(1.0, 0.1, 0.01))
+            )
+        );
+    }
+
+    #[test]
+    fn reading_backgrounds() {
+        let sky = scene(
+            "
+- add: background
+  horizon: [1, 1, 1]
+  zenith: [0.2, 0.4, 1]
+  ground: [0.3, 0.2, 0.1]",
+        )
+        .unwrap();
+        assert_eq!(
+            sky.world.background,
+            Background::Gradient {
+                horizon: Color::white(),
+                zenith: Color::new(0.2, 0.4, 1.0),
+                ground: Some(Color::new(0.3, 0.2, 0.1)),
+            }
+        );
+
+        let solid = scene("- add: background\n  color: [0.1, 0.1, 0.1]").unwrap();
+        assert_eq!(
+            solid.world.background,
+            Background::Solid(Color::new(0.1, 0.1, 0.1))
+        );
+        assert_eq!(
+            invalid_reason("- add: background\n  horizon: [1, 1, 1]"),
+            "missing `zenith`"
+        );
+    }
+
+    #[test]
+    fn reading_fog() {
+        let linear = scene("- add: fog\n  color: [0.5, 0.5, 0.5]\n  end: 20").unwrap();
+        assert_eq!(
+            linear.world.fog,
+            Some(Fog::linear(Color::new(0.5, 0.5, 0.5), 0.0, 20.0))
+        );
+
+        let exponential = scene("- add: fog\n  color: [1, 1, 1]\n  density: 0.1").unwrap();
+        assert_eq!(
+            exponential.world.fog,
+            Some(Fog::exponential(Color::white(), 0.1))
+        );
+
+        assert!(scene("").unwrap().world.fog.is_none());
+        assert_eq!(
+            invalid_reason("- add: fog\n  color: [1, 1, 1]\n  start: 5\n  end: 2"),
+            "fog must start in front of the eye and end past its start"
+        );
+    }
+
+    #[test]
+    fn spotlights_with_bad_angles_are_invalid() {
+        let spotlight = |inner: &str, outer: &str| {
+            invalid_reason(&format!(
+                "- add: spotlight\n  at: [0, 5, 0]\n  to: [0, 0, 0]\n  inner-angle: {}\n  \
+                 outer-angle: {}\n  intensity: [1, 1, 1]",
+                inner, outer
+            ))
+        };
+        let reason = "a spotlight's `inner-angle` must be positive and at most its `outer-angle`";
+
+        assert_eq!(spotlight("0.5", "0.3"), reason);
+        assert_eq!(spotlight("0", "0.3"), reason);
+        assert_eq!(spotlight("-0.5", "-0.3"), reason);
+    }
+
+    #[test]
+    fn reading_camera_with_depth_of_field() {
+        let s = Scene::parse(&format!("{}  aperture: 0.1\n  focal-distance: 5\n", CAMERA)).unwrap();
+
+        assert_eq!(s.camera.aperture(), 0.1);
+        assert_eq!(s.camera.focal_distance(), 5.0);
+    }
+
+    #[test]
+    fn reading_shapes_with_materials_and_transforms() {
+        let s = scene(
+            "
+- add: sphere
+  material:
+    color: [1, 0, 0]
+    diffuse: 0.7
+    refractive-index: 1.5
+    absorption: [0.1, 0.2, 0]
+  transform:
+    - [scale, 2, 2, 2]
+    - [translate, 1, 0, 0]
+- add: cylinder
+  min: 0
+  max: 2
+  closed: true
+- add: plane",
+        )
+        .unwrap();
+
+        assert_eq!(s.world.objects.len(), 3);
+        let sphere = s.world.objects[0].downcast_ref::<Sphere>().unwrap();
+        assert_eq!(sphere.material().color, Color::new(1.0, 0.0, 0.0));
+        assert_eq!(sphere.material().diffuse, 0.7);
+        assert_eq!(sphere.material().refractive_index, 1.5);
+        assert_eq!(sphere.material().absorption, Color::new(0.1, 0.2, 0.0));
+        assert_eq!(sphere.material().specular, Material::default().specular);
+        assert_eq!(
+            *sphere.transform(),
+            Matrix4::translation(1.0, 0.0, 0.0) * Matrix4::scaling(2.0, 2.0, 2.0)
+        );
+        let cylinder = s.world.objects[1].downcast_ref::<Cylinder>().unwrap();
+        assert_eq!(
+            (cylinder.minimum, cylinder.maximum, cylinder.closed),
+            (0.0, 2.0, true)
+        );
+        assert!(s.world.objects[2].downcast_ref::<Plane>().is_some());
+    }
+
+    #[test]
+    fn reading_shape_visibility() {
+        let s = scene(
+            "
+- add: sphere
+  visibility:
+    camera: false
+- add: group
+  visibility:
+    shadows: false
+    refractions: false
+  children:
+    - add: cube",
+        )
+        .unwrap();
+
+        let sphere = s.world.objects[0].as_ref();
+        assert_eq!(
+            sphere.visibility(),
+            Visibility {
+                camera: false,
+                ..Visibility::ALL
+            }
+        );
+        let group = s.world.objects[1].downcast_ref::<Group>().unwrap();
+        let hidden = Visibility {
+            camera: true,
+            shadows: false,
+            reflections: true,
+            refractions: false,
+        };
+        assert_eq!(group.visibility(), hidden);
+        assert_eq!(group.children()[0].visibility(), Visibility::ALL);
+        assert_eq!(group.children()[0].world_visibility(), hidden);
+        assert_eq!(
+            invalid_reason("- add: plane\n  visibility:\n    camera: no"),
+            "expected a boolean for `camera`, not a string"
+        );
+    }
+
+    #[test]
+    fn using_definitions() {
+        let s = scene(
+            "
+- define: white-material
+  value:
+    color: [1, 1, 1]
+    diffuse: 0.7
+- define: blue-material
+  extend: white-material
+  value:
+    color: [0, 0, 1]
+- define: standard-transform
+  value:
+    - [translate, 1, -1, 1]
+    - [scale, 0.5, 0.5, 0.5]
+- define: large-object
+  value:
+    - standard-transform
+    - [scale, 4, 4, 4]
+- add: cube
+  material: blue-material
+  transform:
+    - large-object
+    - [rotate-y, 1.5707964]",
+        )
+        .unwrap();
+
+        let cube = s.world.objects[0].as_ref();
+        assert_eq!(cube.material().color, Color::new(0.0, 0.0, 1.0));
+        assert_eq!(cube.material().diffuse, 0.7);
+        assert_eq!(
+            *cube.transform(),
+            Matrix4::rotation_y(FRAC_PI_2)
+                * Matrix4::scaling(4.0, 4.0, 4.0)
+                * Matrix4::scaling(0.5, 0.5, 0.5)
+                * Matrix4::translation(1.0, -1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn reading_patterns() {
+        let s = scene(
+            "
+- add: plane
+  material:
+    pattern:
+      type: checkers
+      colors:
+        - [1, 1, 1]
+        - [0, 0, 0]
+      transform:
+        - [scale, 0.5, 0.5, 0.5]",
+        )
+        .unwrap();
+
+        let plane = s.world.objects[0].as_ref();
+        let pattern = plane.material().pattern.as_ref().unwrap();
+        assert_eq!(*pattern.transform(), Matrix4::scaling(0.5, 0.5, 0.5));
+        assert_eq!(
+            pattern.pattern_at_shape(plane, Point::new(0.75, 0.0, 0.0)),
+            Color::black()
+        );
+    }
+
+    #[test]
+    fn reading_normal_perturbations() {
+        let s = scene(
+            "
+- add: plane
+  material:
+    normal-perturb: { type: ripples, amplitude: 0.1, frequency: 2 }
+- add: sphere
+  material:
+    normal-perturb: { type: noise, amplitude: 0.2, scale: 0.5 }",
+        )
+        .unwrap();
+
+        let normal = Vector::new(0.0, 1.0, 0.0);
+        let p = Point::new(0.3, 0.0, 0.6);
+        let ripples = s.world.objects[0].material().normal_perturb.as_ref();
+        assert_eq!(
+            ripples.unwrap().perturb(p, normal),
+            Ripples::new(0.1, 2.0).perturb(p, normal)
+        );
+        let noise = s.world.objects[1].material().normal_perturb.as_ref();
+        assert_eq!(
+            noise.unwrap().perturb(p, normal),
+            NoiseBump::new(0.2, 0.5).perturb(p, normal)
+        );
+    }
+
+    #[test]
+    fn reading_texture_maps() {
+        let s = scene(
+            "
+- define: check
+  value:
+    type: align_check
+    colors:
+      main: [1, 1, 1]
+      ul: [1, 0, 0]
+      ur: [1, 1, 0]
+      bl: [0, 1, 0]
+      br: [0, 1, 1]
+- add: sphere
+  material:
+    pattern:
+      type: map
+      mapping: spherical
+      uv_pattern:
+        type: checkers
+        width: 16
+        height: 8
+        colors:
+          - [0, 0, 0]
+          - [1, 1, 1]
+- add: cube
+  material:
+    pattern:
+      type: map
+      mapping: cube
+      left: check
+      front: check
+      right: check
+      back: check
+      up: check
+      down:
+        type: checkers
+        width: 2
+        height: 2
+        colors: [[0, 0, 0], [1, 1, 1]]",
+        )
+        .unwrap();
+
+        let sphere = s.world.objects[0].as_ref();
+        let pattern = sphere.material().pattern.as_ref().unwrap();
+        assert_eq!(
+            pattern.pattern_at_shape(sphere, Point::new(0.4315, 0.4670, 0.7719)),
+            Color::white()
+        );
+        let cube = s.world.objects[1].as_ref();
+        let pattern = cube.material().pattern.as_ref().unwrap();
+        assert_eq!(
+            pattern.pattern_at_shape(cube, Point::new(-0.9, 0.9, 1.0)),
+            Color::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            pattern.pattern_at_shape(cube, Point::new(-0.9, -1.0, 0.9)),
+            Color::white()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn loading_scene_with_image_texture_relative_to_it() {
+        let dir = std::env::temp_dir().join("rustracer-scene-texture-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("texture.ppm"), "P3\n2 1\n255\n255 0 0  0 0 255\n").unwrap();
+        std::fs::write(
+            dir.join("scene.yml"),
+            format!(
+                "{}
+- add: plane
+  material:
+    pattern:
+      type: map
+      mapping: planar
+      uv_pattern:
+        type: image
+        file: texture.ppm",
+                CAMERA
+            ),
+        )
+        .unwrap();
+
+        let s = Scene::load(dir.join("scene.yml")).unwrap();
+
+        let plane = s.world.objects[0].as_ref();
+        let pattern = plane.material().pattern.as_ref().unwrap();
+        assert_eq!(
+            pattern.pattern_at_shape(plane, Point::new(0.1, 0.0, 0.5)),
+            Color::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            pattern.pattern_at_shape(plane, Point::new(0.9, 0.0, 0.5)),
+            Color::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn reading_groups_and_csg_with_inherited_materials() {
+        let s = scene(
+            "
+- define: die
+  value:
+    add: csg
+    operation: difference
+    left: { add: cube }
+    right:
+      add: sphere
+      material: { color: [0, 0, 0] }
+- add: group
+  material: { color: [1, 0, 0] }
+  transform:
+    - [translate, 0, 1, 0]
+  children:
+    - add: die
+      transform:
+        - [scale, 0.5, 0.5, 0.5]
+    - add: triangle
+      p1: [0, 1, 0]
+      p2: [-1, 0, 0]
+      p3: [1, 0, 0]",
+        )
+        .unwrap();
+
+        let group = s.world.objects[0].downcast_ref::<Group>().unwrap();
+        assert_eq!(*group.transform(), Matrix4::translation(0.0, 1.0, 0.0));
+        let csg = group.children()[0].downcast_ref::<Csg>().unwrap();
+        assert_eq!(csg.operation(), CsgOperation::Difference);
+        assert_eq!(*csg.transform(), Matrix4::scaling(0.5, 0.5, 0.5));
+        assert_eq!(csg.left().material().color, Color::new(1.0, 0.0, 0.0));
+        assert_eq!(csg.right().material().color, Color::black());
+        let triangle = group.children()[1].downcast_ref::<Triangle>().unwrap();
+        assert_eq!(triangle.p2(), Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(triangle.material().color, Color::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn loading_scene_with_obj_file_relative_to_it() {
+        let dir = std::env::temp_dir().join("rustracer-scene-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("triangle.obj"),
+            "v 0 1 0\nv -1 0 0\nv 1 0 0\nf 1 2 3\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("scene.yml"),
+            format!(
+                "{}
+- add: obj
+  file: triangle.obj
+  material: {{ color: [0, 1, 0] }}",
+                CAMERA
+            ),
+        )
+        .unwrap();
+
+        let s = Scene::load(dir.join("scene.yml")).unwrap();
+
+        let group = s.world.objects[0].downcast_ref::<Group>().unwrap();
+        let triangle = group.children()[0].as_ref();
+        assert!(triangle.downcast_ref::<Triangle>().is_some());
+        assert_eq!(triangle.material().color, Color::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn invalid_scenes_are_reported() {
+        assert_eq!(invalid_reason("- add: torus"), "`torus` is not defined");
+        assert_eq!(
+            invalid_reason("- add: sphere\n  colour: [1, 0, 0]"),
+            "unknown key `colour` for sphere"
+        );
+        assert_eq!(
+            invalid_reason("- add: sphere\n  material: shiny"),
+            "`shiny` is not defined"
+        );
+        assert_eq!(
+            invalid_reason("- add: sphere\n  transform:\n    - [scale, 1, 2]"),
+            "wrong number of arguments for `scale`"
+        );
+        assert_eq!(
+            invalid_reason("- add: sphere\n  transform:\n    - [scale, 0, 1, 1]"),
+            "transforms must be invertible"
+        );
+        assert_eq!(
+            invalid_reason("- add: light\n  at: [0, 0]\n  intensity: [1, 1, 1]"),
+            "expected a list of three numbers for `at`, not a list"
+        );
+        assert!(matches!(
+            Scene::parse("- add: sphere"),
+            Err(RustracerError::InvalidScene(reason)) if reason == "the scene has no camera"
+        ));
+    }
+
+    #[test]
+    fn large_groups_are_divided() {
+        let children: String = (0..8)
+            .map(|x| {
+                format!(
+                    "\n    - add: sphere\n      transform:\n        - [translate, {}, 0, 0]",
+                    x * 3
+                )
+            })
+            .collect();
+        let s = scene(&format!("- add: group\n  children:{}", children)).unwrap();
+
+        let group = s.world.objects[0].downcast_ref::<Group>().unwrap();
+        assert_eq!(group.children().len(), 2);
+        assert!(group
+            .children()
+            .iter()
+            .all(|child| child.downcast_ref::<Group>().is_some()));
+    }
+
+    #[test]
+    fn cameras_without_orientation_are_invalid() {
+        let camera = |to: &str, up: &str| {
+            format!(
+                "- add: camera\n  width: 10\n  height: 10\n  field-of-view: 1\n  \
+                 from: [0, 0, -5]\n  to: {}\n  up: {}",
+                to, up
+            )
+        };
+
+        assert!(matches!(
+            Scene::parse(&camera("[0, 0, -5]", "[0, 1, 0]")),
+            Err(RustracerError::InvalidScene(reason))
+                if reason == "the camera must look `to` somewhere other than `from`"
+        ));
+        assert!(matches!(
+            Scene::parse(&camera("[0, 0, 0]", "[0, 0, 2]")),
+            Err(RustracerError::InvalidScene(reason))
+                if reason == "the camera's `up` must not point along its view"
+        ));
+        assert!(matches!(
+            Scene::parse(&camera("[0, 0, 0]", "[0, 0, 0]")),
+            Err(RustracerError::InvalidScene(reason))
+                if reason == "the camera's `up` must not point along its view"
+        ));
+    }
+
+    #[test]
+    fn recursive_definitions_are_invalid() {
+        assert_eq!(
+            invalid_reason("- define: a\n  value:\n    add: a\n- add: a"),
+            "recursive definition of `a`"
+        );
+        assert_eq!(
+            invalid_reason(
+                "
+- define: g
+  value:
+    add: group
+    children:
+      - g
+- add: g"
+            ),
+            "recursive definition of `g`"
+        );
+        assert_eq!(
+            invalid_reason(
+                "
+- define: t
+  value:
+    - [scale, 2, 2, 2]
+    - u
+- define: u
+  value:
+    - t
+- add: sphere
+  transform: t"
+            ),
+            "recursive definition of `t`"
+        );
+    }
+
+    #[test]
+    fn definitions_can_be_used_more_than_once() {
+        let s = scene(
+            "
+- define: step
+  value:
+    - [translate, 1, 0, 0]
+- define: ball
+  value:
+    add: sphere
+    transform: [step, step]
+- add: group
+  children: [ball, ball]",
+        )
+        .unwrap();
+
+        let group = s.world.objects[0].downcast_ref::<Group>().unwrap();
+        assert_eq!(group.children().len(), 2);
+        assert_eq!(
+            *group.children()[1].transform(),
+            Matrix4::translation(2.0, 0.0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use crate::prelude::*;
+use core::any::Any;
+use core::fmt;
+use core::ptr;
+
+/// State shared by every kind of shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeProperties {
+    transform: Matrix4,
+    inverse: Matrix4,
+    parent_transform: Matrix4,
+    world_inverse: Matrix4,
+    pub material: Material,
+    visibility: Visibility,
+    parent_visibility: Visibility,
+}
+
+impl ShapeProperties {
+    pub fn new() -> Self {
+        Self {
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            parent_transform: Matrix4::identity(),
+            world_inverse: Matrix4::identity(),
+            material: Material::default(),
+            visibility: Visibility::ALL,
+            parent_visibility: Visibility::ALL,
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    /// Inverse of the transformation, which brings rays from the parent's space into object space.
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    /// Combined transformation of every group enclosing the shape, identity at the top level.
+    pub fn parent_transform(&self) -> &Matrix4 {
+        &self.parent_transform
+    }
+
+    /// Inverse of the parent and own transformations combined, which brings world-space
+    /// points into object space.
+    pub fn world_inverse(&self) -> &Matrix4 {
+        &self.world_inverse
+    }
+
+    /// Transformation from object space to world space.
+    pub fn world_transform(&self) -> Matrix4 {
+        self.parent_transform * self.transform
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `transform` is not invertible, since rays could not be brought into object space.
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.try_set_transform(transform)
+            .expect("shape transformations must be invertible");
+    }
+
+    /// Sets the transformation like [`ShapeProperties::set_transform`], failing instead of
+    /// panicking when it is not invertible.
+    pub fn try_set_transform(&mut self, transform: Matrix4) -> Result<()> {
+        let inverse = transform.inverse()?;
+        let world_inverse = (self.parent_transform * transform).inverse()?;
+        self.transform = transform;
+        self.inverse = inverse;
+        self.world_inverse = world_inverse;
+        Ok(())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `parent_transform` is not invertible.
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.try_set_parent_transform(parent_transform)
+            .expect("shape transformations must be invertible");
+    }
+
+    /// Sets the parent transformation like [`ShapeProperties::set_parent_transform`],
+    /// failing instead of panicking when it is not invertible.
+    pub fn try_set_parent_transform(&mut self, parent_transform: Matrix4) -> Result<()> {
+        self.world_inverse = (parent_transform * self.transform).inverse()?;
+        self.parent_transform = parent_transform;
+        Ok(())
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    /// Combined visibility of the groups enclosing the shape.
+    pub fn parent_visibility(&self) -> Visibility {
+        self.parent_visibility
+    }
+
+    pub fn set_parent_visibility(&mut self, parent_visibility: Visibility) {
+        self.parent_visibility = parent_visibility;
+    }
+
+    /// Kinds of rays that see the shape, those seeing both the shape and its parents.
+    pub fn world_visibility(&self) -> Visibility {
+        self.visibility.and(self.parent_visibility)
+    }
+}
+
+impl Default for ShapeProperties {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a ray traced through a world is for, which decides the objects it can see.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RayKind {
+    /// From the camera, through a pixel.
+    Camera,
+    /// From a surface toward a light, to find out if it is in shadow.
+    Shadow,
+    /// Bounced off a reflective surface.
+    Reflection,
+    /// Bent through a transparent surface.
+    Refraction,
+}
+
+/// Which kinds of rays see a shape, e.g. to hide a light blocker from the camera while it
+/// still casts shadows, or to keep a backdrop out of the reflections.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Visibility {
+    pub camera: bool,
+    pub shadows: bool,
+    pub reflections: bool,
+    /// Whether the shape shows through transparent surfaces.
+    pub refractions: bool,
+}
+
+impl Visibility {
+    /// Seen by every ray, the default.
+    pub const ALL: Self = Self {
+        camera: true,
+        shadows: true,
+        reflections: true,
+        refractions: true,
+    };
+
+    /// Visibility to the rays seeing both `self` and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self {
+            camera: self.camera && other.camera,
+            shadows: self.shadows && other.shadows,
+            reflections: self.reflections && other.reflections,
+            refractions: self.refractions && other.refractions,
+        }
+    }
+
+    pub fn sees(&self, kind: RayKind) -> bool {
+        match kind {
+            RayKind::Camera => self.camera,
+            RayKind::Shadow => self.shadows,
+            RayKind::Reflection => self.reflections,
+            RayKind::Refraction => self.refractions,
+        }
+    }
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// A renderable object.
+///
+/// Implementations only deal with their own, untransformed geometry through
+/// [`Shape::local_intersect`] and [`Shape::local_normal_at`]; the provided methods take
+/// care of moving rays and normals between world and object space.
+///
+/// Shapes must be shareable between threads so that worlds can be rendered in parallel.
+pub trait Shape: Any + Send + Sync + fmt::Debug {
+    fn properties(&self) -> &ShapeProperties;
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties;
+
+    /// Intersections with a ray already transformed into object space.
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_>;
+
+    /// Normal at a point given in object space.
+    fn local_normal_at(&self, point: Point) -> Vector;
+
+    /// Box enclosing the shape in object space.
+    fn bounds(&self) -> BoundingBox;
+
+    /// Normal at a point given in object space, where `hit` struck the shape.
+    ///
+    /// Shapes whose normal depends on more than the point, like smooth triangles using
+    /// the `u`/`v` of the hit, override this.
+    fn local_normal_at_hit(&self, point: Point, _hit: &Intersection) -> Vector {
+        self.local_normal_at(point)
+    }
+
+    fn transform(&self) -> &Matrix4 {
+        self.properties().transform()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `transform` is not invertible.
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.try_set_transform(transform)
+            .expect("shape transformations must be invertible");
+    }
+
+    /// Sets the transformation like [`Shape::set_transform`], failing instead of panicking
+    /// when it is not invertible.
+    fn try_set_transform(&mut self, transform: Matrix4) -> Result<()> {
+        self.properties_mut().try_set_transform(transform)?;
+        // Let shapes holding children pass the new transformation down.
+        let parent_transform = *self.properties().parent_transform();
+        self.set_parent_transform(parent_transform);
+        Ok(())
+    }
+
+    /// Places the shape inside groups whose combined transformation is `parent_transform`.
+    ///
+    /// Shapes holding children override this to pass the transformation down to them.
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.properties_mut().set_parent_transform(parent_transform);
+    }
+
+    fn material(&self) -> &Material {
+        &self.properties().material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.properties_mut().material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.properties_mut().material = material;
+    }
+
+    /// The shape's own visibility, see [`Shape::world_visibility`] for the one rays use.
+    fn visibility(&self) -> Visibility {
+        self.properties().visibility()
+    }
+
+    /// Chooses the kinds of rays that see the shape, see [`World::intersect_visible`].
+    ///
+    /// The shapes inside a group or CSG are only seen by the rays seeing both them and
+    /// every shape holding them, so hiding a group hides all of its children, including
+    /// those added later, while they keep their own flags too.
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.properties_mut().set_visibility(visibility);
+        // Let shapes holding children pass the new visibility down.
+        let parent_visibility = self.properties().parent_visibility();
+        self.set_parent_visibility(parent_visibility);
+    }
+
+    /// Places the shape inside groups whose combined visibility is `parent_visibility`.
+    ///
+    /// Shapes holding children override this to pass the visibility down to them.
+    fn set_parent_visibility(&mut self, parent_visibility: Visibility) {
+        self.properties_mut()
+            .set_parent_visibility(parent_visibility);
+    }
+
+    /// Kinds of rays that see the shape, given its own visibility and its parents'.
+    fn world_visibility(&self) -> Visibility {
+        self.properties().world_visibility()
+    }
+
+    fn with_transform(mut self, transform: Matrix4) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_transform(transform);
+        self
+    }
+
+    fn with_material(mut self, material: Material) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_material(material);
+        self
+    }
+
+    /// Intersections with a ray given in the space of the shape's parent.
+    fn intersect(&self, ray: &Ray) -> Intersections<'_> {
+        self.local_intersect(&ray.transform(self.properties().inverse()))
+    }
+
+    /// Converts a world-space point into object space, through every enclosing group.
+    fn world_to_object(&self, point: Point) -> Point {
+        *self.properties().world_inverse() * point
+    }
+
+    /// Converts an object-space normal into world space, through every enclosing group.
+    fn normal_to_world(&self, normal: Vector) -> Vector {
+        (self.properties().world_inverse().transpose() * normal).normalize()
+    }
+
+    /// World-space surface normal at a world-space point of the shape.
+    fn normal_at(&self, world_point: Point) -> Vector {
+        let local_normal = self.local_normal_at(self.world_to_object(world_point));
+        self.normal_to_world(local_normal)
+    }
+
+    /// World-space normal used to shade the world-space point where `hit` struck the
+    /// shape, tilted by the [`Material::normal_perturb`] of the shape if it has one.
+    fn normal_at_hit(&self, world_point: Point, hit: &Intersection) -> Vector {
+        let object_point = self.world_to_object(world_point);
+        let mut local_normal = self.local_normal_at_hit(object_point, hit);
+        if let Some(perturb) = &self.material().normal_perturb {
+            local_normal = perturb.perturb(object_point, local_normal.normalize());
+        }
+        self.normal_to_world(local_normal)
+    }
+
+    /// Box enclosing the shape in the space of its parent.
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transform())
+    }
+
+    /// Organizes the children of shapes holding them into a bounding volume hierarchy, so
+    /// that rays only visit the children near their path.
+    ///
+    /// Groups with at least `threshold` children are split in two, recursively.
+    fn divide(&mut self, _threshold: usize) {}
+
+    /// Whether `other` is this shape or, for shapes holding children, one of its descendants.
+    fn includes(&self, other: &dyn Shape) -> bool {
+        ptr::addr_eq(self, other)
+    }
+}
+
+impl dyn Shape {
+    /// The concrete shape behind the trait object, if it is a `T`.
+    pub fn downcast_ref<T: Shape>(&self) -> Option<&T> {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+}
+
+/// A shape that records the object-space ray it was intersected with.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct TestShape {
+    properties: ShapeProperties,
+    saved_ray: std::sync::Mutex<Option<Ray>>,
+}
+
+#[cfg(test)]
+impl TestShape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn saved_ray(&self) -> Option<Ray> {
+        *self.saved_ray.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+impl Shape for TestShape {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        *self.saved_ray.lock().unwrap() = Some(*ray);
+        Intersections::default()
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        point - Point::origin()
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestShape;
+    use crate::math::consts::{FRAC_1_SQRT_2, PI};
+    use crate::prelude::*;
+
+    // Scenario: The default transformation
+    //  Given s ← test_shape()
+    //  Then s.transform = identity_matrix
+    #[test]
+    fn default_transformation() {
+        let s = TestShape::new();
+
+        assert_eq!(*s.transform(), Matrix4::identity());
+    }
+
+    // Scenario: Assigning a transformation
+    //  Given s ← test_shape()
+    //  When set_transform(s, translation(2, 3, 4))
+    //  Then s.transform = translation(2, 3, 4)
+    #[test]
+    fn assigning_transformation() {
+        let mut s = TestShape::new();
+        s.set_transform(Matrix4::translation(2.0, 3.0, 4.0));
+
+        assert_eq!(*s.transform(), Matrix4::translation(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn trying_noninvertible_parent_transformation_keeps_previous_one() {
+        let mut s = TestShape::new();
+        s.set_parent_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+
+        assert!(s
+            .properties_mut()
+            .try_set_parent_transform(Matrix4::scaling(1.0, 0.0, 1.0))
+            .is_err());
+        assert_eq!(
+            *s.properties().parent_transform(),
+            Matrix4::scaling(2.0, 2.0, 2.0)
+        );
+        assert_eq!(
+            s.world_to_object(Point::new(2.0, 4.0, 6.0)),
+            Point::new(1.0, 2.0, 3.0)
+        );
+    }
+
+    // Scenario: A shape has a parent attribute
+    //  Given s ← test_shape()
+    //  Then s.parent is nothing
+    #[test]
+    fn shape_starts_outside_any_group() {
+        let s = TestShape::new();
+
+        assert_eq!(*s.properties().parent_transform(), Matrix4::identity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn assigning_noninvertible_transformation_panics() {
+        let mut s = TestShape::new();
+        s.set_transform(Matrix4::scaling(0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn trying_noninvertible_transformation_keeps_previous_one() {
+        let mut s = TestShape::new().with_transform(Matrix4::translation(2.0, 3.0, 4.0));
+
+        assert!(matches!(
+            s.try_set_transform(Matrix4::scaling(0.0, 1.0, 1.0)),
+            Err(RustracerError::NonInvertibleMatrix)
+        ));
+        assert_eq!(*s.transform(), Matrix4::translation(2.0, 3.0, 4.0));
+    }
+
+    // Scenario: The default material
+    //  Given s ← test_shape()
+    //  When m ← s.material
+    //  Then m = material()
+    #[test]
+    fn default_material() {
+        let s = TestShape::new();
+
+        assert_eq!(*s.material(), Material::default());
+    }
+
+    // Scenario: Assigning a material
+    //  Given s ← test_shape()
+    //    And m ← material()
+    //    And m.ambient ← 1
+    //  When s.material ← m
+    //  Then s.material = m
+    #[test]
+    fn assigning_material() {
+        let mut s = TestShape::new();
+        let m = Material {
+            ambient: 1.0,
+            ..Material::default()
+        };
+        s.set_material(m.clone());
+
+        assert_eq!(*s.material(), m);
+    }
+
+    // Scenario: Intersecting a scaled shape with a ray
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← test_shape()
+    //  When set_transform(s, scaling(2, 2, 2))
+    //    And xs ← intersect(s, r)
+    //  Then s.saved_ray.origin = point(0, 0, -2.5)
+    //    And s.saved_ray.direction = vector(0, 0, 0.5)
+    #[test]
+    fn intersecting_scaled_shape_with_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = TestShape::new().with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        s.intersect(&r);
+
+        let saved_ray = s.saved_ray().unwrap();
+        assert_eq!(saved_ray.origin, Point::new(0.0, 0.0, -2.5));
+        assert_eq!(saved_ray.direction, Vector::new(0.0, 0.0, 0.5));
+    }
+
+    // Scenario: Intersecting a translated shape with a ray
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← test_shape()
+    //  When set_transform(s, translation(5, 0, 0))
+    //    And xs ← intersect(s, r)
+    //  Then s.saved_ray.origin = point(-5, 0, -5)
+    //    And s.saved_ray.direction = vector(0, 0, 1)
+    #[test]
+    fn intersecting_translated_shape_with_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = TestShape::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0));
+        s.intersect(&r);
+
+        let saved_ray = s.saved_ray().unwrap();
+        assert_eq!(saved_ray.origin, Point::new(-5.0, 0.0, -5.0));
+        assert_eq!(saved_ray.direction, Vector::new(0.0, 0.0, 1.0));
+    }
+
+    // Scenario: Computing the normal on a translated shape
+    //  Given s ← test_shape()
+    //  When set_transform(s, translation(0, 1, 0))
+    //    And n ← normal_at(s, point(0, 1.70711, -0.70711))
+    //  Then n = vector(0, 0.70711, -0.70711)
+    #[test]
+    fn computing_normal_on_translated_shape() {
+        let s = TestShape::new().with_transform(Matrix4::translation(0.0, 1.0, 0.0));
+        let n = s.normal_at(Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+        assert_eq!(n, Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    }
+
+    // Scenario: Computing the normal on a transformed shape
+    //  Given s ← test_shape()
+    //    And m ← scaling(1, 0.5, 1) * rotation_z(π/5)
+    //  When set_transform(s, m)
+    //    And n ← normal_at(s, point(0, √2/2, -√2/2))
+    //  Then n = vector(0, 0.97014, -0.24254)
+    #[test]
+    fn computing_normal_on_transformed_shape() {
+        let m = Matrix4::scaling(1.0, 0.5, 1.0) * Matrix4::rotation_z(PI / 5.0);
+        let s = TestShape::new().with_transform(m);
+        let n = s.normal_at(Point::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+        assert_eq!(n, Vector::new(0.0, 0.97014, -0.24254));
+    }
+
+    // Scenario: Querying a shape's bounding box in its parent's space
+    //  Given shape ← sphere()
+    //    And set_transform(shape, translation(1, -3, 5) * scaling(0.5, 2, 4))
+    //  When box ← parent_space_bounds_of(shape)
+    //  Then box.min = point(0.5, -5, 1)
+    //    And box.max = point(1.5, -1, 9)
+    #[test]
+    fn querying_bounding_box_in_parent_space() {
+        let shape = Sphere::new()
+            .with_transform(Matrix4::translation(1.0, -3.0, 5.0) * Matrix4::scaling(0.5, 2.0, 4.0));
+
+        let b = shape.parent_space_bounds();
+
+        assert_eq!(b.min, Point::new(0.5, -5.0, 1.0));
+        assert_eq!(b.max, Point::new(1.5, -1.0, 9.0));
+    }
+
+    // Scenario: Subdividing a primitive does nothing
+    //  Given shape ← sphere()
+    //  When divide(shape, 1)
+    //  Then shape is a sphere
+    #[test]
+    fn subdividing_primitive_does_nothing() {
+        let mut shape = Sphere::new();
+
+        shape.divide(1);
+
+        assert_eq!(shape.bounds(), Sphere::new().bounds());
+    }
+}
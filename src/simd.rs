@@ -0,0 +1,251 @@
+//! SSE versions of the matrix operations, used with the `simd` feature.
+//!
+//! Every x86-64 processor has SSE, so there is nothing to detect at runtime. On other
+//! targets, or when [`Float`](crate::math::Float) is an `f64`, the scalar code in
+//! [`matrix`](crate::matrix) is used instead.
+//!
+//! Only the products with four full rows and the inverse gain from explicit lanes, as the
+//! `matrix` bench shows. The compiler already vectorizes the tuple arithmetic and the
+//! three-row products with points and vectors, which came out slower by hand.
+
+pub(crate) type Rows = [[f32; 4]; 4];
+
+// SAFETY: the intrinsics are only unsafe to call without SSE, which every x86-64 processor
+// has; the functions below just forward to the `sse` versions.
+
+pub(crate) fn mul_matrices(a: &Rows, b: &Rows) -> Rows {
+    unsafe { sse::mul_matrices(a, b) }
+}
+
+pub(crate) fn mul_tuple(m: &Rows, t: [f32; 4]) -> [f32; 4] {
+    unsafe { sse::mul_tuple(m, t) }
+}
+
+/// Inverse of `m`, or `None` if its determinant is 0.
+pub(crate) fn inverse(m: &Rows) -> Option<Rows> {
+    unsafe { sse::inverse(m) }
+}
+
+mod sse {
+    use super::Rows;
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "sse")]
+    fn load(values: &[f32; 4]) -> __m128 {
+        // SAFETY: the array holds the 4 floats read, and `loadu` has no alignment requirement.
+        unsafe { _mm_loadu_ps(values.as_ptr()) }
+    }
+
+    #[target_feature(enable = "sse")]
+    fn store(lanes: __m128) -> [f32; 4] {
+        let mut values = [0.0; 4];
+        // SAFETY: the array has room for the 4 floats written, at any alignment.
+        unsafe { _mm_storeu_ps(values.as_mut_ptr(), lanes) };
+        values
+    }
+
+    /// Row of `a * b`: the rows of `b` weighted by the entries of the row of `a`.
+    #[target_feature(enable = "sse")]
+    fn combine_rows(row: &[f32; 4], b: &[__m128; 4]) -> __m128 {
+        let first = _mm_add_ps(
+            _mm_mul_ps(_mm_set1_ps(row[0]), b[0]),
+            _mm_mul_ps(_mm_set1_ps(row[1]), b[1]),
+        );
+        let second = _mm_add_ps(
+            _mm_mul_ps(_mm_set1_ps(row[2]), b[2]),
+            _mm_mul_ps(_mm_set1_ps(row[3]), b[3]),
+        );
+        _mm_add_ps(first, second)
+    }
+
+    #[target_feature(enable = "sse")]
+    pub fn mul_matrices(a: &Rows, b: &Rows) -> Rows {
+        let b = [load(&b[0]), load(&b[1]), load(&b[2]), load(&b[3])];
+        [
+            store(combine_rows(&a[0], &b)),
+            store(combine_rows(&a[1], &b)),
+            store(combine_rows(&a[2], &b)),
+            store(combine_rows(&a[3], &b)),
+        ]
+    }
+
+    /// Sums of the lanes of each of `a`, `b`, `c` and `d`, in that order.
+    #[target_feature(enable = "sse")]
+    fn sum_lanes(a: __m128, b: __m128, c: __m128, d: __m128) -> __m128 {
+        // [ax + az, bx + bz, ay + aw, by + bw], and the same for c and d.
+        let ab = _mm_add_ps(_mm_unpacklo_ps(a, b), _mm_unpackhi_ps(a, b));
+        let cd = _mm_add_ps(_mm_unpacklo_ps(c, d), _mm_unpackhi_ps(c, d));
+        _mm_add_ps(_mm_movelh_ps(ab, cd), _mm_movehl_ps(cd, ab))
+    }
+
+    #[target_feature(enable = "sse")]
+    pub fn mul_tuple(m: &Rows, t: [f32; 4]) -> [f32; 4] {
+        let t = load(&t);
+        store(sum_lanes(
+            _mm_mul_ps(load(&m[0]), t),
+            _mm_mul_ps(load(&m[1]), t),
+            _mm_mul_ps(load(&m[2]), t),
+            _mm_mul_ps(load(&m[3]), t),
+        ))
+    }
+
+    /// Column of the adjugate matrix from a `row` and the 2x2 determinants `k` of the two
+    /// other rows, following the expansion by complementary minors.
+    #[target_feature(enable = "sse")]
+    fn adjugate_column(row: __m128, k: [f32; 6]) -> __m128 {
+        let [k0, k1, k2, k3, k4, k5] = k;
+        let first = _mm_mul_ps(
+            _mm_shuffle_ps(row, row, 0b00_00_00_01),
+            _mm_setr_ps(k5, k5, k4, k3),
+        );
+        let second = _mm_mul_ps(
+            _mm_shuffle_ps(row, row, 0b01_01_10_10),
+            _mm_setr_ps(k4, k2, k2, k1),
+        );
+        let third = _mm_mul_ps(
+            _mm_shuffle_ps(row, row, 0b10_11_11_11),
+            _mm_setr_ps(k3, k1, k0, k0),
+        );
+        _mm_add_ps(_mm_sub_ps(first, second), third)
+    }
+
+    /// Inverse of `m`, or `None` if its determinant is 0.
+    #[target_feature(enable = "sse")]
+    pub fn inverse(m: &Rows) -> Option<Rows> {
+        let det2 = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r1][c0] * m[r0][c1]
+        };
+        // Determinants of the 2x2 blocks in the top and bottom pairs of rows.
+        let s = [
+            det2(0, 1, 0, 1),
+            det2(0, 1, 0, 2),
+            det2(0, 1, 0, 3),
+            det2(0, 1, 1, 2),
+            det2(0, 1, 1, 3),
+            det2(0, 1, 2, 3),
+        ];
+        let c = [
+            det2(2, 3, 0, 1),
+            det2(2, 3, 0, 2),
+            det2(2, 3, 0, 3),
+            det2(2, 3, 1, 2),
+            det2(2, 3, 1, 3),
+            det2(2, 3, 2, 3),
+        ];
+
+        let determinant =
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if determinant == 0.0 {
+            return None;
+        }
+
+        // The signs of the cofactors alternate, and each column of the result starts with the
+        // opposite sign of the previous one.
+        let plus = _mm_mul_ps(
+            _mm_setr_ps(1.0, -1.0, 1.0, -1.0),
+            _mm_set1_ps(1.0 / determinant),
+        );
+        let minus = _mm_sub_ps(_mm_setzero_ps(), plus);
+        let columns = [
+            _mm_mul_ps(adjugate_column(load(&m[1]), c), plus),
+            _mm_mul_ps(adjugate_column(load(&m[0]), c), minus),
+            _mm_mul_ps(adjugate_column(load(&m[3]), s), plus),
+            _mm_mul_ps(adjugate_column(load(&m[2]), s), minus),
+        ];
+
+        let mut rows = [[0.0; 4]; 4];
+        for (col, &values) in columns.iter().enumerate() {
+            for (row, &value) in store(values).iter().enumerate() {
+                rows[row][col] = value;
+            }
+        }
+        Some(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn scalar_inverse(rows: &Rows) -> Rows {
+        let m = Matrix4::from_rows(*rows);
+        let determinant = m.determinant();
+        let mut expected = [[0.0; 4]; 4];
+        for (row, values) in expected.iter_mut().enumerate() {
+            for (col, value) in values.iter_mut().enumerate() {
+                *value = m.cofactor(col, row) / determinant;
+            }
+        }
+        expected
+    }
+
+    fn assert_rows_eq(a: Rows, b: Rows) {
+        assert_eq!(Matrix4::from_rows(a), Matrix4::from_rows(b));
+    }
+
+    #[test]
+    fn matrix_lanes_match_scalar_products() {
+        let a = [
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ];
+        let b = [
+            [-2.0, 1.0, 2.0, 3.0],
+            [3.0, 2.0, 1.0, -1.0],
+            [4.0, 3.0, 6.0, 5.0],
+            [1.0, 2.0, 7.0, 8.0],
+        ];
+
+        assert_rows_eq(
+            mul_matrices(&a, &b),
+            [
+                [20.0, 22.0, 50.0, 48.0],
+                [44.0, 54.0, 114.0, 108.0],
+                [40.0, 58.0, 110.0, 102.0],
+                [16.0, 26.0, 46.0, 42.0],
+            ],
+        );
+        assert_eq!(
+            mul_tuple(&a, [1.0, 2.0, 3.0, 1.0]),
+            [18.0, 46.0, 52.0, 24.0]
+        );
+    }
+
+    #[test]
+    fn inverse_matches_cofactor_expansion() {
+        let matrices = [
+            [
+                [-5.0, 2.0, 6.0, -8.0],
+                [1.0, -5.0, 1.0, 8.0],
+                [7.0, 7.0, -6.0, -7.0],
+                [1.0, -3.0, 7.0, 4.0],
+            ],
+            [
+                [8.0, -5.0, 9.0, 2.0],
+                [7.0, 5.0, 6.0, 1.0],
+                [-6.0, 0.0, 9.0, 6.0],
+                [-3.0, 0.0, -9.0, -4.0],
+            ],
+            [
+                [9.0, 3.0, 0.0, 9.0],
+                [-5.0, -2.0, -6.0, -3.0],
+                [-4.0, 9.0, 6.0, 4.0],
+                [-7.0, 6.0, 6.0, 2.0],
+            ],
+        ];
+
+        for m in &matrices {
+            assert_rows_eq(inverse(m).unwrap(), scalar_inverse(m));
+        }
+        let singular = [
+            [-4.0, 2.0, -2.0, -3.0],
+            [9.0, 6.0, 2.0, 6.0],
+            [0.0, -5.0, 1.0, -5.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+        assert!(inverse(&singular).is_none());
+    }
+}
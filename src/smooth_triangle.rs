@@ -0,0 +1,215 @@
+use crate::prelude::*;
+use crate::triangle::intersect_triangle;
+use alloc::vec;
+
+/// A triangle whose normal is blended from a normal at each vertex, so that meshes of
+/// them look smoothly curved.
+#[derive(Debug, Clone)]
+pub struct SmoothTriangle {
+    properties: ShapeProperties,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        Self {
+            properties: ShapeProperties::new(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub fn n1(&self) -> Vector {
+        self.n1
+    }
+
+    pub fn n2(&self) -> Vector {
+        self.n2
+    }
+
+    pub fn n3(&self) -> Vector {
+        self.n3
+    }
+
+    /// Vertex normals weighted by the `u`/`v` barycentric coordinates of a point.
+    fn interpolate_normal(&self, u: Float, v: Float) -> Vector {
+        self.n2 * u + self.n3 * v + self.n1 * (1.0 - u - v)
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        match intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            Some((t, u, v)) => Intersections::new(vec![Intersection::with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+
+    /// Normal at a point of the triangle, whose `u`/`v` are worked out from its position.
+    fn local_normal_at(&self, point: Point) -> Vector {
+        let to_point = point - self.p1;
+        let d11 = self.e1.dot(&self.e1);
+        let d12 = self.e1.dot(&self.e2);
+        let d22 = self.e2.dot(&self.e2);
+        let dp1 = to_point.dot(&self.e1);
+        let dp2 = to_point.dot(&self.e2);
+        let denom = d11 * d22 - d12 * d12;
+
+        let u = (d22 * dp1 - d12 * dp2) / denom;
+        let v = (d11 * dp2 - d12 * dp1) / denom;
+        self.interpolate_normal(u, v)
+    }
+
+    fn local_normal_at_hit(&self, _point: Point, hit: &Intersection) -> Vector {
+        self.interpolate_normal(hit.u, hit.v)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec;
+
+    // Background:
+    //  Given p1 ← point(0, 1, 0)
+    //    And p2 ← point(-1, 0, 0)
+    //    And p3 ← point(1, 0, 0)
+    //    And n1 ← vector(0, 1, 0)
+    //    And n2 ← vector(-1, 0, 0)
+    //    And n3 ← vector(1, 0, 0)
+    //  When tri ← smooth_triangle(p1, p2, p3, n1, n2, n3)
+    fn background() -> SmoothTriangle {
+        SmoothTriangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            Vector::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    // Scenario: Constructing a smooth triangle
+    //  Then tri.p1 = p1
+    //    And tri.p2 = p2
+    //    And tri.p3 = p3
+    //    And tri.n1 = n1
+    //    And tri.n2 = n2
+    //    And tri.n3 = n3
+    #[test]
+    fn constructing_smooth_triangle() {
+        let tri = background();
+
+        assert_eq!(tri.p1(), Point::new(0.0, 1.0, 0.0));
+        assert_eq!(tri.p2(), Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(tri.p3(), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(tri.n1(), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(tri.n2(), Vector::new(-1.0, 0.0, 0.0));
+        assert_eq!(tri.n3(), Vector::new(1.0, 0.0, 0.0));
+    }
+
+    // Scenario: An intersection with a smooth triangle stores u/v
+    //  When r ← ray(point(-0.2, 0.3, -2), vector(0, 0, 1))
+    //    And xs ← local_intersect(tri, r)
+    //  Then xs[0].u = 0.45
+    //    And xs[0].v = 0.25
+    #[test]
+    fn intersection_with_smooth_triangle_stores_uv() {
+        let tri = background();
+        let r = Ray::new(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = tri.local_intersect(&r);
+
+        assert!(is_approx(xs[0].u, 0.45, None));
+        assert!(is_approx(xs[0].v, 0.25, None));
+    }
+
+    // Scenario: A smooth triangle uses u/v to interpolate the normal
+    //  When i ← intersection_with_uv(1, tri, 0.45, 0.25)
+    //    And n ← normal_at(tri, point(0, 0, 0), i)
+    //  Then n = vector(-0.5547, 0.83205, 0)
+    #[test]
+    fn smooth_triangle_uses_uv_to_interpolate_normal() {
+        let tri = background();
+        let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+
+        let n = tri.normal_at_hit(Point::origin(), &i);
+
+        assert_eq!(n, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+
+    // Scenario: Preparing the normal on a smooth triangle
+    //  When i ← intersection_with_uv(1, tri, 0.45, 0.25)
+    //    And r ← ray(point(-0.2, 0.3, -2), vector(0, 0, 1))
+    //    And xs ← intersections(i)
+    //    And comps ← prepare_computations(i, r, xs)
+    //  Then comps.normalv = vector(-0.5547, 0.83205, 0)
+    #[test]
+    fn preparing_normal_on_smooth_triangle() {
+        let tri = background();
+        let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+        let r = Ray::new(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(comps.normalv, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+
+    #[test]
+    fn normal_without_hit_is_interpolated_from_point() {
+        let tri = background();
+
+        let n = tri.normal_at(Point::new(-0.2, 0.3, 0.0));
+
+        assert_eq!(n, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+
+    #[test]
+    fn smooth_triangle_has_bounding_box() {
+        let b = background().bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(b.max, Point::new(1.0, 1.0, 0.0));
+    }
+}
@@ -0,0 +1,363 @@
+use crate::prelude::*;
+use alloc::vec;
+
+/// A unit sphere centered at the origin of its object space.
+#[derive(Debug, Clone, Default)]
+pub struct Sphere {
+    properties: ShapeProperties,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A fully transparent sphere with the refractive index of glass.
+    pub fn glass() -> Self {
+        Self::new().with_material(Material {
+            transparency: 1.0,
+            refractive_index: 1.5,
+            ..Material::default()
+        })
+    }
+}
+
+impl Shape for Sphere {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let sphere_to_ray = ray.origin - Point::origin();
+
+        let a = ray.direction.dot(&ray.direction);
+        let b = 2.0 * ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return Intersections::default();
+        }
+
+        let root = discriminant.sqrt();
+        Intersections::new(vec![
+            Intersection::new((-b - root) / (2.0 * a), self),
+            Intersection::new((-b + root) / (2.0 * a), self),
+        ])
+    }
+
+    fn local_normal_at(&self, point: Point) -> Vector {
+        point - Point::origin()
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_1_SQRT_2, PI};
+    use crate::prelude::*;
+
+    // Scenario: A ray intersects a sphere at two points
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0] = 4.0
+    //    And xs[1] = 6.0
+    #[test]
+    fn ray_intersects_sphere_at_two_points() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 6.0);
+    }
+
+    // Scenario: A ray intersects a sphere at a tangent
+    //  Given r ← ray(point(0, 1, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0] = 5.0
+    //    And xs[1] = 5.0
+    #[test]
+    fn ray_intersects_sphere_at_tangent() {
+        let r = Ray::new(Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 5.0);
+        assert_eq!(xs[1].t, 5.0);
+    }
+
+    // Scenario: A ray misses a sphere
+    //  Given r ← ray(point(0, 2, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 0
+    #[test]
+    fn ray_misses_sphere() {
+        let r = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        assert!(s.intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray originates inside a sphere
+    //  Given r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0] = -1.0
+    //    And xs[1] = 1.0
+    #[test]
+    fn ray_originates_inside_sphere() {
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -1.0);
+        assert_eq!(xs[1].t, 1.0);
+    }
+
+    // Scenario: A sphere is behind a ray
+    //  Given r ← ray(point(0, 0, 5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0] = -6.0
+    //    And xs[1] = -4.0
+    #[test]
+    fn sphere_is_behind_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -6.0);
+        assert_eq!(xs[1].t, -4.0);
+    }
+
+    // Scenario: A sphere's default transformation
+    //  Given s ← sphere()
+    //  Then s.transform = identity_matrix
+    #[test]
+    fn sphere_default_transformation() {
+        let s = Sphere::new();
+
+        assert_eq!(*s.transform(), Matrix4::identity());
+    }
+
+    // Scenario: Changing a sphere's transformation
+    //  Given s ← sphere()
+    //    And t ← translation(2, 3, 4)
+    //  When set_transform(s, t)
+    //  Then s.transform = t
+    #[test]
+    fn changing_sphere_transformation() {
+        let mut s = Sphere::new();
+        let t = Matrix4::translation(2.0, 3.0, 4.0);
+        s.set_transform(t);
+
+        assert_eq!(*s.transform(), t);
+    }
+
+    // Scenario: Intersecting a scaled sphere with a ray
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When set_transform(s, scaling(2, 2, 2))
+    //    And xs ← intersect(s, r)
+    //  Then xs.count = 2
+    //    And xs[0].t = 3
+    //    And xs[1].t = 7
+    #[test]
+    fn intersecting_scaled_sphere_with_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 3.0);
+        assert_eq!(xs[1].t, 7.0);
+    }
+
+    // Scenario: Intersecting a translated sphere with a ray
+    //  Given r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And s ← sphere()
+    //  When set_transform(s, translation(5, 0, 0))
+    //    And xs ← intersect(s, r)
+    //  Then xs.count = 0
+    #[test]
+    fn intersecting_translated_sphere_with_ray() {
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Matrix4::translation(5.0, 0.0, 0.0));
+
+        assert!(s.intersect(&r).is_empty());
+    }
+
+    // Scenario: The normal on a sphere at a point on the x axis
+    //  Given s ← sphere()
+    //  When n ← normal_at(s, point(1, 0, 0))
+    //  Then n = vector(1, 0, 0)
+    #[test]
+    fn normal_on_sphere_at_point_on_x_axis() {
+        let s = Sphere::new();
+
+        assert_eq!(
+            s.normal_at(Point::new(1.0, 0.0, 0.0)),
+            Vector::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    // Scenario: The normal on a sphere at a point on the y axis
+    //  Given s ← sphere()
+    //  When n ← normal_at(s, point(0, 1, 0))
+    //  Then n = vector(0, 1, 0)
+    #[test]
+    fn normal_on_sphere_at_point_on_y_axis() {
+        let s = Sphere::new();
+
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 1.0, 0.0)),
+            Vector::new(0.0, 1.0, 0.0)
+        );
+    }
+
+    // Scenario: The normal on a sphere at a point on the z axis
+    //  Given s ← sphere()
+    //  When n ← normal_at(s, point(0, 0, 1))
+    //  Then n = vector(0, 0, 1)
+    #[test]
+    fn normal_on_sphere_at_point_on_z_axis() {
+        let s = Sphere::new();
+
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 0.0, 1.0)),
+            Vector::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    // Scenario: The normal on a sphere at a nonaxial point
+    //  Given s ← sphere()
+    //  When n ← normal_at(s, point(√3/3, √3/3, √3/3))
+    //  Then n = vector(√3/3, √3/3, √3/3)
+    #[test]
+    fn normal_on_sphere_at_nonaxial_point() {
+        let s = Sphere::new();
+        let k = Float::sqrt(3.0) / 3.0;
+
+        assert_eq!(s.normal_at(Point::new(k, k, k)), Vector::new(k, k, k));
+    }
+
+    // Scenario: The normal is a normalized vector
+    //  Given s ← sphere()
+    //  When n ← normal_at(s, point(√3/3, √3/3, √3/3))
+    //  Then n = normalize(n)
+    #[test]
+    fn normal_is_normalized_vector() {
+        let s = Sphere::new();
+        let k = Float::sqrt(3.0) / 3.0;
+        let n = s.normal_at(Point::new(k, k, k));
+
+        assert_eq!(n, n.normalize());
+    }
+
+    // Scenario: Computing the normal on a translated sphere
+    //  Given s ← sphere()
+    //    And set_transform(s, translation(0, 1, 0))
+    //  When n ← normal_at(s, point(0, 1.70711, -0.70711))
+    //  Then n = vector(0, 0.70711, -0.70711)
+    #[test]
+    fn computing_normal_on_translated_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Matrix4::translation(0.0, 1.0, 0.0));
+        let n = s.normal_at(Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+        assert_eq!(n, Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+    }
+
+    // Scenario: Computing the normal on a transformed sphere
+    //  Given s ← sphere()
+    //    And m ← scaling(1, 0.5, 1) * rotation_z(π/5)
+    //    And set_transform(s, m)
+    //  When n ← normal_at(s, point(0, √2/2, -√2/2))
+    //  Then n = vector(0, 0.97014, -0.24254)
+    #[test]
+    fn computing_normal_on_transformed_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Matrix4::scaling(1.0, 0.5, 1.0) * Matrix4::rotation_z(PI / 5.0));
+        let k = Float::sqrt(2.0) / 2.0;
+        let n = s.normal_at(Point::new(0.0, k, -k));
+
+        assert_eq!(n, Vector::new(0.0, 0.97014, -0.24254));
+    }
+
+    // Scenario: A sphere has a default material
+    //  Given s ← sphere()
+    //  When m ← s.material
+    //  Then m = material()
+    #[test]
+    fn sphere_has_default_material() {
+        let s = Sphere::new();
+
+        assert_eq!(*s.material(), Material::default());
+    }
+
+    // Scenario: A sphere may be assigned a material
+    //  Given s ← sphere()
+    //    And m ← material()
+    //    And m.ambient ← 1
+    //  When s.material ← m
+    //  Then s.material = m
+    #[test]
+    fn sphere_may_be_assigned_material() {
+        let mut s = Sphere::new();
+        let m = Material {
+            ambient: 1.0,
+            ..Material::default()
+        };
+        s.set_material(m.clone());
+
+        assert_eq!(*s.material(), m);
+    }
+
+    // Scenario: A helper for producing a sphere with a glassy material
+    //  Given s ← glass_sphere()
+    //  Then s.transform = identity_matrix
+    //    And s.material.transparency = 1.0
+    //    And s.material.refractive_index = 1.5
+    #[test]
+    fn helper_for_producing_glass_sphere() {
+        let s = Sphere::glass();
+
+        assert_eq!(*s.transform(), Matrix4::identity());
+        assert_eq!(s.material().transparency, 1.0);
+        assert_eq!(s.material().refractive_index, 1.5);
+    }
+
+    // Scenario: A sphere has a bounding box
+    //  Given shape ← sphere()
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-1, -1, -1)
+    //    And box.max = point(1, 1, 1)
+    #[test]
+    fn sphere_has_bounding_box() {
+        let b = Sphere::new().bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(b.max, Point::new(1.0, 1.0, 1.0));
+    }
+}
@@ -0,0 +1,674 @@
+use crate::math::consts::PI;
+use crate::prelude::*;
+use alloc::boxed::Box;
+use core::fmt;
+
+/// A color that varies over a flat surface, with coordinates `u` and `v` in [0, 1].
+///
+/// [`TextureMap`] and [`CubeMap`] wrap these around shapes by mapping points of the shape
+/// to (u, v) coordinates.
+pub trait UvPattern: Send + Sync + fmt::Debug {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color;
+}
+
+/// A checkerboard of `width` squares across and `height` squares up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UvCheckers {
+    pub width: Float,
+    pub height: Float,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl UvCheckers {
+    pub fn new(width: Float, height: Float, a: Color, b: Color) -> Self {
+        Self {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+}
+
+impl UvPattern for UvCheckers {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        let u2 = (u * self.width).floor() as i64;
+        let v2 = (v * self.height).floor() as i64;
+        if (u2 + v2) % 2 == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// A `main` color with a different square in each corner, to check how a mapping is
+/// oriented.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UvAlignCheck {
+    pub main: Color,
+    pub upper_left: Color,
+    pub upper_right: Color,
+    pub bottom_left: Color,
+    pub bottom_right: Color,
+}
+
+impl UvAlignCheck {
+    pub fn new(
+        main: Color,
+        upper_left: Color,
+        upper_right: Color,
+        bottom_left: Color,
+        bottom_right: Color,
+    ) -> Self {
+        Self {
+            main,
+            upper_left,
+            upper_right,
+            bottom_left,
+            bottom_right,
+        }
+    }
+}
+
+impl UvPattern for UvAlignCheck {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        if v > 0.8 {
+            if u < 0.2 {
+                return self.upper_left;
+            }
+            if u > 0.8 {
+                return self.upper_right;
+            }
+        } else if v < 0.2 {
+            if u < 0.2 {
+                return self.bottom_left;
+            }
+            if u > 0.8 {
+                return self.bottom_right;
+            }
+        }
+        self.main
+    }
+}
+
+/// An image stretched over the whole (u, v) square, with `v` going up from its bottom row.
+#[derive(Debug, Clone)]
+pub struct UvImage {
+    canvas: Canvas,
+}
+
+impl UvImage {
+    /// # Panics
+    ///
+    /// Panics if the canvas has no pixels, since there would be no color to show.
+    pub fn new(canvas: Canvas) -> Self {
+        Self::try_new(canvas).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Texture like [`UvImage::new`], failing instead of panicking on an empty canvas.
+    pub fn try_new(canvas: Canvas) -> Result<Self> {
+        if canvas.width() == 0 || canvas.height() == 0 {
+            return Err(RustracerError::InvalidArgument(
+                "an image texture needs at least one pixel",
+            ));
+        }
+        Ok(Self { canvas })
+    }
+
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+}
+
+impl UvPattern for UvImage {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        // Rows of the canvas go down while v goes up.
+        let x = u * (self.canvas.width() - 1) as Float;
+        let y = (1.0 - v) * (self.canvas.height() - 1) as Float;
+        let column = ((x + 0.5).floor() as usize).min(self.canvas.width() - 1);
+        let row = ((y + 0.5).floor() as usize).min(self.canvas.height() - 1);
+        self.canvas.pixel_at(column, row)
+    }
+}
+
+/// Fractional part of `x / m`, scaled back to [0, m), for wrapping textures that repeat.
+fn wrap(x: Float, m: Float) -> Float {
+    x - m * (x / m).floor()
+}
+
+/// (u, v) coordinates of a point on the unit sphere, from its longitude and latitude.
+pub fn spherical_map(point: Point) -> (Float, Float) {
+    // Longitude, going counterclockwise when seen from above.
+    let theta = point.x.atan2(point.z);
+    let radius = Vector::new(point.x, point.y, point.z).magnitude();
+    let phi = (point.y / radius).acos();
+    let raw_u = theta / (2.0 * PI);
+    (1.0 - (raw_u + 0.5), 1.0 - phi / PI)
+}
+
+/// (u, v) coordinates of a point of the xz plane, repeating every unit.
+pub fn planar_map(point: Point) -> (Float, Float) {
+    (wrap(point.x, 1.0), wrap(point.z, 1.0))
+}
+
+/// (u, v) coordinates of a point on the unit cylinder around the y axis, with `v`
+/// repeating every unit of height.
+pub fn cylindrical_map(point: Point) -> (Float, Float) {
+    let theta = point.x.atan2(point.z);
+    let raw_u = theta / (2.0 * PI);
+    (1.0 - (raw_u + 0.5), wrap(point.y, 1.0))
+}
+
+/// (u, v) coordinates of a point on the unit cube, on whichever face it lies.
+pub fn cubic_map(point: Point) -> (Float, Float) {
+    CubeFace::from_point(point).uv(point)
+}
+
+/// How the points of a shape are projected onto a [`UvPattern`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UvMapping {
+    /// See [`spherical_map`].
+    Spherical,
+    /// See [`planar_map`].
+    Planar,
+    /// See [`cylindrical_map`].
+    Cylindrical,
+    /// See [`cubic_map`]; every face shows the whole pattern.
+    Cubic,
+}
+
+impl UvMapping {
+    pub fn map(self, point: Point) -> (Float, Float) {
+        match self {
+            UvMapping::Spherical => spherical_map(point),
+            UvMapping::Planar => planar_map(point),
+            UvMapping::Cylindrical => cylindrical_map(point),
+            UvMapping::Cubic => cubic_map(point),
+        }
+    }
+}
+
+/// One of the faces of the unit cube, as seen from outside it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Front,
+    Right,
+    Back,
+    Up,
+    Down,
+}
+
+impl CubeFace {
+    /// The face a point is on, or closest to.
+    pub fn from_point(point: Point) -> Self {
+        let coord = point.x.abs().max(point.y.abs()).max(point.z.abs());
+        if coord == point.x {
+            CubeFace::Right
+        } else if coord == -point.x {
+            CubeFace::Left
+        } else if coord == point.y {
+            CubeFace::Up
+        } else if coord == -point.y {
+            CubeFace::Down
+        } else if coord == point.z {
+            CubeFace::Front
+        } else {
+            CubeFace::Back
+        }
+    }
+
+    /// (u, v) coordinates of a point on this face, with `v` going up and the faces
+    /// laid out as a cross unfolded around the front one.
+    pub fn uv(self, point: Point) -> (Float, Float) {
+        let Point { x, y, z, .. } = point;
+        let (u, v) = match self {
+            CubeFace::Front => (x + 1.0, y + 1.0),
+            CubeFace::Back => (1.0 - x, y + 1.0),
+            CubeFace::Left => (z + 1.0, y + 1.0),
+            CubeFace::Right => (1.0 - z, y + 1.0),
+            CubeFace::Up => (x + 1.0, 1.0 - z),
+            CubeFace::Down => (x + 1.0, z + 1.0),
+        };
+        (wrap(u, 2.0) / 2.0, wrap(v, 2.0) / 2.0)
+    }
+}
+
+/// A [`UvPattern`] wrapped around a shape through a [`UvMapping`].
+#[derive(Debug)]
+pub struct TextureMap {
+    properties: PatternProperties,
+    uv_pattern: Box<dyn UvPattern>,
+    mapping: UvMapping,
+}
+
+impl TextureMap {
+    pub fn new<U: UvPattern + 'static>(uv_pattern: U, mapping: UvMapping) -> Self {
+        Self::from_boxed(Box::new(uv_pattern), mapping)
+    }
+
+    /// Builds a texture map from a pattern that is already boxed, e.g. one whose type is
+    /// only known at runtime.
+    pub fn from_boxed(uv_pattern: Box<dyn UvPattern>, mapping: UvMapping) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            uv_pattern,
+            mapping,
+        }
+    }
+
+    pub fn uv_pattern(&self) -> &dyn UvPattern {
+        self.uv_pattern.as_ref()
+    }
+
+    pub fn mapping(&self) -> UvMapping {
+        self.mapping
+    }
+}
+
+impl Pattern for TextureMap {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let (u, v) = self.mapping.map(point);
+        self.uv_pattern.uv_pattern_at(u, v)
+    }
+}
+
+/// A different [`UvPattern`] on each face of the unit cube, e.g. to make a skybox.
+#[derive(Debug)]
+pub struct CubeMap {
+    properties: PatternProperties,
+    /// Indexed by [`CubeFace`].
+    faces: [Box<dyn UvPattern>; 6],
+}
+
+impl CubeMap {
+    /// Faces are given in the order of [`CubeFace`]: left, front, right, back, up, down.
+    pub fn new(faces: [Box<dyn UvPattern>; 6]) -> Self {
+        Self {
+            properties: PatternProperties::new(),
+            faces,
+        }
+    }
+
+    pub fn face(&self, face: CubeFace) -> &dyn UvPattern {
+        self.faces[face as usize].as_ref()
+    }
+}
+
+impl Pattern for CubeMap {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let face = CubeFace::from_point(point);
+        let (u, v) = face.uv(point);
+        self.face(face).uv_pattern_at(u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::FRAC_1_SQRT_2;
+    use crate::prelude::*;
+    use alloc::boxed::Box;
+
+    // Scenario Outline: Checker pattern in 2D
+    //  Given checkers ← uv_checkers(2, 2, black, white)
+    //  When color ← uv_pattern_at(checkers, <u>, <v>)
+    //  Then color = <expected>
+    #[test]
+    fn checker_pattern_in_2d() {
+        let checkers = UvCheckers::new(2.0, 2.0, Color::black(), Color::white());
+
+        let examples = [
+            (0.0, 0.0, Color::black()),
+            (0.5, 0.0, Color::white()),
+            (0.0, 0.5, Color::white()),
+            (0.5, 0.5, Color::black()),
+            (1.0, 1.0, Color::black()),
+        ];
+        for &(u, v, expected) in &examples {
+            assert_eq!(checkers.uv_pattern_at(u, v), expected);
+        }
+    }
+
+    // Scenario Outline: Using a spherical mapping on a 3D point
+    //  Given p ← <point>
+    //  When (u, v) ← spherical_map(p)
+    //  Then u = <u>
+    //    And v = <v>
+    #[test]
+    fn using_spherical_mapping_on_3d_point() {
+        let examples = [
+            (Point::new(0.0, 0.0, -1.0), 0.0, 0.5),
+            (Point::new(1.0, 0.0, 0.0), 0.25, 0.5),
+            (Point::new(0.0, 0.0, 1.0), 0.5, 0.5),
+            (Point::new(-1.0, 0.0, 0.0), 0.75, 0.5),
+            (Point::new(0.0, 1.0, 0.0), 0.5, 1.0),
+            (Point::new(0.0, -1.0, 0.0), 0.5, 0.0),
+            (Point::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), 0.25, 0.75),
+        ];
+        for &(p, u, v) in &examples {
+            let (pu, pv) = spherical_map(p);
+
+            assert!(is_approx(pu, u, None), "u of {:?}", p);
+            assert!(is_approx(pv, v, None), "v of {:?}", p);
+        }
+    }
+
+    // Scenario Outline: Using a texture map pattern with a spherical map
+    //  Given checkers ← uv_checkers(16, 8, black, white)
+    //    And pattern ← texture_map(checkers, spherical_map)
+    //  Then pattern_at(pattern, <point>) = <color>
+    #[test]
+    fn using_texture_map_pattern_with_spherical_map() {
+        let checkers = UvCheckers::new(16.0, 8.0, Color::black(), Color::white());
+        let pattern = TextureMap::new(checkers, UvMapping::Spherical);
+
+        let examples = [
+            (Point::new(0.4315, 0.4670, 0.7719), Color::white()),
+            (Point::new(-0.9654, 0.2552, -0.0534), Color::black()),
+            (Point::new(0.1039, 0.7090, 0.6975), Color::white()),
+            (Point::new(-0.4986, -0.7856, -0.3663), Color::black()),
+            (Point::new(-0.0317, -0.9395, 0.3411), Color::black()),
+            (Point::new(0.4809, -0.7721, 0.4154), Color::black()),
+            (Point::new(0.0285, -0.9612, -0.2745), Color::black()),
+            (Point::new(-0.5734, -0.2162, -0.7903), Color::white()),
+            (Point::new(0.7688, -0.1470, 0.6223), Color::black()),
+            (Point::new(-0.7652, 0.2175, 0.6060), Color::black()),
+        ];
+        for &(p, color) in &examples {
+            assert_eq!(pattern.local_pattern_at(p), color, "at {:?}", p);
+        }
+    }
+
+    // Scenario Outline: Using a planar mapping on a 3D point
+    //  Given p ← <point>
+    //  When (u, v) ← planar_map(p)
+    //  Then u = <u>
+    //    And v = <v>
+    #[test]
+    fn using_planar_mapping_on_3d_point() {
+        let examples = [
+            (Point::new(0.25, 0.0, 0.5), 0.25, 0.5),
+            (Point::new(0.25, 0.0, -0.25), 0.25, 0.75),
+            (Point::new(0.25, 0.5, -0.25), 0.25, 0.75),
+            (Point::new(1.25, 0.0, 0.5), 0.25, 0.5),
+            (Point::new(0.25, 0.0, -1.75), 0.25, 0.25),
+            (Point::new(1.0, 0.0, -1.0), 0.0, 0.0),
+            (Point::new(0.0, 0.0, 0.0), 0.0, 0.0),
+        ];
+        for &(p, u, v) in &examples {
+            assert_eq!(planar_map(p), (u, v), "at {:?}", p);
+        }
+    }
+
+    // Scenario Outline: Using a cylindrical mapping on a 3D point
+    //  Given p ← <point>
+    //  When (u, v) ← cylindrical_map(p)
+    //  Then u = <u>
+    //    And v = <v>
+    #[test]
+    fn using_cylindrical_mapping_on_3d_point() {
+        let examples = [
+            (Point::new(0.0, 0.0, -1.0), 0.0, 0.0),
+            (Point::new(0.0, 0.5, -1.0), 0.0, 0.5),
+            (Point::new(0.0, 1.0, -1.0), 0.0, 0.0),
+            (Point::new(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), 0.125, 0.5),
+            (Point::new(1.0, 0.5, 0.0), 0.25, 0.5),
+            (Point::new(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), 0.375, 0.5),
+            (Point::new(0.0, -0.25, 1.0), 0.5, 0.75),
+            (Point::new(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), 0.625, 0.5),
+            (Point::new(-1.0, 1.25, 0.0), 0.75, 0.25),
+            (Point::new(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), 0.875, 0.5),
+        ];
+        for &(p, u, v) in &examples {
+            let (pu, pv) = cylindrical_map(p);
+
+            assert!(is_approx(pu, u, None), "u of {:?}", p);
+            assert!(is_approx(pv, v, None), "v of {:?}", p);
+        }
+    }
+
+    fn red() -> Color {
+        Color::new(1.0, 0.0, 0.0)
+    }
+
+    fn yellow() -> Color {
+        Color::new(1.0, 1.0, 0.0)
+    }
+
+    fn brown() -> Color {
+        Color::new(1.0, 0.5, 0.0)
+    }
+
+    fn green() -> Color {
+        Color::new(0.0, 1.0, 0.0)
+    }
+
+    fn cyan() -> Color {
+        Color::new(0.0, 1.0, 1.0)
+    }
+
+    fn blue() -> Color {
+        Color::new(0.0, 0.0, 1.0)
+    }
+
+    fn purple() -> Color {
+        Color::new(1.0, 0.0, 1.0)
+    }
+
+    // Scenario Outline: Layout of the "align check" pattern
+    //  Given main ← color(1, 1, 1)
+    //    And ul ← color(1, 0, 0)
+    //    And ur ← color(1, 1, 0)
+    //    And bl ← color(0, 1, 0)
+    //    And br ← color(0, 1, 1)
+    //    And pattern ← uv_align_check(main, ul, ur, bl, br)
+    //  When c ← uv_pattern_at(pattern, <u>, <v>)
+    //  Then c = <expected>
+    #[test]
+    fn layout_of_align_check_pattern() {
+        let pattern = UvAlignCheck::new(Color::white(), red(), yellow(), green(), cyan());
+
+        let examples = [
+            (0.5, 0.5, Color::white()),
+            (0.1, 0.9, red()),
+            (0.9, 0.9, yellow()),
+            (0.1, 0.1, green()),
+            (0.9, 0.1, cyan()),
+        ];
+        for &(u, v, expected) in &examples {
+            assert_eq!(pattern.uv_pattern_at(u, v), expected);
+        }
+    }
+
+    // Scenario Outline: Identifying the face of a cube from a point
+    //  When face ← face_from_point(<point>)
+    //  Then face = <face>
+    #[test]
+    fn identifying_face_of_cube_from_point() {
+        let examples = [
+            (Point::new(-1.0, 0.5, -0.25), CubeFace::Left),
+            (Point::new(1.1, -0.75, 0.8), CubeFace::Right),
+            (Point::new(0.1, 0.6, 0.9), CubeFace::Front),
+            (Point::new(-0.7, 0.0, -2.0), CubeFace::Back),
+            (Point::new(0.5, 1.0, 0.9), CubeFace::Up),
+            (Point::new(-0.2, -1.3, 1.1), CubeFace::Down),
+        ];
+        for &(p, face) in &examples {
+            assert_eq!(CubeFace::from_point(p), face, "at {:?}", p);
+        }
+    }
+
+    // Scenario Outline: UV mapping the faces of a cube
+    //  When (u, v) ← cube_uv_<face>(<point>)
+    //  Then u = <u>
+    //    And v = <v>
+    #[test]
+    fn uv_mapping_faces_of_cube() {
+        let examples = [
+            (CubeFace::Front, Point::new(-0.5, 0.5, 1.0), 0.25, 0.75),
+            (CubeFace::Front, Point::new(0.5, -0.5, 1.0), 0.75, 0.25),
+            (CubeFace::Back, Point::new(0.5, 0.5, -1.0), 0.25, 0.75),
+            (CubeFace::Back, Point::new(-0.5, -0.5, -1.0), 0.75, 0.25),
+            (CubeFace::Left, Point::new(-1.0, 0.5, -0.5), 0.25, 0.75),
+            (CubeFace::Left, Point::new(-1.0, -0.5, 0.5), 0.75, 0.25),
+            (CubeFace::Right, Point::new(1.0, 0.5, 0.5), 0.25, 0.75),
+            (CubeFace::Right, Point::new(1.0, -0.5, -0.5), 0.75, 0.25),
+            (CubeFace::Up, Point::new(-0.5, 1.0, -0.5), 0.25, 0.75),
+            (CubeFace::Up, Point::new(0.5, 1.0, 0.5), 0.75, 0.25),
+            (CubeFace::Down, Point::new(-0.5, -1.0, 0.5), 0.25, 0.75),
+            (CubeFace::Down, Point::new(0.5, -1.0, -0.5), 0.75, 0.25),
+        ];
+        for &(face, p, u, v) in &examples {
+            assert_eq!(face.uv(p), (u, v), "on {:?} at {:?}", face, p);
+        }
+    }
+
+    // Scenario Outline: Finding the colors on a mapped cube
+    //  When left ← uv_align_check(yellow, cyan, red, blue, brown)
+    //    And front ← uv_align_check(cyan, red, yellow, brown, green)
+    //    And right ← uv_align_check(red, yellow, purple, green, white)
+    //    And back ← uv_align_check(green, purple, cyan, white, blue)
+    //    And up ← uv_align_check(brown, cyan, purple, red, yellow)
+    //    And down ← uv_align_check(purple, brown, green, blue, white)
+    //    And pattern ← cube_map(left, front, right, back, up, down)
+    //  Then pattern_at(pattern, <point>) = <color>
+    #[test]
+    fn finding_colors_on_mapped_cube() {
+        let white = Color::white();
+        let check = |main, ul, ur, bl, br| -> Box<dyn UvPattern> {
+            Box::new(UvAlignCheck::new(main, ul, ur, bl, br))
+        };
+        let pattern = CubeMap::new([
+            check(yellow(), cyan(), red(), blue(), brown()),
+            check(cyan(), red(), yellow(), brown(), green()),
+            check(red(), yellow(), purple(), green(), white),
+            check(green(), purple(), cyan(), white, blue()),
+            check(brown(), cyan(), purple(), red(), yellow()),
+            check(purple(), brown(), green(), blue(), white),
+        ]);
+
+        let examples = [
+            // left
+            (Point::new(-1.0, 0.0, 0.0), yellow()),
+            (Point::new(-1.0, 0.9, -0.9), cyan()),
+            (Point::new(-1.0, 0.9, 0.9), red()),
+            (Point::new(-1.0, -0.9, -0.9), blue()),
+            (Point::new(-1.0, -0.9, 0.9), brown()),
+            // front
+            (Point::new(0.0, 0.0, 1.0), cyan()),
+            (Point::new(-0.9, 0.9, 1.0), red()),
+            (Point::new(0.9, 0.9, 1.0), yellow()),
+            (Point::new(-0.9, -0.9, 1.0), brown()),
+            (Point::new(0.9, -0.9, 1.0), green()),
+            // right
+            (Point::new(1.0, 0.0, 0.0), red()),
+            (Point::new(1.0, 0.9, 0.9), yellow()),
+            (Point::new(1.0, 0.9, -0.9), purple()),
+            (Point::new(1.0, -0.9, 0.9), green()),
+            (Point::new(1.0, -0.9, -0.9), white),
+            // back
+            (Point::new(0.0, 0.0, -1.0), green()),
+            (Point::new(0.9, 0.9, -1.0), purple()),
+            (Point::new(-0.9, 0.9, -1.0), cyan()),
+            (Point::new(0.9, -0.9, -1.0), white),
+            (Point::new(-0.9, -0.9, -1.0), blue()),
+            // up
+            (Point::new(0.0, 1.0, 0.0), brown()),
+            (Point::new(-0.9, 1.0, -0.9), cyan()),
+            (Point::new(0.9, 1.0, -0.9), purple()),
+            (Point::new(-0.9, 1.0, 0.9), red()),
+            (Point::new(0.9, 1.0, 0.9), yellow()),
+            // down
+            (Point::new(0.0, -1.0, 0.0), purple()),
+            (Point::new(-0.9, -1.0, 0.9), brown()),
+            (Point::new(0.9, -1.0, 0.9), green()),
+            (Point::new(-0.9, -1.0, -0.9), blue()),
+            (Point::new(0.9, -1.0, -0.9), white),
+        ];
+        for &(p, color) in &examples {
+            assert_eq!(pattern.local_pattern_at(p), color, "at {:?}", p);
+        }
+    }
+
+    // Scenario Outline: uv_pattern_at(uv_image) returns the color at the u,v coordinates
+    //  Given ppm ← a file containing:
+    //    ...a 10x10 gradient...
+    //    And canvas ← canvas_from_ppm(ppm)
+    //    And pattern ← uv_image(canvas)
+    //  When color ← uv_pattern_at(pattern, <u>, <v>)
+    //  Then color = <expected>
+    #[test]
+    fn uv_image_returns_color_at_uv_coordinates() {
+        let mut canvas = Canvas::new(10, 10);
+        for y in 0..10 {
+            for x in 0..10 {
+                let value = (x + y) as Float / 18.0;
+                canvas.write_pixel(x, y, Color::new(value, value, value));
+            }
+        }
+        let pattern = UvImage::new(canvas);
+        let gray = |pixels: usize| {
+            let value = pixels as Float / 18.0;
+            Color::new(value, value, value)
+        };
+
+        assert_eq!(pattern.uv_pattern_at(0.0, 0.0), gray(9));
+        assert_eq!(pattern.uv_pattern_at(0.3, 0.0), gray(12));
+        assert_eq!(pattern.uv_pattern_at(0.6, 0.3), gray(11));
+        assert_eq!(pattern.uv_pattern_at(1.0, 1.0), gray(9));
+        assert_eq!(pattern.uv_pattern_at(0.0, 1.0), gray(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn uv_image_of_empty_canvas_panics() {
+        UvImage::new(Canvas::new(0, 0));
+    }
+
+    #[test]
+    fn trying_uv_image_of_empty_canvas_fails() {
+        assert!(matches!(
+            UvImage::try_new(Canvas::new(3, 0)),
+            Err(RustracerError::InvalidArgument(_))
+        ));
+        assert!(UvImage::try_new(Canvas::new(1, 1)).is_ok());
+    }
+
+    #[test]
+    fn texture_maps_follow_pattern_and_object_transformations() {
+        let pattern = TextureMap::new(
+            UvCheckers::new(2.0, 2.0, Color::black(), Color::white()),
+            UvMapping::Planar,
+        )
+        .with_transform(Matrix4::scaling(2.0, 2.0, 2.0));
+        let object = Plane::new().with_transform(Matrix4::translation(5.0, 0.0, 0.0));
+
+        assert_eq!(pattern.mapping(), UvMapping::Planar);
+        assert_eq!(
+            pattern.pattern_at_shape(&object, Point::new(5.5, 0.0, 0.5)),
+            Color::black()
+        );
+        assert_eq!(
+            pattern.pattern_at_shape(&object, Point::new(6.5, 0.0, 0.5)),
+            Color::white()
+        );
+    }
+}
@@ -0,0 +1,484 @@
+use crate::prelude::*;
+
+impl Matrix4 {
+    pub fn translation(x: Float, y: Float, z: Float) -> Self {
+        Self::from_rows([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn scaling(x: Float, y: Float, z: Float) -> Self {
+        Self::from_rows([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Rotation around the x axis, following the left-hand rule.
+    pub fn rotation_x(radians: Float) -> Self {
+        let (sin, cos) = (radians.sin(), radians.cos());
+        Self::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Rotation around the y axis, following the left-hand rule.
+    pub fn rotation_y(radians: Float) -> Self {
+        let (sin, cos) = (radians.sin(), radians.cos());
+        Self::from_rows([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Rotation around the z axis, following the left-hand rule.
+    pub fn rotation_z(radians: Float) -> Self {
+        let (sin, cos) = (radians.sin(), radians.cos());
+        Self::from_rows([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Moves each component in proportion to the other two, e.g. `xy` is how much x moves per unit of y.
+    pub fn shearing(xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
+        Self::from_rows([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Transformation for an eye at `from` looking at `to`, with `up` roughly pointing upward.
+    pub fn view_transform(from: Point, to: Point, up: Vector) -> Self {
+        let forward = (to - from).normalize();
+        let left = forward.cross(&up.normalize());
+        let true_up = left.cross(&forward);
+        let orientation = Self::from_rows([
+            [left.x, left.y, left.z, 0.0],
+            [true_up.x, true_up.y, true_up.z, 0.0],
+            [-forward.x, -forward.y, -forward.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        orientation * Self::translation(-from.x, -from.y, -from.z)
+    }
+}
+
+/// Builds a transformation by chaining operations in the order they are applied.
+///
+/// `Transform::identity().rotate_x(a).scale(x, y, z).translate(x, y, z)` rotates first
+/// and translates last, i.e. it is the matrix `T * S * R`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform {
+    matrix: Matrix4,
+}
+
+impl Transform {
+    pub const fn identity() -> Self {
+        Self {
+            matrix: Matrix4::identity(),
+        }
+    }
+
+    /// Applies `m` after the transformations chained so far.
+    pub fn then(self, m: Matrix4) -> Self {
+        Self {
+            matrix: m * self.matrix,
+        }
+    }
+
+    pub fn translate(self, x: Float, y: Float, z: Float) -> Self {
+        self.then(Matrix4::translation(x, y, z))
+    }
+
+    pub fn scale(self, x: Float, y: Float, z: Float) -> Self {
+        self.then(Matrix4::scaling(x, y, z))
+    }
+
+    pub fn rotate_x(self, radians: Float) -> Self {
+        self.then(Matrix4::rotation_x(radians))
+    }
+
+    pub fn rotate_y(self, radians: Float) -> Self {
+        self.then(Matrix4::rotation_y(radians))
+    }
+
+    pub fn rotate_z(self, radians: Float) -> Self {
+        self.then(Matrix4::rotation_z(radians))
+    }
+
+    pub fn shear(self, xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
+        self.then(Matrix4::shearing(xy, xz, yx, yz, zx, zy))
+    }
+
+    pub fn matrix(&self) -> Matrix4 {
+        self.matrix
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Transform> for Matrix4 {
+    fn from(t: Transform) -> Self {
+        t.matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::PI;
+    use crate::prelude::*;
+
+    // Scenario: Multiplying by a translation matrix
+    //  Given transform ← translation(5, -3, 2)
+    //    And p ← point(-3, 4, 5)
+    //   Then transform * p = point(2, 1, 7)
+    #[test]
+    fn multiplying_by_translation_matrix() {
+        let transform = Matrix4::translation(5.0, -3.0, 2.0);
+        let p = Point::new(-3.0, 4.0, 5.0);
+
+        assert_eq!(transform * p, Point::new(2.0, 1.0, 7.0));
+    }
+
+    // Scenario: Multiplying by the inverse of a translation matrix
+    //  Given transform ← translation(5, -3, 2)
+    //    And inv ← inverse(transform)
+    //    And p ← point(-3, 4, 5)
+    //   Then inv * p = point(-8, 7, 3)
+    #[test]
+    fn multiplying_by_inverse_of_translation_matrix() {
+        let inv = Matrix4::translation(5.0, -3.0, 2.0).inverse().unwrap();
+        let p = Point::new(-3.0, 4.0, 5.0);
+
+        assert_eq!(inv * p, Point::new(-8.0, 7.0, 3.0));
+    }
+
+    // Scenario: Translation does not affect vectors
+    //  Given transform ← translation(5, -3, 2)
+    //    And v ← vector(-3, 4, 5)
+    //   Then transform * v = v
+    #[test]
+    fn translation_does_not_affect_vectors() {
+        let transform = Matrix4::translation(5.0, -3.0, 2.0);
+        let v = Vector::new(-3.0, 4.0, 5.0);
+
+        assert_eq!(transform * v, v);
+    }
+
+    // Scenario: A scaling matrix applied to a point
+    //  Given transform ← scaling(2, 3, 4)
+    //    And p ← point(-4, 6, 8)
+    //   Then transform * p = point(-8, 18, 32)
+    #[test]
+    fn scaling_matrix_applied_to_point() {
+        let transform = Matrix4::scaling(2.0, 3.0, 4.0);
+        let p = Point::new(-4.0, 6.0, 8.0);
+
+        assert_eq!(transform * p, Point::new(-8.0, 18.0, 32.0));
+    }
+
+    // Scenario: A scaling matrix applied to a vector
+    //  Given transform ← scaling(2, 3, 4)
+    //    And v ← vector(-4, 6, 8)
+    //   Then transform * v = vector(-8, 18, 32)
+    #[test]
+    fn scaling_matrix_applied_to_vector() {
+        let transform = Matrix4::scaling(2.0, 3.0, 4.0);
+        let v = Vector::new(-4.0, 6.0, 8.0);
+
+        assert_eq!(transform * v, Vector::new(-8.0, 18.0, 32.0));
+    }
+
+    // Scenario: Multiplying by the inverse of a scaling matrix
+    //  Given transform ← scaling(2, 3, 4)
+    //    And inv ← inverse(transform)
+    //    And v ← vector(-4, 6, 8)
+    //   Then inv * v = vector(-2, 2, 2)
+    #[test]
+    fn multiplying_by_inverse_of_scaling_matrix() {
+        let inv = Matrix4::scaling(2.0, 3.0, 4.0).inverse().unwrap();
+        let v = Vector::new(-4.0, 6.0, 8.0);
+
+        assert_eq!(inv * v, Vector::new(-2.0, 2.0, 2.0));
+    }
+
+    // Scenario: Reflection is scaling by a negative value
+    //  Given transform ← scaling(-1, 1, 1)
+    //    And p ← point(2, 3, 4)
+    //   Then transform * p = point(-2, 3, 4)
+    #[test]
+    fn reflection_is_scaling_by_negative_value() {
+        let transform = Matrix4::scaling(-1.0, 1.0, 1.0);
+        let p = Point::new(2.0, 3.0, 4.0);
+
+        assert_eq!(transform * p, Point::new(-2.0, 3.0, 4.0));
+    }
+
+    // Scenario: Rotating a point around the x axis
+    //  Given p ← point(0, 1, 0)
+    //    And half_quarter ← rotation_x(π / 4)
+    //    And full_quarter ← rotation_x(π / 2)
+    //  Then half_quarter * p = point(0, √2/2, √2/2)
+    //    And full_quarter * p = point(0, 0, 1)
+    #[test]
+    fn rotating_point_around_x_axis() {
+        let p = Point::new(0.0, 1.0, 0.0);
+        let half_quarter = Matrix4::rotation_x(PI / 4.0);
+        let full_quarter = Matrix4::rotation_x(PI / 2.0);
+        let s = Float::sqrt(2.0) / 2.0;
+
+        assert_eq!(half_quarter * p, Point::new(0.0, s, s));
+        assert_eq!(full_quarter * p, Point::new(0.0, 0.0, 1.0));
+    }
+
+    // Scenario: The inverse of an x-rotation rotates in the opposite direction
+    //  Given p ← point(0, 1, 0)
+    //    And half_quarter ← rotation_x(π / 4)
+    //    And inv ← inverse(half_quarter)
+    //  Then inv * p = point(0, √2/2, -√2/2)
+    #[test]
+    fn inverse_of_x_rotation_rotates_in_opposite_direction() {
+        let p = Point::new(0.0, 1.0, 0.0);
+        let inv = Matrix4::rotation_x(PI / 4.0).inverse().unwrap();
+        let s = Float::sqrt(2.0) / 2.0;
+
+        assert_eq!(inv * p, Point::new(0.0, s, -s));
+    }
+
+    // Scenario: Rotating a point around the y axis
+    //  Given p ← point(0, 0, 1)
+    //    And half_quarter ← rotation_y(π / 4)
+    //    And full_quarter ← rotation_y(π / 2)
+    //  Then half_quarter * p = point(√2/2, 0, √2/2)
+    //    And full_quarter * p = point(1, 0, 0)
+    #[test]
+    fn rotating_point_around_y_axis() {
+        let p = Point::new(0.0, 0.0, 1.0);
+        let half_quarter = Matrix4::rotation_y(PI / 4.0);
+        let full_quarter = Matrix4::rotation_y(PI / 2.0);
+        let s = Float::sqrt(2.0) / 2.0;
+
+        assert_eq!(half_quarter * p, Point::new(s, 0.0, s));
+        assert_eq!(full_quarter * p, Point::new(1.0, 0.0, 0.0));
+    }
+
+    // Scenario: Rotating a point around the z axis
+    //  Given p ← point(0, 1, 0)
+    //    And half_quarter ← rotation_z(π / 4)
+    //    And full_quarter ← rotation_z(π / 2)
+    //  Then half_quarter * p = point(-√2/2, √2/2, 0)
+    //    And full_quarter * p = point(-1, 0, 0)
+    #[test]
+    fn rotating_point_around_z_axis() {
+        let p = Point::new(0.0, 1.0, 0.0);
+        let half_quarter = Matrix4::rotation_z(PI / 4.0);
+        let full_quarter = Matrix4::rotation_z(PI / 2.0);
+        let s = Float::sqrt(2.0) / 2.0;
+
+        assert_eq!(half_quarter * p, Point::new(-s, s, 0.0));
+        assert_eq!(full_quarter * p, Point::new(-1.0, 0.0, 0.0));
+    }
+
+    // Scenario Outline: A shearing transformation moves a coordinate in proportion to the others
+    //  Given transform ← shearing(<xy>, <xz>, <yx>, <yz>, <zx>, <zy>)
+    //    And p ← point(2, 3, 4)
+    //   Then transform * p = point(<x>, <y>, <z>)
+    //
+    //  Examples:
+    //    | xy | xz | yx | yz | zx | zy | x | y | z |
+    //    | 1  | 0  | 0  | 0  | 0  | 0  | 5 | 3 | 4 |
+    //    | 0  | 1  | 0  | 0  | 0  | 0  | 6 | 3 | 4 |
+    //    | 0  | 0  | 1  | 0  | 0  | 0  | 2 | 5 | 4 |
+    //    | 0  | 0  | 0  | 1  | 0  | 0  | 2 | 7 | 4 |
+    //    | 0  | 0  | 0  | 0  | 1  | 0  | 2 | 3 | 6 |
+    //    | 0  | 0  | 0  | 0  | 0  | 1  | 2 | 3 | 7 |
+    #[test]
+    fn shearing_moves_coordinate_in_proportion_to_others() {
+        let p = Point::new(2.0, 3.0, 4.0);
+        let examples = [
+            ([1.0, 0.0, 0.0, 0.0, 0.0, 0.0], Point::new(5.0, 3.0, 4.0)),
+            ([0.0, 1.0, 0.0, 0.0, 0.0, 0.0], Point::new(6.0, 3.0, 4.0)),
+            ([0.0, 0.0, 1.0, 0.0, 0.0, 0.0], Point::new(2.0, 5.0, 4.0)),
+            ([0.0, 0.0, 0.0, 1.0, 0.0, 0.0], Point::new(2.0, 7.0, 4.0)),
+            ([0.0, 0.0, 0.0, 0.0, 1.0, 0.0], Point::new(2.0, 3.0, 6.0)),
+            ([0.0, 0.0, 0.0, 0.0, 0.0, 1.0], Point::new(2.0, 3.0, 7.0)),
+        ];
+
+        for &([xy, xz, yx, yz, zx, zy], expected) in &examples {
+            let transform = Matrix4::shearing(xy, xz, yx, yz, zx, zy);
+            assert_eq!(transform * p, expected);
+        }
+    }
+
+    // Scenario: Individual transformations are applied in sequence
+    //  Given p ← point(1, 0, 1)
+    //    And A ← rotation_x(π / 2)
+    //    And B ← scaling(5, 5, 5)
+    //    And C ← translation(10, 5, 7)
+    //  # apply rotation first
+    //  When p2 ← A * p
+    //  Then p2 = point(1, -1, 0)
+    //  # then apply scaling
+    //  When p3 ← B * p2
+    //  Then p3 = point(5, -5, 0)
+    //  # then apply translation
+    //  When p4 ← C * p3
+    //  Then p4 = point(15, 0, 7)
+    #[test]
+    fn individual_transformations_are_applied_in_sequence() {
+        let p = Point::new(1.0, 0.0, 1.0);
+        let a = Matrix4::rotation_x(PI / 2.0);
+        let b = Matrix4::scaling(5.0, 5.0, 5.0);
+        let c = Matrix4::translation(10.0, 5.0, 7.0);
+
+        let p2 = a * p;
+        assert_eq!(p2, Point::new(1.0, -1.0, 0.0));
+        let p3 = b * p2;
+        assert_eq!(p3, Point::new(5.0, -5.0, 0.0));
+        let p4 = c * p3;
+        assert_eq!(p4, Point::new(15.0, 0.0, 7.0));
+    }
+
+    // Scenario: Chained transformations must be applied in reverse order
+    //  Given p ← point(1, 0, 1)
+    //    And A ← rotation_x(π / 2)
+    //    And B ← scaling(5, 5, 5)
+    //    And C ← translation(10, 5, 7)
+    //  When T ← C * B * A
+    //  Then T * p = point(15, 0, 7)
+    #[test]
+    fn chained_transformations_are_applied_in_reverse_order() {
+        let p = Point::new(1.0, 0.0, 1.0);
+        let a = Matrix4::rotation_x(PI / 2.0);
+        let b = Matrix4::scaling(5.0, 5.0, 5.0);
+        let c = Matrix4::translation(10.0, 5.0, 7.0);
+
+        assert_eq!(c * b * a * p, Point::new(15.0, 0.0, 7.0));
+    }
+
+    // Scenario: Fluent transformations are applied in the order they are chained
+    //  Given p ← point(1, 0, 1)
+    //  When T ← identity_matrix.rotate_x(π / 2).scale(5, 5, 5).translate(10, 5, 7)
+    //  Then T * p = point(15, 0, 7)
+    #[test]
+    fn fluent_transformations_are_applied_in_chained_order() {
+        let p = Point::new(1.0, 0.0, 1.0);
+        let t = Transform::identity()
+            .rotate_x(PI / 2.0)
+            .scale(5.0, 5.0, 5.0)
+            .translate(10.0, 5.0, 7.0);
+        let expected = Matrix4::translation(10.0, 5.0, 7.0)
+            * Matrix4::scaling(5.0, 5.0, 5.0)
+            * Matrix4::rotation_x(PI / 2.0);
+
+        assert_eq!(t.matrix(), expected);
+        assert_eq!(Matrix4::from(t) * p, Point::new(15.0, 0.0, 7.0));
+    }
+
+    // Scenario: The transformation matrix for the default orientation
+    //  Given from ← point(0, 0, 0)
+    //    And to ← point(0, 0, -1)
+    //    And up ← vector(0, 1, 0)
+    //  When t ← view_transform(from, to, up)
+    //  Then t = identity_matrix
+    #[test]
+    fn view_transform_for_default_orientation() {
+        let from = Point::origin();
+        let to = Point::new(0.0, 0.0, -1.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(Matrix4::view_transform(from, to, up), Matrix4::identity());
+    }
+
+    // Scenario: A view transformation matrix looking in positive z direction
+    //  Given from ← point(0, 0, 0)
+    //    And to ← point(0, 0, 1)
+    //    And up ← vector(0, 1, 0)
+    //  When t ← view_transform(from, to, up)
+    //  Then t = scaling(-1, 1, -1)
+    #[test]
+    fn view_transform_looking_in_positive_z_direction() {
+        let from = Point::origin();
+        let to = Point::new(0.0, 0.0, 1.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            Matrix4::view_transform(from, to, up),
+            Matrix4::scaling(-1.0, 1.0, -1.0)
+        );
+    }
+
+    // Scenario: The view transformation moves the world
+    //  Given from ← point(0, 0, 8)
+    //    And to ← point(0, 0, 0)
+    //    And up ← vector(0, 1, 0)
+    //  When t ← view_transform(from, to, up)
+    //  Then t = translation(0, 0, -8)
+    #[test]
+    fn view_transform_moves_the_world() {
+        let from = Point::new(0.0, 0.0, 8.0);
+        let to = Point::origin();
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            Matrix4::view_transform(from, to, up),
+            Matrix4::translation(0.0, 0.0, -8.0)
+        );
+    }
+
+    // Scenario: An arbitrary view transformation
+    //  Given from ← point(1, 3, 2)
+    //    And to ← point(4, -2, 8)
+    //    And up ← vector(1, 1, 0)
+    //  When t ← view_transform(from, to, up)
+    //  Then t is the following 4x4 matrix:
+    //      | -0.50709 | 0.50709 |  0.67612 | -2.36643 |
+    //      |  0.76772 | 0.60609 |  0.12122 | -2.82843 |
+    //      | -0.35857 | 0.59761 | -0.71714 |  0.00000 |
+    //      |  0.00000 | 0.00000 |  0.00000 |  1.00000 |
+    #[test]
+    fn arbitrary_view_transform() {
+        let from = Point::new(1.0, 3.0, 2.0);
+        let to = Point::new(4.0, -2.0, 8.0);
+        let up = Vector::new(1.0, 1.0, 0.0);
+        let expected = Matrix4::from_rows([
+            [-0.50709, 0.50709, 0.67612, -2.36643],
+            [0.76772, 0.60609, 0.12122, -2.82843],
+            [-0.35857, 0.59761, -0.71714, 0.00000],
+            [0.00000, 0.00000, 0.00000, 1.00000],
+        ]);
+
+        assert_eq!(Matrix4::view_transform(from, to, up), expected);
+    }
+
+    #[test]
+    fn fluent_shear_and_rotations_match_matrices() {
+        let t = Transform::identity()
+            .shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+            .rotate_y(PI / 3.0)
+            .rotate_z(PI / 6.0);
+        let expected = Matrix4::rotation_z(PI / 6.0)
+            * Matrix4::rotation_y(PI / 3.0)
+            * Matrix4::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(t.matrix(), expected);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::prelude::*;
+use alloc::vec;
+
+/// A flat triangle between three points of its object space.
+///
+/// The edges and normal are computed once at construction, since every intersection needs them.
+#[derive(Debug, Clone)]
+pub struct Triangle {
+    properties: ShapeProperties,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+
+        Self {
+            properties: ShapeProperties::new(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: e2.cross(&e1).normalize(),
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    /// Edge from `p1` to `p2`.
+    pub fn e1(&self) -> Vector {
+        self.e1
+    }
+
+    /// Edge from `p1` to `p3`.
+    pub fn e2(&self) -> Vector {
+        self.e2
+    }
+
+    pub fn normal(&self) -> Vector {
+        self.normal
+    }
+}
+
+/// Möller–Trumbore intersection of `ray` with the triangle at `p1` spanned by edges `e1`
+/// and `e2`, giving the distance `t` and the `u`/`v` of the hit.
+pub(crate) fn intersect_triangle(
+    p1: Point,
+    e1: Vector,
+    e2: Vector,
+    ray: &Ray,
+) -> Option<(Float, Float, Float)> {
+    let dir_cross_e2 = ray.direction.cross(&e2);
+    let det = e1.dot(&dir_cross_e2);
+    // The ray is parallel to the plane of the triangle.
+    if det.abs() < EPSILON {
+        return None;
+    }
+
+    let f = 1.0 / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * p1_to_origin.dot(&dir_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(&e1);
+    let v = f * ray.direction.dot(&origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    Some((f * e2.dot(&origin_cross_e1), u, v))
+}
+
+impl Shape for Triangle {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        match intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            Some((t, u, v)) => Intersections::new(vec![Intersection::with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point) -> Vector {
+        self.normal
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    fn book_triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    // Scenario: Constructing a triangle
+    //  Given p1 ← point(0, 1, 0)
+    //    And p2 ← point(-1, 0, 0)
+    //    And p3 ← point(1, 0, 0)
+    //    And t ← triangle(p1, p2, p3)
+    //  Then t.p1 = p1
+    //    And t.p2 = p2
+    //    And t.p3 = p3
+    //    And t.e1 = vector(-1, -1, 0)
+    //    And t.e2 = vector(1, -1, 0)
+    //    And t.normal = vector(0, 0, -1)
+    #[test]
+    fn constructing_triangle() {
+        let p1 = Point::new(0.0, 1.0, 0.0);
+        let p2 = Point::new(-1.0, 0.0, 0.0);
+        let p3 = Point::new(1.0, 0.0, 0.0);
+        let t = Triangle::new(p1, p2, p3);
+
+        assert_eq!(t.p1(), p1);
+        assert_eq!(t.p2(), p2);
+        assert_eq!(t.p3(), p3);
+        assert_eq!(t.e1(), Vector::new(-1.0, -1.0, 0.0));
+        assert_eq!(t.e2(), Vector::new(1.0, -1.0, 0.0));
+        assert_eq!(t.normal(), Vector::new(0.0, 0.0, -1.0));
+    }
+
+    // Scenario: Finding the normal on a triangle
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //  When n1 ← local_normal_at(t, point(0, 0.5, 0))
+    //    And n2 ← local_normal_at(t, point(-0.5, 0.75, 0))
+    //    And n3 ← local_normal_at(t, point(0.5, 0.25, 0))
+    //  Then n1 = t.normal
+    //    And n2 = t.normal
+    //    And n3 = t.normal
+    #[test]
+    fn finding_normal_on_triangle() {
+        let t = book_triangle();
+
+        assert_eq!(t.local_normal_at(Point::new(0.0, 0.5, 0.0)), t.normal());
+        assert_eq!(t.local_normal_at(Point::new(-0.5, 0.75, 0.0)), t.normal());
+        assert_eq!(t.local_normal_at(Point::new(0.5, 0.25, 0.0)), t.normal());
+    }
+
+    // Scenario: Intersecting a ray parallel to the triangle
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //    And r ← ray(point(0, -1, -2), vector(0, 1, 0))
+    //  When xs ← local_intersect(t, r)
+    //  Then xs is empty
+    #[test]
+    fn intersecting_ray_parallel_to_triangle() {
+        let t = book_triangle();
+        let r = Ray::new(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 0.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray misses the p1-p3 edge
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //    And r ← ray(point(1, 1, -2), vector(0, 0, 1))
+    //  When xs ← local_intersect(t, r)
+    //  Then xs is empty
+    #[test]
+    fn ray_misses_p1_p3_edge() {
+        let t = book_triangle();
+        let r = Ray::new(Point::new(1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray misses the p1-p2 edge
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //    And r ← ray(point(-1, 1, -2), vector(0, 0, 1))
+    //  When xs ← local_intersect(t, r)
+    //  Then xs is empty
+    #[test]
+    fn ray_misses_p1_p2_edge() {
+        let t = book_triangle();
+        let r = Ray::new(Point::new(-1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray misses the p2-p3 edge
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //    And r ← ray(point(0, -1, -2), vector(0, 0, 1))
+    //  When xs ← local_intersect(t, r)
+    //  Then xs is empty
+    #[test]
+    fn ray_misses_p2_p3_edge() {
+        let t = book_triangle();
+        let r = Ray::new(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    // Scenario: A ray strikes a triangle
+    //  Given t ← triangle(point(0, 1, 0), point(-1, 0, 0), point(1, 0, 0))
+    //    And r ← ray(point(0, 0.5, -2), vector(0, 0, 1))
+    //  When xs ← local_intersect(t, r)
+    //  Then xs.count = 1
+    //    And xs[0].t = 2
+    #[test]
+    fn ray_strikes_triangle() {
+        let t = book_triangle();
+        let r = Ray::new(Point::new(0.0, 0.5, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = t.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.0);
+    }
+
+    // Scenario: A triangle has a bounding box
+    //  Given p1 ← point(-3, 7, 2)
+    //    And p2 ← point(6, 2, -4)
+    //    And p3 ← point(2, -1, -1)
+    //    And shape ← triangle(p1, p2, p3)
+    //  When box ← bounds_of(shape)
+    //  Then box.min = point(-3, -1, -4)
+    //    And box.max = point(6, 7, 2)
+    #[test]
+    fn triangle_has_bounding_box() {
+        let shape = Triangle::new(
+            Point::new(-3.0, 7.0, 2.0),
+            Point::new(6.0, 2.0, -4.0),
+            Point::new(2.0, -1.0, -1.0),
+        );
+
+        let b = shape.bounds();
+
+        assert_eq!(b.min, Point::new(-3.0, -1.0, -4.0));
+        assert_eq!(b.max, Point::new(6.0, 7.0, 2.0));
+    }
+}
@@ -1,24 +1,28 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 use crate::prelude::*;
 
+/// A homogeneous coordinate where `w` tells points (1) and vectors (0) apart.
+///
+/// Prefer the [`Point`] and [`Vector`] types, which let the compiler reject
+/// meaningless operations such as adding two points.
 #[derive(Debug, Copy, Clone)]
 pub struct Tuple {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+    pub w: Float,
 }
 
 impl Tuple {
-    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: Float, y: Float, z: Float, w: Float) -> Self {
         Self { x, y, z, w }
     }
 
-    pub fn from_point(x: f32, y: f32, z: f32) -> Self {
+    pub fn from_point(x: Float, y: Float, z: Float) -> Self {
         Self { x, y, z, w: 1.0 }
     }
 
-    pub fn from_vector(x: f32, y: f32, z: f32) -> Self {
+    pub fn from_vector(x: Float, y: Float, z: Float) -> Self {
         Self { x, y, z, w: 0.0 }
     }
 
@@ -30,7 +34,7 @@ impl Tuple {
         self.w == 0.0
     }
 
-    pub fn magnitude(&self) -> f32 {
+    pub fn magnitude(&self) -> Float {
         (self.x.powi(2) + self.y.powi(2) + self.z.powi(2) + self.w.powi(2)).sqrt()
     }
 
@@ -44,20 +48,31 @@ impl Tuple {
         }
     }
 
-    pub fn dot(&self, rhs: &Self) -> f32 {
+    /// Normalizes the tuple, failing instead of producing NaNs when its magnitude is zero.
+    pub fn try_normalize(&self) -> Result<Self> {
+        if self.magnitude() == 0.0 {
+            return Err(RustracerError::NormalizeZeroVector);
+        }
+
+        Ok(self.normalize())
+    }
+
+    pub fn dot(&self, rhs: &Self) -> Float {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
 
-    pub fn cross(&self, rhs: &Self) -> Self {
-        // FIXME: having a proper type would be much better.
-        assert!(self.is_vector() && rhs.is_vector());
+    /// Cross product of two vector tuples; see [`Vector::cross`] for the checked-at-compile-time version.
+    pub fn cross(&self, rhs: &Self) -> Result<Self> {
+        if !self.is_vector() || !rhs.is_vector() {
+            return Err(RustracerError::CrossOnPoints);
+        }
 
-        Self {
+        Ok(Self {
             x: self.y * rhs.z - self.z * rhs.y,
             y: self.z * rhs.x - self.x * rhs.z,
             z: self.x * rhs.y - self.y * rhs.x,
             w: 0.0,
-        }
+        })
     }
 }
 
@@ -109,10 +124,10 @@ impl Neg for Tuple {
     }
 }
 
-impl Mul<f32> for Tuple {
+impl Mul<Float> for Tuple {
     type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self::Output {
+    fn mul(self, rhs: Float) -> Self::Output {
         Self {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -122,10 +137,10 @@ impl Mul<f32> for Tuple {
     }
 }
 
-impl Div<f32> for Tuple {
+impl Div<Float> for Tuple {
     type Output = Self;
 
-    fn div(self, rhs: f32) -> Self::Output {
+    fn div(self, rhs: Float) -> Self::Output {
         Self {
             x: self.x / rhs,
             y: self.y / rhs,
@@ -148,6 +163,7 @@ mod tests {
     //   And a is a point
     //   And a is not a vector
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn tuple_with_w_1_is_a_point() {
         let a = Tuple::new(4.3, -4.2, 3.1, 1.0);
 
@@ -168,6 +184,7 @@ mod tests {
     //   And a is not a point
     //   And a is a vector
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn tuple_with_w_0_is_a_vector() {
         let a = Tuple::new(4.3, -4.2, 3.1, 0.0);
 
@@ -291,7 +308,7 @@ mod tests {
     #[test]
     fn multiplying_tuple_by_scalar() {
         let a = Tuple::new(1.0, -2.0, 3.0, -4.0);
-        let s: f32 = 3.5;
+        let s: Float = 3.5;
         let expected = Tuple::new(3.5, -7.0, 10.5, -14.0);
 
         assert_eq!(a * s, expected);
@@ -303,7 +320,7 @@ mod tests {
     #[test]
     fn multiplying_tuple_by_fraction() {
         let a = Tuple::new(1.0, -2.0, 3.0, -4.0);
-        let s: f32 = 0.5;
+        let s: Float = 0.5;
         let expected = Tuple::new(0.5, -1.0, 1.5, -2.0);
 
         assert_eq!(a * s, expected);
@@ -315,7 +332,7 @@ mod tests {
     #[test]
     fn dividing_tuple_by_scalar() {
         let a = Tuple::new(1.0, -2.0, 3.0, -4.0);
-        let s: f32 = 2.0;
+        let s: Float = 2.0;
         let expected = Tuple::new(0.5, -1.0, 1.5, -2.0);
 
         assert_eq!(a / s, expected);
@@ -360,7 +377,7 @@ mod tests {
     #[test]
     fn computing_magnitude_of_vector_pos() {
         let v = Tuple::from_vector(1.0, 2.0, 3.0);
-        let expected = (14.0f32).sqrt();
+        let expected = Float::sqrt(14.0);
 
         assert_eq!(v.magnitude(), expected);
     }
@@ -371,7 +388,7 @@ mod tests {
     #[test]
     fn computing_magnitude_of_vector_neg() {
         let v = Tuple::from_vector(-1.0, -2.0, -3.0);
-        let expected = (14.0f32).sqrt();
+        let expected = Float::sqrt(14.0);
 
         assert_eq!(v.magnitude(), expected);
     }
@@ -436,7 +453,26 @@ mod tests {
         let expected_ab = Tuple::from_vector(-1.0, 2.0, -1.0);
         let expected_ba = Tuple::from_vector(1.0, -2.0, 1.0);
 
-        assert_eq!(a.cross(&b), expected_ab);
-        assert_eq!(b.cross(&a), expected_ba);
+        assert_eq!(a.cross(&b).unwrap(), expected_ab);
+        assert_eq!(b.cross(&a).unwrap(), expected_ba);
+    }
+
+    #[test]
+    fn cross_product_of_points_is_an_error() {
+        let a = Tuple::from_point(1.0, 2.0, 3.0);
+        let b = Tuple::from_vector(2.0, 3.0, 4.0);
+
+        assert!(matches!(a.cross(&b), Err(RustracerError::CrossOnPoints)));
+        assert!(matches!(b.cross(&a), Err(RustracerError::CrossOnPoints)));
+    }
+
+    #[test]
+    fn normalizing_zero_vector_is_an_error() {
+        let v = Tuple::from_vector(0.0, 0.0, 0.0);
+
+        assert!(matches!(
+            v.try_normalize(),
+            Err(RustracerError::NormalizeZeroVector)
+        ));
     }
 }
@@ -0,0 +1,268 @@
+use crate::prelude::*;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A direction in space, equivalent to a tuple with `w = 0`.
+#[derive(Debug, Copy, Clone)]
+pub struct Vector {
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+}
+
+impl Vector {
+    pub const fn new(x: Float, y: Float, z: Float) -> Self {
+        Self { x, y, z }
+    }
+
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    pub fn magnitude(&self) -> Float {
+        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        *self / self.magnitude()
+    }
+
+    /// Normalizes the vector, failing instead of producing NaNs when its magnitude is zero.
+    pub fn try_normalize(&self) -> Result<Self> {
+        if self.magnitude() == 0.0 {
+            return Err(RustracerError::NormalizeZeroVector);
+        }
+
+        Ok(self.normalize())
+    }
+
+    pub fn dot(&self, rhs: &Self) -> Float {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    /// Reflects the vector around `normal`, like a ball bouncing off a surface.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * 2.0 * self.dot(normal)
+    }
+}
+
+impl PartialEq for Vector {
+    fn eq(&self, other: &Self) -> bool {
+        is_approx(self.x, other.x, None)
+            && is_approx(self.y, other.y, None)
+            && is_approx(self.z, other.z, None)
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Add<Point> for Vector {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<Float> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: Float) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Div<Float> for Vector {
+    type Output = Self;
+
+    fn div(self, rhs: Float) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl From<Vector> for Tuple {
+    fn from(v: Vector) -> Self {
+        Tuple::from_vector(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    // Scenario: vector() creates tuples with w=0
+    //  Given v ← vector(4, -4, 3)
+    //   Then v = tuple(4, -4, 3, 0)
+    #[test]
+    fn vector_converts_to_tuple_with_w_0() {
+        let v = Vector::new(4.0, -4.0, 3.0);
+        let expected = Tuple::new(4.0, -4.0, 3.0, 0.0);
+
+        assert_eq!(Tuple::from(v), expected);
+    }
+
+    #[test]
+    fn adding_two_vectors() {
+        let v1 = Vector::new(3.0, -2.0, 5.0);
+        let v2 = Vector::new(-2.0, 3.0, 1.0);
+        let expected = Vector::new(1.0, 1.0, 6.0);
+
+        assert_eq!(v1 + v2, expected);
+    }
+
+    // Scenario: Subtracting two vectors
+    // Given v1 ← vector(3, 2, 1)
+    //   And v2 ← vector(5, 6, 7)
+    //  Then v1 - v2 = vector(-2, -4, -6)
+    #[test]
+    fn subtracting_two_vectors() {
+        let v1 = Vector::new(3.0, 2.0, 1.0);
+        let v2 = Vector::new(5.0, 6.0, 7.0);
+        let expected = Vector::new(-2.0, -4.0, -6.0);
+
+        assert_eq!(v1 - v2, expected);
+    }
+
+    // Scenario: Subtracting a vector from the zero vector
+    //  Given zero ← vector(0, 0, 0)
+    //    And v ← vector(1, -2, 3)
+    //   Then zero - v = vector(-1, 2, -3)
+    #[test]
+    fn subtracting_vector_from_zero_vector() {
+        let zero = Vector::zero();
+        let v = Vector::new(1.0, -2.0, 3.0);
+        let expected = Vector::new(-1.0, 2.0, -3.0);
+
+        assert_eq!(zero - v, expected);
+    }
+
+    #[test]
+    fn negating_vector() {
+        let v = Vector::new(1.0, -2.0, 3.0);
+        let expected = Vector::new(-1.0, 2.0, -3.0);
+
+        assert_eq!(-v, expected);
+    }
+
+    #[test]
+    fn multiplying_and_dividing_vector_by_scalar() {
+        let v = Vector::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v * 3.5, Vector::new(3.5, -7.0, 10.5));
+        assert_eq!(v / 2.0, Vector::new(0.5, -1.0, 1.5));
+    }
+
+    // Scenario: Computing the magnitude of vector(1, 2, 3)
+    //  Given v ← vector(1, 2, 3)
+    //   Then magnitude(v) = √14
+    #[test]
+    fn computing_magnitude_of_vector() {
+        let v = Vector::new(1.0, 2.0, 3.0);
+        let expected = Float::sqrt(14.0);
+
+        assert_eq!(v.magnitude(), expected);
+        assert_eq!((-v).magnitude(), expected);
+    }
+
+    // Scenario: Normalizing vector(1, 2, 3)
+    //  Given v ← vector(1, 2, 3)
+    //  Then normalize(v) = approximately vector(0.26726, 0.53452, 0.80178)
+    #[test]
+    fn normalizing_vector() {
+        let v = Vector::new(1.0, 2.0, 3.0);
+        let expected = Vector::new(0.26726, 0.53452, 0.80178);
+
+        assert_eq!(v.normalize(), expected);
+        assert!(is_approx(v.normalize().magnitude(), 1.0, None));
+    }
+
+    #[test]
+    fn normalizing_zero_vector_is_an_error() {
+        assert!(matches!(
+            Vector::zero().try_normalize(),
+            Err(RustracerError::NormalizeZeroVector)
+        ));
+    }
+
+    // Scenario: The dot product of two tuples
+    //  Given a ← vector(1, 2, 3)
+    //    And b ← vector(2, 3, 4)
+    //   Then dot(a, b) = 20
+    #[test]
+    fn dot_product_of_two_vectors() {
+        let a = Vector::new(1.0, 2.0, 3.0);
+        let b = Vector::new(2.0, 3.0, 4.0);
+
+        assert_eq!(a.dot(&b), 20.0);
+    }
+
+    // Scenario: The cross product of two vectors
+    //  Given a ← vector(1, 2, 3)
+    //    And b ← vector(2, 3, 4)
+    //   Then cross(a, b) = vector(-1, 2, -1)
+    //    And cross(b, a) = vector(1, -2, 1)
+    #[test]
+    fn cross_product_of_two_vectors() {
+        let a = Vector::new(1.0, 2.0, 3.0);
+        let b = Vector::new(2.0, 3.0, 4.0);
+
+        assert_eq!(a.cross(&b), Vector::new(-1.0, 2.0, -1.0));
+        assert_eq!(b.cross(&a), Vector::new(1.0, -2.0, 1.0));
+    }
+
+    // Scenario: Reflecting a vector approaching at 45°
+    //  Given v ← vector(1, -1, 0)
+    //    And n ← vector(0, 1, 0)
+    //  When r ← reflect(v, n)
+    //  Then r = vector(1, 1, 0)
+    #[test]
+    fn reflecting_vector_approaching_at_45_degrees() {
+        let v = Vector::new(1.0, -1.0, 0.0);
+        let n = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(v.reflect(&n), Vector::new(1.0, 1.0, 0.0));
+    }
+
+    // Scenario: Reflecting a vector off a slanted surface
+    //  Given v ← vector(0, -1, 0)
+    //    And n ← vector(√2/2, √2/2, 0)
+    //  When r ← reflect(v, n)
+    //  Then r = vector(1, 0, 0)
+    #[test]
+    fn reflecting_vector_off_slanted_surface() {
+        let v = Vector::new(0.0, -1.0, 0.0);
+        let k = Float::sqrt(2.0) / 2.0;
+        let n = Vector::new(k, k, 0.0);
+
+        assert_eq!(v.reflect(&n), Vector::new(1.0, 0.0, 0.0));
+    }
+}
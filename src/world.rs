@@ -0,0 +1,964 @@
+use crate::prelude::*;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// How many times a ray may bounce between reflective surfaces by default.
+pub const DEFAULT_MAX_DEPTH: u32 = 5;
+
+/// Everything in a scene: the objects to render and the lights illuminating them.
+#[derive(Debug)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    pub lights: Vec<Box<dyn Light>>,
+    /// Bounces followed by [`World::color_at`], so facing mirrors cannot recurse forever.
+    pub max_depth: u32,
+    /// Fog over every hit seen through the air rather than from inside an object. Rays
+    /// hitting nothing are left alone.
+    pub fog: Option<Fog>,
+    /// What rays hitting nothing see, black by default.
+    pub background: Background,
+}
+
+impl World {
+    /// A world with no objects and no light.
+    pub fn new() -> Self {
+        Self {
+            objects: Vec::new(),
+            lights: Vec::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            fog: None,
+            background: Background::default(),
+        }
+    }
+
+    pub fn add_object<S: Shape + 'static>(&mut self, object: S) {
+        self.objects.push(Box::new(object));
+    }
+
+    pub fn add_light<L: Light + 'static>(&mut self, light: L) {
+        self.lights.push(Box::new(light));
+    }
+
+    pub fn intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut xs = Intersections::default();
+        for object in &self.objects {
+            xs.extend(object.intersect(ray));
+        }
+        xs
+    }
+
+    /// Intersections like [`World::intersect`], leaving out the shapes hidden from rays of
+    /// `kind` by their [`Shape::world_visibility`].
+    pub fn intersect_visible(&self, ray: &Ray, kind: RayKind) -> Intersections<'_> {
+        let mut xs = Intersections::default();
+        for object in &self.objects {
+            xs.extend(
+                object
+                    .intersect(ray)
+                    .into_iter()
+                    .filter(|i| i.object.world_visibility().sees(kind)),
+            );
+        }
+        xs
+    }
+
+    /// Color at the intersection described by `comps`, summed over every light.
+    ///
+    /// Reflections are followed for at most `remaining` more bounces.
+    pub fn shade_hit(&self, comps: &Computations, remaining: u32) -> Color {
+        let surface = self.lights.iter().fold(Color::black(), |color, light| {
+            color
+                + lighting(
+                    comps.object.material(),
+                    comps.object,
+                    light.as_ref(),
+                    comps.over_point,
+                    comps.eyev,
+                    comps.normalv,
+                    self.is_shadowed(comps.over_point, light.as_ref()),
+                )
+        });
+
+        let reflected = self.reflected_color(comps, remaining);
+        let refracted = self.refracted_color(comps, remaining);
+
+        let material = comps.object.material();
+        if material.reflective > 0.0 && material.transparency > 0.0 {
+            let reflectance = comps.schlick();
+            surface + reflected * reflectance + refracted * (1.0 - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
+    }
+
+    /// Color reflected by the surface at `comps`, black once `remaining` bounces are spent.
+    pub fn reflected_color(&self, comps: &Computations, remaining: u32) -> Color {
+        let reflective = comps.object.material().reflective;
+        if remaining == 0 || reflective == 0.0 {
+            return Color::black();
+        }
+
+        let reflect_ray = Ray::new(comps.over_point, comps.reflectv);
+        self.color_along(&reflect_ray, RayKind::Reflection, remaining - 1) * reflective
+    }
+
+    /// Whether an object casting shadows sits between `point` and `light`.
+    pub fn is_shadowed(&self, point: Point, light: &dyn Light) -> bool {
+        let v = light.position() - point;
+        let distance = v.magnitude();
+        let ray = Ray::new(point, v.normalize());
+
+        match self.intersect_visible(&ray, RayKind::Shadow).hit() {
+            Some(hit) => hit.t < distance,
+            None => false,
+        }
+    }
+
+    /// Color let through the surface at `comps`, black once `remaining` bounces are spent
+    /// or when all the light is reflected back.
+    pub fn refracted_color(&self, comps: &Computations, remaining: u32) -> Color {
+        let transparency = comps.object.material().transparency;
+        if remaining == 0 || transparency == 0.0 {
+            return Color::black();
+        }
+
+        // Snell's law, with the angle of incidence taken from the eye vector.
+        let n_ratio = comps.n1 / comps.n2;
+        let cos_i = comps.eyev.dot(&comps.normalv);
+        let sin2_t = n_ratio * n_ratio * (1.0 - cos_i * cos_i);
+        if sin2_t > 1.0 {
+            return Color::black();
+        }
+
+        let cos_t = (1.0 - sin2_t).sqrt();
+        let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+        let refract_ray = Ray::new(comps.under_point, direction);
+        self.color_along(&refract_ray, RayKind::Refraction, remaining - 1) * transparency
+    }
+
+    /// Color seen along `ray`, the background's if it hits nothing.
+    pub fn color_at(&self, ray: &Ray) -> Color {
+        self.color_at_depth(ray, self.max_depth)
+    }
+
+    /// Color seen along `ray` from the camera, following reflections for at most
+    /// `remaining` bounces.
+    pub fn color_at_depth(&self, ray: &Ray, remaining: u32) -> Color {
+        self.color_along(ray, RayKind::Camera, remaining)
+    }
+
+    /// Color seen along a ray of `kind`, following reflections for at most `remaining`
+    /// bounces.
+    fn color_along(&self, ray: &Ray, kind: RayKind, remaining: u32) -> Color {
+        let xs = self.intersect_visible(ray, kind);
+        match xs.hit() {
+            Some(hit) => {
+                let comps = hit.prepare_computations(ray, &xs);
+                let color = self.shade_hit(&comps, remaining);
+                let distance = hit.t * ray.direction.magnitude();
+                match (comps.medium, &self.fog) {
+                    // Light coming from inside an object is dimmed on its way out.
+                    (Some(medium), _) => color * medium.material().transmittance(distance),
+                    (None, Some(fog)) => fog.apply(color, distance),
+                    (None, None) => color,
+                }
+            }
+            None => self.background.color_toward(ray.direction),
+        }
+    }
+}
+
+/// The book's test world: two concentric spheres lit from the upper left.
+impl Default for World {
+    fn default() -> Self {
+        let s1 = Sphere::new().with_material(Material {
+            color: Color::new(0.8, 1.0, 0.6),
+            diffuse: 0.7,
+            specular: 0.2,
+            ..Material::default()
+        });
+        let s2 = Sphere::new().with_transform(Matrix4::scaling(0.5, 0.5, 0.5));
+
+        Self {
+            objects: vec![Box::new(s1), Box::new(s2)],
+            lights: vec![Box::new(PointLight::new(
+                Point::new(-10.0, 10.0, -10.0),
+                Color::white(),
+            ))],
+            max_depth: DEFAULT_MAX_DEPTH,
+            fog: None,
+            background: Background::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{FRAC_1_SQRT_2, FRAC_PI_2, SQRT_2};
+    use crate::pattern::TestPattern;
+    use crate::prelude::*;
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    // Scenario: Creating a world
+    //  Given w ← world()
+    //  Then w contains no objects
+    //    And w has no light source
+    #[test]
+    fn creating_world() {
+        let w = World::new();
+
+        assert!(w.objects.is_empty());
+        assert!(w.lights.is_empty());
+    }
+
+    // Scenario: The default world
+    //  Given light ← point_light(point(-10, 10, -10), color(1, 1, 1))
+    //    And s1 ← sphere() with:
+    //      | material.color     | (0.8, 1.0, 0.6)        |
+    //      | material.diffuse   | 0.7                    |
+    //      | material.specular  | 0.2                    |
+    //    And s2 ← sphere() with:
+    //      | transform | scaling(0.5, 0.5, 0.5) |
+    //  When w ← default_world()
+    //  Then w.light = light
+    //    And w contains s1
+    //    And w contains s2
+    #[test]
+    fn default_world() {
+        let light = PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::white());
+        let s1_material = Material {
+            color: Color::new(0.8, 1.0, 0.6),
+            diffuse: 0.7,
+            specular: 0.2,
+            ..Material::default()
+        };
+
+        let w = World::default();
+
+        assert_eq!(w.lights.len(), 1);
+        assert_eq!(w.lights[0].downcast_ref::<PointLight>(), Some(&light));
+        assert_eq!(w.objects.len(), 2);
+        assert_eq!(*w.objects[0].material(), s1_material);
+        assert_eq!(*w.objects[0].transform(), Matrix4::identity());
+        assert_eq!(*w.objects[1].material(), Material::default());
+        assert_eq!(*w.objects[1].transform(), Matrix4::scaling(0.5, 0.5, 0.5));
+    }
+
+    // Scenario: Intersect a world with a ray
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //  When xs ← intersect_world(w, r)
+    //  Then xs.count = 4
+    //    And xs[0].t = 4
+    //    And xs[1].t = 4.5
+    //    And xs[2].t = 5.5
+    //    And xs[3].t = 6
+    #[test]
+    fn intersect_world_with_ray() {
+        let w = World::default();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = w.intersect(&r);
+
+        assert_eq!(xs.len(), 4);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 4.5);
+        assert_eq!(xs[2].t, 5.5);
+        assert_eq!(xs[3].t, 6.0);
+    }
+
+    // Scenario: Shading an intersection
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And shape ← the first object in w
+    //    And i ← intersection(4, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And c ← shade_hit(w, comps)
+    //  Then c = color(0.38066, 0.47583, 0.2855)
+    #[test]
+    fn shading_intersection() {
+        let w = World::default();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = w.objects[0].as_ref();
+        let i = Intersection::new(4.0, shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let c = w.shade_hit(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(c, Color::new(0.38066, 0.47583, 0.2855));
+    }
+
+    // Scenario: Shading an intersection from the inside
+    //  Given w ← default_world()
+    //    And w.light ← point_light(point(0, 0.25, 0), color(1, 1, 1))
+    //    And r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //    And shape ← the second object in w
+    //    And i ← intersection(0.5, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And c ← shade_hit(w, comps)
+    //  Then c = color(0.90498, 0.90498, 0.90498)
+    #[test]
+    fn shading_intersection_from_inside() {
+        let mut w = World::default();
+        w.lights[0] = Box::new(PointLight::new(Point::new(0.0, 0.25, 0.0), Color::white()));
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+        let shape = w.objects[1].as_ref();
+        let i = Intersection::new(0.5, shape);
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let c = w.shade_hit(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(c, Color::new(0.90498, 0.90498, 0.90498));
+    }
+
+    #[test]
+    fn shading_sums_contributions_of_every_light() {
+        let mut w = World::default();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let single = w.color_at(&r);
+        w.add_light(PointLight::new(
+            Point::new(-10.0, 10.0, -10.0),
+            Color::white(),
+        ));
+
+        assert_eq!(w.color_at(&r), single + single);
+    }
+
+    // Scenario: There is no shadow when nothing is collinear with point and light
+    //  Given w ← default_world()
+    //    And p ← point(0, 10, 0)
+    //   Then is_shadowed(w, p) is false
+    #[test]
+    fn no_shadow_when_nothing_is_collinear_with_point_and_light() {
+        let w = World::default();
+        let p = Point::new(0.0, 10.0, 0.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    // Scenario: The shadow when an object is between the point and the light
+    //  Given w ← default_world()
+    //    And p ← point(10, -10, 10)
+    //   Then is_shadowed(w, p) is true
+    #[test]
+    fn shadow_when_object_is_between_point_and_light() {
+        let w = World::default();
+        let p = Point::new(10.0, -10.0, 10.0);
+
+        assert!(w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    // Scenario: There is no shadow when an object is behind the light
+    //  Given w ← default_world()
+    //    And p ← point(-20, 20, -20)
+    //   Then is_shadowed(w, p) is false
+    #[test]
+    fn no_shadow_when_object_is_behind_light() {
+        let w = World::default();
+        let p = Point::new(-20.0, 20.0, -20.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    // Scenario: There is no shadow when an object is behind the point
+    //  Given w ← default_world()
+    //    And p ← point(-2, 2, -2)
+    //   Then is_shadowed(w, p) is false
+    #[test]
+    fn no_shadow_when_object_is_behind_point() {
+        let w = World::default();
+        let p = Point::new(-2.0, 2.0, -2.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    // Scenario: shade_hit() is given an intersection in shadow
+    //  Given w ← world()
+    //    And w.light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    //    And s1 ← sphere()
+    //    And s1 is added to w
+    //    And s2 ← sphere() with:
+    //      | transform | translation(0, 0, 10) |
+    //    And s2 is added to w
+    //    And r ← ray(point(0, 0, 5), vector(0, 0, 1))
+    //    And i ← intersection(4, s2)
+    //  When comps ← prepare_computations(i, r)
+    //    And c ← shade_hit(w, comps)
+    //  Then c = color(0.1, 0.1, 0.1)
+    #[test]
+    fn shade_hit_is_given_intersection_in_shadow() {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white()));
+        w.add_object(Sphere::new());
+        w.add_object(Sphere::new().with_transform(Matrix4::translation(0.0, 0.0, 10.0)));
+        let r = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let i = Intersection::new(4.0, w.objects[1].as_ref());
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let c = w.shade_hit(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(c, Color::new(0.1, 0.1, 0.1));
+    }
+
+    // Scenario: The color when a ray misses
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, -5), vector(0, 1, 0))
+    //  When c ← color_at(w, r)
+    //  Then c = color(0, 0, 0)
+    #[test]
+    fn color_when_ray_misses() {
+        let w = World::default();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        assert_eq!(w.color_at(&r), Color::black());
+    }
+
+    // Scenario: The color when a ray hits
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //  When c ← color_at(w, r)
+    //  Then c = color(0.38066, 0.47583, 0.2855)
+    #[test]
+    fn color_when_ray_hits() {
+        let w = World::default();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert_eq!(w.color_at(&r), Color::new(0.38066, 0.47583, 0.2855));
+    }
+
+    // Scenario: The color with an intersection behind the ray
+    //  Given w ← default_world()
+    //    And outer ← the first object in w
+    //    And outer.material.ambient ← 1
+    //    And inner ← the second object in w
+    //    And inner.material.ambient ← 1
+    //    And r ← ray(point(0, 0, 0.75), vector(0, 0, -1))
+    //  When c ← color_at(w, r)
+    //  Then c = inner.material.color
+    #[test]
+    fn color_with_intersection_behind_ray() {
+        let mut w = World::default();
+        w.objects[0].material_mut().ambient = 1.0;
+        w.objects[1].material_mut().ambient = 1.0;
+        let r = Ray::new(Point::new(0.0, 0.0, 0.75), Vector::new(0.0, 0.0, -1.0));
+
+        assert_eq!(w.color_at(&r), w.objects[1].material().color);
+    }
+
+    // Scenario: The reflected color for a nonreflective material
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    //    And shape ← the second object in w
+    //    And shape.material.ambient ← 1
+    //    And i ← intersection(1, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And color ← reflected_color(w, comps)
+    //  Then color = color(0, 0, 0)
+    #[test]
+    fn reflected_color_for_nonreflective_material() {
+        let mut w = World::default();
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 0.0, 1.0));
+        w.objects[1].material_mut().ambient = 1.0;
+        let i = Intersection::new(1.0, w.objects[1].as_ref());
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let color = w.reflected_color(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(color, Color::black());
+    }
+
+    // Scenario: The reflected color for a reflective material
+    //  Given w ← default_world()
+    //    And shape ← plane() with:
+    //      | material.reflective | 0.5                   |
+    //      | transform           | translation(0, -1, 0) |
+    //    And shape is added to w
+    //    And r ← ray(point(0, 0, -3), vector(0, -√2/2, √2/2))
+    //    And i ← intersection(√2, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And color ← reflected_color(w, comps)
+    //  Then color = color(0.19032, 0.2379, 0.14274)
+    #[test]
+    fn reflected_color_for_reflective_material() {
+        let mut w = World::default();
+        w.add_object(reflective_floor(0.5));
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let color = w.reflected_color(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(color, Color::new(0.19032, 0.2379, 0.14274));
+    }
+
+    // Scenario: shade_hit() with a reflective material
+    //  Given w ← default_world()
+    //    And shape ← plane() with:
+    //      | material.reflective | 0.5                   |
+    //      | transform           | translation(0, -1, 0) |
+    //    And shape is added to w
+    //    And r ← ray(point(0, 0, -3), vector(0, -√2/2, √2/2))
+    //    And i ← intersection(√2, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And color ← shade_hit(w, comps)
+    //  Then color = color(0.87677, 0.92436, 0.82918)
+    #[test]
+    fn shade_hit_with_reflective_material() {
+        let mut w = World::default();
+        w.add_object(reflective_floor(0.5));
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let color = w.shade_hit(&comps, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(color, Color::new(0.87677, 0.92436, 0.82918));
+    }
+
+    // Scenario: color_at() with mutually reflective surfaces
+    //  Given w ← world()
+    //    And w.light ← point_light(point(0, 0, 0), color(1, 1, 1))
+    //    And lower ← plane() with:
+    //      | material.reflective | 1                     |
+    //      | transform           | translation(0, -1, 0) |
+    //    And lower is added to w
+    //    And upper ← plane() with:
+    //      | material.reflective | 1                    |
+    //      | transform           | translation(0, 1, 0) |
+    //    And upper is added to w
+    //    And r ← ray(point(0, 0, 0), vector(0, 1, 0))
+    //  Then color_at(w, r) should terminate successfully
+    #[test]
+    fn color_at_with_mutually_reflective_surfaces() {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::origin(), Color::white()));
+        w.add_object(reflective_floor(1.0));
+        w.add_object(
+            Plane::new()
+                .with_transform(Matrix4::translation(0.0, 1.0, 0.0))
+                .with_material(Material {
+                    reflective: 1.0,
+                    ..Material::default()
+                }),
+        );
+        let r = Ray::new(Point::origin(), Vector::new(0.0, 1.0, 0.0));
+
+        w.color_at(&r);
+    }
+
+    // Scenario: The reflected color at the maximum recursive depth
+    //  Given w ← default_world()
+    //    And shape ← plane() with:
+    //      | material.reflective | 0.5                   |
+    //      | transform           | translation(0, -1, 0) |
+    //    And shape is added to w
+    //    And r ← ray(point(0, 0, -3), vector(0, -√2/2, √2/2))
+    //    And i ← intersection(√2, shape)
+    //  When comps ← prepare_computations(i, r)
+    //    And color ← reflected_color(w, comps, 0)
+    //  Then color = color(0, 0, 0)
+    #[test]
+    fn reflected_color_at_maximum_recursive_depth() {
+        let mut w = World::default();
+        w.add_object(reflective_floor(0.5));
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+
+        let comps = i.prepare_computations(&r, &Intersections::new(vec![i]));
+        let color = w.reflected_color(&comps, 0);
+
+        assert_eq!(color, Color::black());
+    }
+
+    fn reflective_floor(reflective: Float) -> Plane {
+        Plane::new()
+            .with_transform(Matrix4::translation(0.0, -1.0, 0.0))
+            .with_material(Material {
+                reflective,
+                ..Material::default()
+            })
+    }
+
+    // Scenario: The refracted color with an opaque surface
+    //  Given w ← default_world()
+    //    And shape ← the first object in w
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And xs ← intersections(4:shape, 6:shape)
+    //  When comps ← prepare_computations(xs[0], r, xs)
+    //    And c ← refracted_color(w, comps, 5)
+    //  Then c = color(0, 0, 0)
+    #[test]
+    fn refracted_color_with_opaque_surface() {
+        let w = World::default();
+        let shape = w.objects[0].as_ref();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(4.0, shape),
+            Intersection::new(6.0, shape),
+        ]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+        let c = w.refracted_color(&comps, 5);
+
+        assert_eq!(c, Color::black());
+    }
+
+    // Scenario: The refracted color at the maximum recursive depth
+    //  Given w ← default_world()
+    //    And shape ← the first object in w
+    //    And shape has:
+    //      | material.transparency     | 1.0 |
+    //      | material.refractive_index | 1.5 |
+    //    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    //    And xs ← intersections(4:shape, 6:shape)
+    //  When comps ← prepare_computations(xs[0], r, xs)
+    //    And c ← refracted_color(w, comps, 0)
+    //  Then c = color(0, 0, 0)
+    #[test]
+    fn refracted_color_at_maximum_recursive_depth() {
+        let mut w = World::default();
+        w.objects[0].material_mut().transparency = 1.0;
+        w.objects[0].material_mut().refractive_index = 1.5;
+        let shape = w.objects[0].as_ref();
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(4.0, shape),
+            Intersection::new(6.0, shape),
+        ]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+        let c = w.refracted_color(&comps, 0);
+
+        assert_eq!(c, Color::black());
+    }
+
+    // Scenario: The refracted color under total internal reflection
+    //  Given w ← default_world()
+    //    And shape ← the first object in w
+    //    And shape has:
+    //      | material.transparency     | 1.0 |
+    //      | material.refractive_index | 1.5 |
+    //    And r ← ray(point(0, 0, √2/2), vector(0, 1, 0))
+    //    And xs ← intersections(-√2/2:shape, √2/2:shape)
+    //  # NOTE: this time you're inside the sphere, so you need
+    //  # to look at the second intersection, xs[1], not xs[0]
+    //  When comps ← prepare_computations(xs[1], r, xs)
+    //    And c ← refracted_color(w, comps, 5)
+    //  Then c = color(0, 0, 0)
+    #[test]
+    fn refracted_color_under_total_internal_reflection() {
+        let mut w = World::default();
+        w.objects[0].material_mut().transparency = 1.0;
+        w.objects[0].material_mut().refractive_index = 1.5;
+        let shape = w.objects[0].as_ref();
+        let r = Ray::new(
+            Point::new(0.0, 0.0, FRAC_1_SQRT_2),
+            Vector::new(0.0, 1.0, 0.0),
+        );
+        let xs = Intersections::new(vec![
+            Intersection::new(-FRAC_1_SQRT_2, shape),
+            Intersection::new(FRAC_1_SQRT_2, shape),
+        ]);
+
+        let comps = xs[1].prepare_computations(&r, &xs);
+        let c = w.refracted_color(&comps, 5);
+
+        assert_eq!(c, Color::black());
+    }
+
+    // Scenario: The refracted color with a refracted ray
+    //  Given w ← default_world()
+    //    And A ← the first object in w
+    //    And A has:
+    //      | material.ambient | 1.0            |
+    //      | material.pattern | test_pattern() |
+    //    And B ← the second object in w
+    //    And B has:
+    //      | material.transparency     | 1.0 |
+    //      | material.refractive_index | 1.5 |
+    //    And r ← ray(point(0, 0, 0.1), vector(0, 1, 0))
+    //    And xs ← intersections(-0.9899:A, -0.4899:B, 0.4899:B, 0.9899:A)
+    //  When comps ← prepare_computations(xs[2], r, xs)
+    //    And c ← refracted_color(w, comps, 5)
+    //  Then c = color(0, 0.99888, 0.04725)
+    #[test]
+    fn refracted_color_with_refracted_ray() {
+        let mut w = World::default();
+        w.objects[0].material_mut().ambient = 1.0;
+        w.objects[0].material_mut().pattern = Some(Arc::new(TestPattern::new()));
+        w.objects[1].material_mut().transparency = 1.0;
+        w.objects[1].material_mut().refractive_index = 1.5;
+        let a = w.objects[0].as_ref();
+        let b = w.objects[1].as_ref();
+        let r = Ray::new(Point::new(0.0, 0.0, 0.1), Vector::new(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(-0.9899, a),
+            Intersection::new(-0.4899, b),
+            Intersection::new(0.4899, b),
+            Intersection::new(0.9899, a),
+        ]);
+
+        let comps = xs[2].prepare_computations(&r, &xs);
+        let c = w.refracted_color(&comps, 5);
+
+        assert_eq!(c, Color::new(0.0, 0.99888, 0.04725));
+    }
+
+    // Scenario: shade_hit() with a transparent material
+    //  Given w ← default_world()
+    //    And floor ← plane() with:
+    //      | transform                 | translation(0, -1, 0) |
+    //      | material.transparency     | 0.5                   |
+    //      | material.refractive_index | 1.5                   |
+    //    And floor is added to w
+    //    And ball ← sphere() with:
+    //      | material.color     | (1, 0, 0)                  |
+    //      | material.ambient   | 0.5                        |
+    //      | transform          | translation(0, -3.5, -0.5) |
+    //    And ball is added to w
+    //    And r ← ray(point(0, 0, -3), vector(0, -√2/2, √2/2))
+    //    And xs ← intersections(√2:floor)
+    //  When comps ← prepare_computations(xs[0], r, xs)
+    //    And color ← shade_hit(w, comps, 5)
+    //  Then color = color(0.93642, 0.68642, 0.68642)
+    #[test]
+    fn shade_hit_with_transparent_material() {
+        let mut w = World::default();
+        w.add_object(translucent_floor(0.0));
+        w.add_object(red_ball());
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let xs = Intersections::new(vec![Intersection::new(SQRT_2, w.objects[2].as_ref())]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+        let color = w.shade_hit(&comps, 5);
+
+        assert_eq!(color, Color::new(0.93642, 0.68642, 0.68642));
+    }
+
+    // Scenario: shade_hit() with a reflective, transparent material
+    //  Given w ← default_world()
+    //    And r ← ray(point(0, 0, -3), vector(0, -√2/2, √2/2))
+    //    And floor ← plane() with:
+    //      | transform                 | translation(0, -1, 0) |
+    //      | material.reflective       | 0.5                   |
+    //      | material.transparency     | 0.5                   |
+    //      | material.refractive_index | 1.5                   |
+    //    And floor is added to w
+    //    And ball ← sphere() with:
+    //      | material.color     | (1, 0, 0)                  |
+    //      | material.ambient   | 0.5                        |
+    //      | transform          | translation(0, -3.5, -0.5) |
+    //    And ball is added to w
+    //    And xs ← intersections(√2:floor)
+    //  When comps ← prepare_computations(xs[0], r, xs)
+    //    And color ← shade_hit(w, comps, 5)
+    //  Then color = color(0.93391, 0.69643, 0.69243)
+    #[test]
+    fn shade_hit_with_reflective_transparent_material() {
+        let mut w = World::default();
+        w.add_object(translucent_floor(0.5));
+        w.add_object(red_ball());
+        let r = Ray::new(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let xs = Intersections::new(vec![Intersection::new(SQRT_2, w.objects[2].as_ref())]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+        let color = w.shade_hit(&comps, 5);
+
+        assert_eq!(color, Color::new(0.93391, 0.69643, 0.69243));
+    }
+
+    fn translucent_floor(reflective: Float) -> Plane {
+        Plane::new()
+            .with_transform(Matrix4::translation(0.0, -1.0, 0.0))
+            .with_material(Material {
+                reflective,
+                transparency: 0.5,
+                refractive_index: 1.5,
+                ..Material::default()
+            })
+    }
+
+    fn red_ball() -> Sphere {
+        Sphere::new()
+            .with_transform(Matrix4::translation(0.0, -3.5, -0.5))
+            .with_material(Material {
+                color: Color::new(1.0, 0.0, 0.0),
+                ambient: 0.5,
+                ..Material::default()
+            })
+    }
+
+    /// A clear ball at the origin, in front of a white wall lit by ambient light only.
+    fn glass_ball_before_wall(absorption: Color) -> World {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::new(0.0, 0.0, -10.0), Color::white()));
+        w.add_object(Sphere::new().with_material(Material {
+            ambient: 0.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            transparency: 1.0,
+            absorption,
+            ..Material::default()
+        }));
+        w.add_object(
+            Plane::new()
+                .with_transform(
+                    Matrix4::translation(0.0, 0.0, 10.0) * Matrix4::rotation_x(FRAC_PI_2),
+                )
+                .with_material(Material {
+                    ambient: 1.0,
+                    diffuse: 0.0,
+                    specular: 0.0,
+                    ..Material::default()
+                }),
+        );
+        w
+    }
+
+    #[test]
+    fn rays_hitting_nothing_see_the_background() {
+        let mut w = World::new();
+        w.background = Background::Gradient {
+            horizon: Color::white(),
+            zenith: Color::new(0.0, 0.0, 1.0),
+            ground: None,
+        };
+        w.add_object(Plane::new().with_material(Material {
+            ambient: 0.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            reflective: 1.0,
+            ..Material::default()
+        }));
+
+        let up = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        let at_mirror = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+        assert_eq!(w.color_at(&up), Color::new(0.0, 0.0, 1.0));
+        assert_eq!(w.color_at(&at_mirror), Color::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn fog_hides_distant_hits_but_not_the_background() {
+        let mut w = glass_ball_before_wall(Color::black());
+        w.objects.remove(0);
+        w.fog = Some(Fog::linear(Color::new(0.0, 0.0, 1.0), 5.0, 25.0));
+
+        let at_wall = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let away_from_wall = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, -1.0));
+
+        assert_eq!(w.color_at(&at_wall), Color::new(0.5, 0.5, 1.0));
+        assert_eq!(w.color_at(&away_from_wall), Color::black());
+    }
+
+    #[test]
+    fn light_through_object_is_absorbed_along_the_way() {
+        let through_center = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let past_ball = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let clear = glass_ball_before_wall(Color::black());
+        assert_eq!(clear.color_at(&through_center), Color::white());
+
+        let tinted = glass_ball_before_wall(Color::new(0.5, 0.0, 0.0));
+        assert_eq!(
+            tinted.color_at(&through_center),
+            Color::new(0.36788, 1.0, 1.0)
+        );
+        assert_eq!(tinted.color_at(&past_ball), Color::white());
+    }
+
+    #[test]
+    fn objects_hidden_from_camera_still_cast_shadows() {
+        let mut w = World::default();
+        for object in &mut w.objects {
+            object.set_visibility(Visibility {
+                camera: false,
+                ..Visibility::ALL
+            });
+        }
+        let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let p = Point::new(10.0, -10.0, 10.0);
+
+        assert_eq!(w.color_at(&r), Color::black());
+        assert!(w.is_shadowed(p, w.lights[0].as_ref()));
+
+        for object in &mut w.objects {
+            object.set_visibility(Visibility {
+                shadows: false,
+                ..Visibility::ALL
+            });
+        }
+        assert_ne!(w.color_at(&r), Color::black());
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn objects_hidden_from_reflections_are_not_mirrored() {
+        let mut w = World::new();
+        w.background = Background::Solid(Color::new(0.0, 0.0, 1.0));
+        w.add_light(PointLight::new(Point::new(0.0, 10.0, 0.0), Color::white()));
+        w.add_object(Plane::new().with_material(Material {
+            ambient: 0.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            reflective: 1.0,
+            ..Material::default()
+        }));
+        let mut ball = Group::new().with_child(
+            Sphere::new()
+                .with_transform(Matrix4::translation(0.0, 3.0, 0.0))
+                .with_material(Material {
+                    ambient: 1.0,
+                    diffuse: 0.0,
+                    specular: 0.0,
+                    ..Material::default()
+                }),
+        );
+        ball.set_visibility(Visibility {
+            reflections: false,
+            ..Visibility::ALL
+        });
+        w.add_object(ball);
+
+        let at_ball = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        let at_mirror = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+        assert_eq!(w.color_at(&at_ball), Color::white());
+        assert_eq!(w.color_at(&at_mirror), Color::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn objects_hidden_from_refractions_are_not_seen_through_glass() {
+        let mut w = glass_ball_before_wall(Color::black());
+        let through_center = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let past_ball = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        w.objects[1].set_visibility(Visibility {
+            reflections: false,
+            ..Visibility::ALL
+        });
+        assert_eq!(w.color_at(&through_center), Color::white());
+
+        w.objects[1].set_visibility(Visibility {
+            refractions: false,
+            ..Visibility::ALL
+        });
+        assert_eq!(w.color_at(&through_center), Color::black());
+        assert_eq!(w.color_at(&past_ball), Color::white());
+    }
+}
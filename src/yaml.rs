@@ -0,0 +1,578 @@
+//! The subset of YAML used by scene files.
+//!
+//! Block mappings and sequences nest by indentation, and values on a single line may use
+//! the flow syntax (`[1, 2, 3]`, `{ add: cube }`). Anchors, tags, multi-document streams
+//! and multi-line strings are not supported.
+
+use crate::prelude::*;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(Float),
+    String(String),
+    Sequence(Vec<Value>),
+    /// Entries in the order they were written.
+    Mapping(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Mapping(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Short description of the kind of value, for error messages.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "nothing",
+            Value::Bool(_) => "a boolean",
+            Value::Number(_) => "a number",
+            Value::String(_) => "a string",
+            Value::Sequence(_) => "a list",
+            Value::Mapping(_) => "a mapping",
+        }
+    }
+}
+
+pub(crate) fn parse(input: &str) -> Result<Value> {
+    let mut parser = Parser {
+        lines: lines(input)?,
+        pos: 0,
+    };
+    if parser.lines.is_empty() {
+        return Ok(Value::Null);
+    }
+
+    let value = parser.parse_block(parser.lines[0].indent)?;
+    match parser.lines.get(parser.pos) {
+        Some(line) => Err(error(line.number, "unexpected indentation")),
+        None => Ok(value),
+    }
+}
+
+fn error(line: usize, reason: &str) -> RustracerError {
+    RustracerError::InvalidScene(format!("line {}: {}", line, reason))
+}
+
+/// A non-blank line without its comment and indentation.
+struct Line {
+    number: usize,
+    indent: usize,
+    text: String,
+}
+
+fn lines(input: &str) -> Result<Vec<Line>> {
+    let mut lines: Vec<Line> = Vec::new();
+    // Flow collections left open continue on the following lines.
+    let mut open_brackets = 0;
+
+    for (index, raw) in input.lines().enumerate() {
+        let number = index + 1;
+        let text = strip_comment(raw);
+        let content = text.trim_start();
+        if content.is_empty() || (content == "---" && lines.is_empty()) {
+            continue;
+        }
+
+        if open_brackets > 0 {
+            let line = lines
+                .last_mut()
+                .expect("open brackets come from a previous line");
+            line.text.push(' ');
+            line.text.push_str(content);
+        } else {
+            let indentation = &text[..text.len() - content.len()];
+            if indentation.contains('\t') {
+                return Err(error(number, "tabs cannot be used for indentation"));
+            }
+            lines.push(Line {
+                number,
+                indent: indentation.len(),
+                text: content.to_string(),
+            });
+        }
+        open_brackets = bracket_depth(&lines.last().expect("a line was just added").text);
+    }
+
+    match lines.last() {
+        Some(line) if open_brackets > 0 => Err(error(line.number, "unclosed bracket")),
+        _ => Ok(lines),
+    }
+}
+
+/// The line up to a `#` that starts a comment, without trailing whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '#') if previous.is_whitespace() => return line[..i].trim_end(),
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            _ => {}
+        }
+        previous = c;
+    }
+    line.trim_end()
+}
+
+/// Number of flow brackets opened but not closed, outside of quoted strings.
+fn bracket_depth(text: &str) -> usize {
+    let mut depth = 0usize;
+    let mut quote = None;
+    for c in text.chars() {
+        match (quote, c) {
+            (None, '[') | (None, '{') => depth += 1,
+            (None, ']') | (None, '}') => depth = depth.saturating_sub(1),
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            _ => {}
+        }
+    }
+    depth
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Splits a `key: value` line, returning `None` if it has no key.
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '[') | (None, '{') => depth += 1,
+            (None, ']') | (None, '}') => depth -= 1,
+            (None, ':') if depth == 0 => {
+                let rest = &text[i + 1..];
+                if rest.is_empty() || rest.starts_with(' ') {
+                    return Some((text[..i].trim(), rest.trim()));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+struct Parser {
+    lines: Vec<Line>,
+    pos: usize,
+}
+
+impl Parser {
+    /// Parses the block starting at the current line, which is indented by `indent`.
+    fn parse_block(&mut self, indent: usize) -> Result<Value> {
+        let line = &self.lines[self.pos];
+        if is_sequence_item(&line.text) {
+            self.parse_sequence(indent)
+        } else if split_key(&line.text).is_some() {
+            self.parse_mapping(indent)
+        } else {
+            self.pos += 1;
+            parse_flow(&line.text, line.number)
+        }
+    }
+
+    fn parse_sequence(&mut self, indent: usize) -> Result<Value> {
+        let mut items = Vec::new();
+
+        while let Some(line) = self.lines.get_mut(self.pos) {
+            if line.indent != indent || !is_sequence_item(&line.text) {
+                break;
+            }
+
+            let rest = line.text[1..].trim_start();
+            if rest.is_empty() {
+                self.pos += 1;
+                items.push(self.parse_nested(indent)?);
+            } else {
+                // The item's content is treated as a line of its own, indented to where
+                // it starts, so that a mapping can continue on the following lines.
+                line.indent += line.text.len() - rest.len();
+                line.text = rest.to_string();
+                let item_indent = line.indent;
+                items.push(self.parse_block(item_indent)?);
+            }
+        }
+
+        Ok(Value::Sequence(items))
+    }
+
+    fn parse_mapping(&mut self, indent: usize) -> Result<Value> {
+        let mut entries: Vec<(String, Value)> = Vec::new();
+
+        while let Some(line) = self.lines.get(self.pos) {
+            if line.indent != indent || is_sequence_item(&line.text) {
+                break;
+            }
+
+            let number = line.number;
+            let (key, rest) =
+                split_key(&line.text).ok_or_else(|| error(number, "expected `key: value`"))?;
+            let key = match parse_flow(key, number)? {
+                Value::String(key) => key,
+                _ => return Err(error(number, "mapping keys must be strings")),
+            };
+            if entries.iter().any(|(k, _)| *k == key) {
+                return Err(error(number, &format!("duplicate key `{}`", key)));
+            }
+
+            let value = if rest.is_empty() {
+                self.pos += 1;
+                match self.lines.get(self.pos) {
+                    // A sequence may sit at the same indentation as its key.
+                    Some(next) if next.indent == indent && is_sequence_item(&next.text) => {
+                        self.parse_sequence(indent)?
+                    }
+                    _ => self.parse_nested(indent)?,
+                }
+            } else {
+                let value = parse_flow(rest, number)?;
+                self.pos += 1;
+                value
+            };
+            entries.push((key, value));
+        }
+
+        Ok(Value::Mapping(entries))
+    }
+
+    /// Parses the block indented further than `indent` on the current line, if any.
+    fn parse_nested(&mut self, indent: usize) -> Result<Value> {
+        match self.lines.get(self.pos) {
+            Some(next) if next.indent > indent => {
+                let nested_indent = next.indent;
+                self.parse_block(nested_indent)
+            }
+            _ => Ok(Value::Null),
+        }
+    }
+}
+
+fn parse_flow(text: &str, line: usize) -> Result<Value> {
+    let mut flow = Flow {
+        text,
+        pos: 0,
+        line,
+        depth: 0,
+    };
+    let value = flow.value()?;
+    flow.skip_whitespace();
+    if flow.pos < text.len() {
+        return Err(error(line, "unexpected characters after value"));
+    }
+    Ok(value)
+}
+
+/// Parser for a value written on a single line.
+struct Flow<'a> {
+    text: &'a str,
+    pos: usize,
+    line: usize,
+    /// Number of enclosing flow collections, inside which `,` ends plain scalars.
+    depth: usize,
+}
+
+impl Flow<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(error(self.line, &format!("expected `{}`", expected)))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('[') => self.sequence(),
+            Some('{') => self.mapping(),
+            Some(quote @ '"') | Some(quote @ '\'') => self.quoted(quote).map(Value::String),
+            _ => Ok(scalar(self.plain(false))),
+        }
+    }
+
+    fn sequence(&mut self) -> Result<Value> {
+        self.expect('[')?;
+        self.depth += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                break;
+            }
+            items.push(self.value()?);
+            self.skip_whitespace();
+            if self.peek() != Some(',') {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.depth -= 1;
+        self.expect(']')?;
+        Ok(Value::Sequence(items))
+    }
+
+    fn mapping(&mut self) -> Result<Value> {
+        self.expect('{')?;
+        self.depth += 1;
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                break;
+            }
+            let key = match self.peek() {
+                Some(quote @ '"') | Some(quote @ '\'') => self.quoted(quote)?,
+                _ => self.plain(true).to_string(),
+            };
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            if self.peek() != Some(',') {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.depth -= 1;
+        self.expect('}')?;
+        Ok(Value::Mapping(entries))
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<String> {
+        self.pos += 1;
+        let mut result = String::new();
+        let mut chars = self.text[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                // A quote is doubled to escape it in single-quoted strings.
+                '\'' if quote == '\'' && self.text[self.pos + i + 1..].starts_with('\'') => {
+                    chars.next();
+                    result.push('\'');
+                }
+                '\\' if quote == '"' => match chars.next() {
+                    Some((_, 'n')) => result.push('\n'),
+                    Some((_, 't')) => result.push('\t'),
+                    Some((_, escaped @ '"')) | Some((_, escaped @ '\\')) => result.push(escaped),
+                    _ => return Err(error(self.line, "unknown escape sequence")),
+                },
+                c if c == quote => {
+                    self.pos += i + 1;
+                    return Ok(result);
+                }
+                c => result.push(c),
+            }
+        }
+        Err(error(self.line, "unterminated string"))
+    }
+
+    /// An unquoted scalar, ending at a flow indicator when inside a collection.
+    fn plain(&mut self, is_key: bool) -> &str {
+        let rest = &self.text[self.pos..];
+        let end = if self.depth == 0 && !is_key {
+            rest.len()
+        } else {
+            rest.find(|c| matches!(c, ',' | ']' | '}') || (is_key && c == ':'))
+                .unwrap_or(rest.len())
+        };
+        self.pos += end;
+        rest[..end].trim()
+    }
+}
+
+fn scalar(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => match text.parse() {
+            Ok(number) => Value::Number(number),
+            Err(_) => Value::String(text.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Value};
+    use crate::prelude::Float;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    fn numbers(values: &[Float]) -> Value {
+        Value::Sequence(values.iter().map(|&n| Value::Number(n)).collect())
+    }
+
+    #[test]
+    fn parsing_scalars() {
+        assert_eq!(parse("42").unwrap(), Value::Number(42.0));
+        assert_eq!(parse("-0.5").unwrap(), Value::Number(-0.5));
+        assert_eq!(parse("true").unwrap(), Value::Bool(true));
+        assert_eq!(parse("~").unwrap(), Value::Null);
+        assert_eq!(parse("white-material").unwrap(), string("white-material"));
+        assert_eq!(parse("'it''s'").unwrap(), string("it's"));
+        assert_eq!(parse(r#""a \"b\"""#).unwrap(), string("a \"b\""));
+        assert_eq!(parse("").unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn parsing_block_mapping() {
+        let value = parse(
+            "width: 100
+field-of-view: 0.785
+from: [ -6, 6, -10 ]",
+        )
+        .unwrap();
+
+        assert_eq!(value.get("width"), Some(&Value::Number(100.0)));
+        assert_eq!(value.get("field-of-view"), Some(&Value::Number(0.785)));
+        assert_eq!(value.get("from"), Some(&numbers(&[-6.0, 6.0, -10.0])));
+        assert_eq!(value.get("to"), None);
+    }
+
+    #[test]
+    fn parsing_sequence_of_mappings() {
+        let value = parse(
+            "# A comment
+- add: light
+  at: [ 50, 100, -50 ]   # trailing comment
+
+- define: standard-transform
+  value:
+    - [ translate, 1, -1, 1 ]
+    - [ scale, 0.5, 0.5, 0.5 ]",
+        )
+        .unwrap();
+
+        let items = match value {
+            Value::Sequence(items) => items,
+            other => panic!("expected a sequence, got {:?}", other),
+        };
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].get("add"), Some(&string("light")));
+        assert_eq!(items[0].get("at"), Some(&numbers(&[50.0, 100.0, -50.0])));
+        assert_eq!(
+            items[1].get("value"),
+            Some(&Value::Sequence(vec![
+                Value::Sequence(vec![
+                    string("translate"),
+                    Value::Number(1.0),
+                    Value::Number(-1.0),
+                    Value::Number(1.0),
+                ]),
+                Value::Sequence(vec![
+                    string("scale"),
+                    Value::Number(0.5),
+                    Value::Number(0.5),
+                    Value::Number(0.5),
+                ]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parsing_sequence_at_same_indentation_as_its_key() {
+        let value = parse(
+            "children:
+- add: sphere
+- add: cube
+name: g",
+        )
+        .unwrap();
+
+        match value.get("children") {
+            Some(Value::Sequence(children)) => assert_eq!(children.len(), 2),
+            other => panic!("expected a sequence, got {:?}", other),
+        }
+        assert_eq!(value.get("name"), Some(&string("g")));
+    }
+
+    #[test]
+    fn parsing_nested_items_and_flow_mappings() {
+        let value = parse(
+            "-
+  - 1
+  - { add: cube, material: { color: [1, 0, 0] } }
+- [ a, 'b, c' ]",
+        )
+        .unwrap();
+
+        let inner = Value::Mapping(vec![
+            ("add".to_string(), string("cube")),
+            (
+                "material".to_string(),
+                Value::Mapping(vec![("color".to_string(), numbers(&[1.0, 0.0, 0.0]))]),
+            ),
+        ]);
+        assert_eq!(
+            value,
+            Value::Sequence(vec![
+                Value::Sequence(vec![Value::Number(1.0), inner]),
+                Value::Sequence(vec![string("a"), string("b, c")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn flow_collections_can_span_several_lines() {
+        let value = parse(
+            "colors: [
+  [1, 0, 0],
+  [0, 0, 1]
+]
+type: stripes",
+        )
+        .unwrap();
+
+        assert_eq!(
+            value.get("colors"),
+            Some(&Value::Sequence(vec![
+                numbers(&[1.0, 0.0, 0.0]),
+                numbers(&[0.0, 0.0, 1.0])
+            ]))
+        );
+        assert_eq!(value.get("type"), Some(&string("stripes")));
+    }
+
+    #[test]
+    fn errors_report_the_line() {
+        let err = parse("a: 1\n  b: 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid scene: line 2: unexpected indentation"
+        );
+
+        let err = parse("a: 1\na: 2").unwrap_err();
+        assert_eq!(err.to_string(), "invalid scene: line 2: duplicate key `a`");
+
+        assert!(parse("a: [1, 2").is_err());
+        assert!(parse("a: \"open").is_err());
+        assert!(parse("a:\n\tb: 1").is_err());
+    }
+}